@@ -1,6 +1,6 @@
 use std::io::{stdin, stdout, Write};
 
-use bodhi::{BodhiServiceBuilder, Karma, Update, UpdateIDQuery};
+use bodhi::{BodhiServiceBuilder, Karma, Update, UpdateAlias, UpdateIDQuery};
 
 fn read_username() -> String {
     print!("FAS username: ");
@@ -25,7 +25,9 @@ fn main() -> Result<(), String> {
         .build()
         .unwrap();
 
-    let update: Update = match bodhi.query(UpdateIDQuery::new("FEDORA-2019-e7f463674c")) {
+    let update: Update = match bodhi.query(UpdateIDQuery::new(
+        "FEDORA-2019-e7f463674c".parse::<UpdateAlias>().unwrap(),
+    )) {
         Ok(ok) => match ok {
             Some(update) => update,
             None => {
@@ -1,7 +1,7 @@
 use std::io::{stdin, stdout, Write};
 use std::time::Duration;
 
-use bodhi::{BodhiServiceBuilder, Update, UpdateEditor, UpdateIDQuery};
+use bodhi::{BodhiServiceBuilder, Update, UpdateAlias, UpdateEditor, UpdateIDQuery};
 
 fn read_username() -> String {
     print!("FAS username: ");
@@ -25,7 +25,9 @@ fn main() -> Result<(), String> {
         .build()
         .unwrap();
 
-    let update: Update = match bodhi.query(UpdateIDQuery::new("FEDORA-2019-586c873435")) {
+    let update: Update = match bodhi.query(UpdateIDQuery::new(
+        "FEDORA-2019-586c873435".parse::<UpdateAlias>().unwrap(),
+    )) {
         Err(_) => {
             return Err(String::from("Failed to fetch update."));
         },
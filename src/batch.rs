@@ -0,0 +1,346 @@
+//! This module contains methods for creating and editing many things on a bodhi instance at once
+//! (for example, buildroot overrides for all builds of a mass rebuild).
+//!
+//! All items are validated before any request is sent, and a single CSRF token is shared by all
+//! requests of a batch. Requests can be sent from multiple threads at once, and can be limited to a
+//! maximum rate. Results are reported per item, in the order in which the items were supplied.
+//!
+//! ```
+//! use bodhi::{BatchOptions, BodhiDate, BodhiServiceBuilder, OverrideBuilder};
+//! use std::convert::TryFrom;
+//! use std::time::Duration;
+//!
+//! let bodhi = BodhiServiceBuilder::default().build().unwrap();
+//! let expiration_date = BodhiDate::try_from("2020-01-01 00:00:00").unwrap();
+//!
+//! let overrides = vec![
+//!     OverrideBuilder::new("rust-1.40.0-1.fc31", "mass rebuild", &expiration_date),
+//!     OverrideBuilder::new("rust-1.40.0-1.fc32", "mass rebuild", &expiration_date),
+//! ];
+//!
+//! let options = BatchOptions::new()
+//!     .concurrency(4)
+//!     .rate_limit(Duration::from_millis(200))
+//!     .continue_on_error(true);
+//!
+//! // the expiration date is in the past, so no requests are sent
+//! let report = bodhi.create_many(&overrides, &options).unwrap();
+//! assert_eq!(report.failed().count(), 2);
+//! ```
+
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::error::{QueryError, ValidationError};
+use crate::{BodhiService, CSRFQuery, Create, Edit};
+
+/// This struct contains the options for creating or editing many things at once with
+/// [`BodhiService::create_many`](../service/struct.BodhiService.html#method.create_many) and
+/// [`BodhiService::edit_many`](../service/struct.BodhiService.html#method.edit_many). By default,
+/// requests are sent one after the other, without rate limit, and the batch is stopped after the
+/// first failure.
+#[derive(Clone, Debug)]
+pub struct BatchOptions {
+    concurrency: usize,
+    rate_limit: Option<Duration>,
+    continue_on_error: bool,
+}
+
+impl Default for BatchOptions {
+    fn default() -> Self {
+        BatchOptions {
+            concurrency: 1,
+            rate_limit: None,
+            continue_on_error: false,
+        }
+    }
+}
+
+impl BatchOptions {
+    /// This method creates a new set of batch options with default values.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// This method sets the maximum number of requests that are sent at the same time. Values
+    /// smaller than 1 are treated as 1.
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// This method sets the minimum interval between the start of two consecutive requests.
+    pub fn rate_limit(mut self, interval: Duration) -> Self {
+        self.rate_limit = Some(interval);
+        self
+    }
+
+    /// This method sets whether the remaining items are still processed after one of them failed.
+    pub fn continue_on_error(mut self, continue_on_error: bool) -> Self {
+        self.continue_on_error = continue_on_error;
+        self
+    }
+}
+
+/// This enum represents the result of processing one item of a batch.
+#[derive(Debug)]
+pub enum BatchResult<T> {
+    /// The item was created or edited successfully.
+    Succeeded(T),
+    /// Creating or editing the item failed (or the item did not pass validation).
+    Failed(QueryError),
+    /// The item was not processed, because the batch was stopped after another item failed.
+    Skipped,
+}
+
+impl<T> BatchResult<T> {
+    /// This method returns `true` if the item was created or edited successfully.
+    pub fn is_success(&self) -> bool {
+        matches!(self, BatchResult::Succeeded(_))
+    }
+}
+
+/// This struct contains the results of processing all items of a batch, in the order in which the
+/// items were supplied.
+#[derive(Debug)]
+pub struct BatchReport<T> {
+    /// results for all items of the batch
+    pub results: Vec<BatchResult<T>>,
+}
+
+impl<T> BatchReport<T> {
+    /// This method returns `true` if all items were created or edited successfully.
+    pub fn is_success(&self) -> bool {
+        self.results.iter().all(BatchResult::is_success)
+    }
+
+    /// This method returns the index and result of all items that were processed successfully.
+    pub fn succeeded(&self) -> impl Iterator<Item = (usize, &T)> {
+        self.results
+            .iter()
+            .enumerate()
+            .filter_map(|(index, result)| match result {
+                BatchResult::Succeeded(value) => Some((index, value)),
+                _ => None,
+            })
+    }
+
+    /// This method returns the index and error of all items that failed.
+    pub fn failed(&self) -> impl Iterator<Item = (usize, &QueryError)> {
+        self.results
+            .iter()
+            .enumerate()
+            .filter_map(|(index, result)| match result {
+                BatchResult::Failed(error) => Some((index, error)),
+                _ => None,
+            })
+    }
+
+    /// This method returns the indices of all items that were skipped.
+    pub fn skipped(&self) -> impl Iterator<Item = usize> + '_ {
+        self.results
+            .iter()
+            .enumerate()
+            .filter_map(|(index, result)| match result {
+                BatchResult::Skipped => Some(index),
+                _ => None,
+            })
+    }
+}
+
+impl BodhiService {
+    /// This method is used for POST methods to create many new things on the bodhi instance at
+    /// once. All items are validated first, and one CSRF token is used for all requests. An error
+    /// is only returned if the CSRF token could not be fetched, failures of individual items are
+    /// included in the returned report.
+    pub fn create_many<'b, T, C, I>(&self, creators: I, options: &BatchOptions) -> Result<BatchReport<T>, QueryError>
+    where
+        T: Send,
+        C: Create<T> + Sync + ?Sized + 'b,
+        I: IntoIterator<Item = &'b C>,
+    {
+        let creators: Vec<&C> = creators.into_iter().collect();
+        let validated = creators.iter().map(|creator| creator.validate()).collect();
+
+        self.run_batch(creators, validated, options, |creator, csrf_token| {
+            creator.check(self)?;
+            creator.send(self, csrf_token)
+        })
+    }
+
+    /// This method is used for POST methods to edit many existing things on the bodhi instance at
+    /// once. All items are validated first, and one CSRF token is used for all requests. An error
+    /// is only returned if the CSRF token could not be fetched, failures of individual items are
+    /// included in the returned report.
+    pub fn edit_many<'b, T, E, I>(&self, editors: I, options: &BatchOptions) -> Result<BatchReport<T>, QueryError>
+    where
+        T: Send,
+        E: Edit<T> + Sync + ?Sized + 'b,
+        I: IntoIterator<Item = &'b E>,
+    {
+        let editors: Vec<&E> = editors.into_iter().collect();
+        let validated = editors.iter().map(|editor| editor.validate()).collect();
+
+        self.run_batch(editors, validated, options, |editor, csrf_token| {
+            editor.check(self)?;
+            editor.send(self, csrf_token)
+        })
+    }
+
+    fn run_batch<C, T>(
+        &self,
+        items: Vec<&C>,
+        validated: Vec<Result<(), ValidationError>>,
+        options: &BatchOptions,
+        send: impl Fn(&C, &str) -> Result<T, QueryError> + Sync,
+    ) -> Result<BatchReport<T>, QueryError>
+    where
+        C: Sync + ?Sized,
+        T: Send,
+    {
+        let invalid = validated.iter().any(Result::is_err);
+        let mut results: Vec<Option<BatchResult<T>>> = Vec::with_capacity(items.len());
+        let mut indices: Vec<usize> = Vec::new();
+        let mut pending: Vec<&C> = Vec::new();
+
+        for (index, (item, validation)) in items.into_iter().zip(validated).enumerate() {
+            match validation {
+                Err(error) => results.push(Some(BatchResult::Failed(error.into()))),
+                Ok(()) if invalid && !options.continue_on_error => results.push(Some(BatchResult::Skipped)),
+                Ok(()) => {
+                    results.push(None);
+                    indices.push(index);
+                    pending.push(item);
+                },
+            }
+        }
+
+        if !pending.is_empty() {
+            let csrf_token = self.query(CSRFQuery::new())?;
+            let processed = run(&pending, options, |item| send(item, &csrf_token));
+
+            for (index, result) in indices.into_iter().zip(processed) {
+                results[index] = Some(result);
+            }
+        }
+
+        Ok(BatchReport {
+            results: results
+                .into_iter()
+                .map(|result| result.unwrap_or(BatchResult::Skipped))
+                .collect(),
+        })
+    }
+}
+
+/// This function processes all items with the given function, using the given number of threads
+/// and rate limit. If processing an item fails and the batch is not supposed to continue, items
+/// that were not started yet are skipped.
+pub(crate) fn run<C, T>(
+    items: &[&C],
+    options: &BatchOptions,
+    process: impl Fn(&C) -> Result<T, QueryError> + Sync,
+) -> Vec<BatchResult<T>>
+where
+    C: Sync + ?Sized,
+    T: Send,
+{
+    let next = AtomicUsize::new(0);
+    let stopped = AtomicBool::new(false);
+    let limiter = RateLimiter::new(options.rate_limit);
+
+    let worker = || {
+        let mut processed = Vec::new();
+
+        loop {
+            let index = next.fetch_add(1, Ordering::SeqCst);
+            if index >= items.len() {
+                break;
+            }
+
+            limiter.wait();
+            if stopped.load(Ordering::SeqCst) {
+                break;
+            }
+
+            let result = match process(items[index]) {
+                Ok(value) => BatchResult::Succeeded(value),
+                Err(error) => {
+                    if !options.continue_on_error {
+                        stopped.store(true, Ordering::SeqCst);
+                    }
+                    BatchResult::Failed(error)
+                },
+            };
+
+            processed.push((index, result));
+        }
+
+        processed
+    };
+
+    let mut results: Vec<BatchResult<T>> = items.iter().map(|_| BatchResult::Skipped).collect();
+    let threads = options.concurrency.min(items.len());
+
+    let processed: Vec<(usize, BatchResult<T>)> = if threads <= 1 {
+        worker()
+    } else {
+        std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..threads).map(|_| scope.spawn(worker)).collect();
+
+            handles
+                .into_iter()
+                .flat_map(|handle| match handle.join() {
+                    Ok(processed) => processed,
+                    Err(panic) => std::panic::resume_unwind(panic),
+                })
+                .collect()
+        })
+    };
+
+    for (index, result) in processed {
+        results[index] = result;
+    }
+
+    results
+}
+
+/// This struct makes sure that consecutive requests are started at least the given interval apart,
+/// even if they are sent from different threads.
+struct RateLimiter {
+    interval: Option<Duration>,
+    next: Mutex<Option<Instant>>,
+}
+
+impl RateLimiter {
+    fn new(interval: Option<Duration>) -> Self {
+        RateLimiter {
+            interval,
+            next: Mutex::new(None),
+        }
+    }
+
+    fn wait(&self) {
+        let interval = match self.interval {
+            Some(interval) => interval,
+            None => return,
+        };
+
+        let now = Instant::now();
+
+        let start = {
+            let mut next = self.next.lock().unwrap_or_else(|error| error.into_inner());
+            let start = match *next {
+                Some(next) if next > now => next,
+                _ => now,
+            };
+            *next = Some(start + interval);
+            start
+        };
+
+        if start > now {
+            std::thread::sleep(start - now);
+        }
+    }
+}
@@ -2,8 +2,9 @@ use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
 
-use crate::error::{BodhiError, QueryError};
-use crate::{BodhiService, CSRFQuery, Comment, Create, Karma, SinglePageQuery, Update};
+use crate::error::{QueryError, ValidationError, ValidationProblem};
+use crate::query::json::from_json;
+use crate::{Comment, Create, Karma, PostRequest, Update, Validate, ValidationContext};
 
 // API documentation: <https://bodhi.fedoraproject.org/docs/server_api/rest/comments.html#service-1-POST>
 #[derive(Debug, Serialize)]
@@ -102,12 +103,23 @@ impl<'a> CommentBuilder<'a> {
     }
 }
 
+impl<'a> Validate for CommentBuilder<'a> {
+    fn validate_with(&self, _context: &ValidationContext) -> Result<(), ValidationError> {
+        let mut problems = Vec::new();
+
+        let has_text = matches!(self.text, Some(text) if !text.trim().is_empty());
+        if !has_text && self.karma.is_none() && self.bug_feedback.is_none() && self.testcase_feedback.is_none() {
+            problems.push(ValidationProblem::EmptyComment);
+        }
+
+        ValidationError::check(problems)
+    }
+}
+
 impl<'a> Create<NewComment> for CommentBuilder<'a> {
-    fn create(&self, bodhi: &BodhiService) -> Result<NewComment, QueryError> {
+    fn request(&self, csrf_token: &str) -> Result<PostRequest, QueryError> {
         let path = String::from("/comments/");
 
-        let csrf_token = CSRFQuery::new().query(bodhi)?;
-
         let mut feedback: HashMap<String, String> = HashMap::new();
 
         let karma_string = |k: Karma| match k {
@@ -146,7 +158,7 @@ impl<'a> Create<NewComment> for CommentBuilder<'a> {
                 None => Karma::Neutral,
             },
             feedback,
-            csrf_token: &csrf_token,
+            csrf_token,
         };
 
         let data = match serde_json::to_string(&new_comment) {
@@ -154,18 +166,11 @@ impl<'a> Create<NewComment> for CommentBuilder<'a> {
             Err(error) => return Err(QueryError::SerializationError { error }),
         };
 
-        let response = bodhi.post(&path, data)?;
-        let status = response.status();
-
-        if !status.is_success() {
-            let text = response.text().unwrap_or_else(|_| String::from(""));
-
-            let error: BodhiError = serde_json::from_str(&text)?;
-            return Err(QueryError::BodhiError { error });
-        };
+        Ok(PostRequest::new(path, data))
+    }
 
-        let result = response.text()?;
-        let new_comment: NewComment = serde_json::from_str(&result)?;
+    fn parse(&self, string: &str) -> Result<NewComment, QueryError> {
+        let new_comment: NewComment = from_json(string)?;
 
         Ok(new_comment)
     }
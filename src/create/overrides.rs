@@ -2,8 +2,10 @@ use std::collections::HashMap;
 
 use serde::Deserialize;
 
-use crate::error::{BodhiError, QueryError};
-use crate::{BodhiDate, BodhiService, Build, CSRFQuery, Create, Override, OverrideData};
+use crate::error::{QueryError, ValidationError};
+use crate::query::json::from_json;
+use crate::validate::{check_builds, check_expiration, check_notes};
+use crate::{BodhiDate, Build, Create, Override, OverrideData, PostRequest, Validate, ValidationContext};
 
 /// This struct contains the values that are returned when creating a new comment.
 #[derive(Debug, Deserialize)]
@@ -25,29 +27,40 @@ pub struct OverrideBuilder<'a> {
 }
 
 impl<'a> OverrideBuilder<'a> {
-    /// This method has to be used to create and initialize a new `OverrideBuilder`.
-    pub fn new(nvr: &'a str, notes: &'a str, expiration_date: &'a BodhiDate) -> Self {
+    /// This method has to be used to create and initialize a new `OverrideBuilder`. The NVR can be
+    /// either a string, or a validated [`Nvr`](../../data/struct.Nvr.html).
+    pub fn new<S: AsRef<str> + ?Sized>(nvr: &'a S, notes: &'a str, expiration_date: &'a BodhiDate) -> Self {
         OverrideBuilder {
-            nvr,
+            nvr: nvr.as_ref(),
             notes,
             expiration_date,
         }
     }
 }
 
+impl<'a> Validate for OverrideBuilder<'a> {
+    fn validate_with(&self, _context: &ValidationContext) -> Result<(), ValidationError> {
+        let mut problems = Vec::new();
+
+        check_notes(self.notes, &mut problems);
+        check_builds(&[self.nvr], &mut problems);
+        check_expiration(self.expiration_date, &mut problems);
+
+        ValidationError::check(problems)
+    }
+}
+
 impl<'a> Create<NewOverride> for OverrideBuilder<'a> {
-    fn create(&self, bodhi: &BodhiService) -> Result<NewOverride, QueryError> {
+    fn request(&self, csrf_token: &str) -> Result<PostRequest, QueryError> {
         let path = String::from("/overrides/");
 
-        let csrf_token = bodhi.query(CSRFQuery::new())?;
-
         let new_override = OverrideData {
             nvr: self.nvr,
             notes: self.notes,
             expiration_date: self.expiration_date,
             expired: None,
             edited: None,
-            csrf_token: &csrf_token,
+            csrf_token,
         };
 
         let data = match serde_json::to_string(&new_override) {
@@ -55,19 +68,11 @@ impl<'a> Create<NewOverride> for OverrideBuilder<'a> {
             Err(error) => return Err(QueryError::SerializationError { error }),
         };
 
-        let response = bodhi.post(&path, data)?;
-        let status = response.status();
-
-        if !status.is_success() {
-            let text = response.text().unwrap_or_else(|_| String::from(""));
-
-            let error: BodhiError = serde_json::from_str(&text)?;
-            return Err(QueryError::BodhiError { error });
-        };
-
-        let result = response.text()?;
+        Ok(PostRequest::new(path, data))
+    }
 
-        let new_override: NewOverride = serde_json::from_str(&result)?;
+    fn parse(&self, string: &str) -> Result<NewOverride, QueryError> {
+        let new_override: NewOverride = from_json(string)?;
 
         Ok(new_override)
     }
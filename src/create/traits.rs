@@ -1,6 +1,42 @@
 use crate::error::QueryError;
-use crate::BodhiService;
+use crate::{BodhiService, CSRFQuery, PostRequest, Validate};
 
-pub trait Create<T> {
-    fn create(&self, bodhi: &BodhiService) -> Result<T, QueryError>;
+pub trait Create<T>: Validate {
+    /// This method is expected to return the POST request for creating the new thing on the
+    /// server, including the given CSRF token. It does not validate the data.
+    fn request(&self, csrf_token: &str) -> Result<PostRequest, QueryError>;
+
+    /// This method is expected to return the result that was parsed from the JSON response, or an
+    /// error.
+    fn parse(&self, string: &str) -> Result<T, QueryError>;
+
+    /// This method can be overridden to check whether the server supports creating the new thing
+    /// (for example, based on its version) before the request is sent.
+    fn check(&self, _bodhi: &BodhiService) -> Result<(), QueryError> {
+        Ok(())
+    }
+
+    /// This method validates the data, checks whether the server supports it, fetches a new CSRF
+    /// token, and sends the request.
+    fn create(&self, bodhi: &BodhiService) -> Result<T, QueryError> {
+        self.validate()?;
+        self.check(bodhi)?;
+
+        let csrf_token = bodhi.query(CSRFQuery::new())?;
+        self.send(bodhi, &csrf_token)
+    }
+
+    /// This method sends the request with the given CSRF token, without validating the data or
+    /// checking the server first.
+    fn send(&self, bodhi: &BodhiService, csrf_token: &str) -> Result<T, QueryError> {
+        let request = self.request(csrf_token)?;
+
+        let response = bodhi.post(&request.path, request.body)?;
+        if !response.status().is_success() {
+            return Err(QueryError::from_response(response));
+        };
+
+        let result = response.text()?;
+        self.parse(&result)
+    }
 }
@@ -2,17 +2,26 @@ use std::collections::HashMap;
 
 use serde::Deserialize;
 
-use crate::error::{BodhiError, QueryError};
+use crate::error::{QueryError, ValidationError};
+use crate::query::json::from_json;
+use crate::validate::{check_builds, check_karma, check_known, check_notes, check_severity, check_stable_days};
 use crate::{
     BodhiService,
-    CSRFQuery,
+    BugId,
+    BuildNVRQuery,
     Create,
+    PostRequest,
+    Release,
+    ReleaseQuery,
+    ServerVersion,
     Update,
     UpdateData,
     UpdateRequest,
     UpdateSeverity,
     UpdateSuggestion,
     UpdateType,
+    Validate,
+    ValidationContext,
 };
 
 /// This struct contains the values that are returned when creating a new update.
@@ -40,7 +49,7 @@ pub struct UpdateBuilder<'a> {
     notes: &'a str,
 
     // optional fields
-    bugs: Option<Vec<u32>>,
+    bugs: Option<Vec<BugId>>,
     display_name: Option<String>,
     close_bugs: Option<bool>,
     update_type: Option<UpdateType>,
@@ -82,7 +91,17 @@ impl<'a> UpdateBuilder<'a> {
         }
     }
 
-    /// Use this method when creating an update for a side tag.
+    /// This method returns the builds of the new update, unless it is created for a side tag.
+    pub(crate) fn builds(&self) -> Option<&'a [&'a str]> {
+        match self.source {
+            UpdateSource::Builds { builds } => Some(builds),
+            UpdateSource::Tag { .. } => None,
+        }
+    }
+
+    /// Use this method when creating an update for a side tag. Side tag updates are only supported
+    /// by bodhi 5.0 and later, so creating them fails with an `UnsupportedServerVersion` error on
+    /// older servers.
     pub fn from_tag(tag: &'a str, notes: &'a str) -> Self {
         UpdateBuilder {
             source: UpdateSource::Tag { tag },
@@ -109,7 +128,9 @@ impl<'a> UpdateBuilder<'a> {
     /// Add a related bug to the update.
     ///
     /// Can be specified multiple times.
-    pub fn bugs(mut self, bug: u32) -> Self {
+    pub fn bugs(mut self, bug: impl Into<BugId>) -> Self {
+        let bug = bug.into();
+
         match &mut self.bugs {
             Some(bugs) => bugs.push(bug),
             None => self.bugs = Some(vec![bug]),
@@ -125,8 +146,8 @@ impl<'a> UpdateBuilder<'a> {
     }
 
     /// Add a custom user-visible title to the update.
-    pub fn display_name(mut self, display_name: String) -> Self {
-        self.display_name = Some(display_name);
+    pub fn display_name(mut self, display_name: impl Into<String>) -> Self {
+        self.display_name = Some(display_name.into());
         self
     }
 
@@ -170,8 +191,8 @@ impl<'a> UpdateBuilder<'a> {
     }
 
     /// Add custom taskotron requirements.
-    pub fn requirements(mut self, requirements: String) -> Self {
-        self.requirements = Some(requirements);
+    pub fn requirements(mut self, requirements: impl Into<String>) -> Self {
+        self.requirements = Some(requirements.into());
         self
     }
 
@@ -204,45 +225,44 @@ impl<'a> UpdateBuilder<'a> {
     }
 }
 
-impl<'a> Create<NewUpdate> for UpdateBuilder<'a> {
-    fn create(&self, bodhi: &BodhiService) -> Result<NewUpdate, QueryError> {
-        let path = String::from("/updates/");
+impl<'a> Validate for UpdateBuilder<'a> {
+    fn validate_with(&self, context: &ValidationContext) -> Result<(), ValidationError> {
+        let mut problems = Vec::new();
 
-        // do some data sanity verification
-        if let Some(karma) = self.stable_karma {
-            if karma < 1 {
-                return Err(QueryError::InvalidDataError {
-                    error: String::from("Stable karma must be positive."),
-                });
-            }
-        }
-        if let Some(karma) = self.unstable_karma {
-            if karma > -1 {
-                return Err(QueryError::InvalidDataError {
-                    error: String::from("Unstable karma must be negative."),
-                });
-            }
+        check_notes(self.notes, &mut problems);
+        if let UpdateSource::Builds { builds } = self.source {
+            check_builds(builds, &mut problems);
         }
+        check_karma(self.stable_karma, self.unstable_karma, &mut problems);
+        check_severity(self.update_type.as_ref(), self.severity.as_ref(), &mut problems);
+        check_known("type", self.update_type.as_ref(), &mut problems);
+        check_known("request", self.request.as_ref(), &mut problems);
+        check_known("severity", self.severity.as_ref(), &mut problems);
+        check_known("suggest", self.suggest.as_ref(), &mut problems);
+        check_stable_days(self.stable_days, context, &mut problems);
+
+        ValidationError::check(problems)
+    }
+}
 
-        if let Some(update_type) = self.update_type {
-            if update_type == UpdateType::Security {
-                match self.severity {
-                    Some(value) if value == UpdateSeverity::Unspecified => {
-                        return Err(QueryError::InvalidDataError {
-                            error: String::from("For security updates, severity has to be specified."),
-                        });
-                    },
-                    None => {
-                        return Err(QueryError::InvalidDataError {
-                            error: String::from("For security updates, severity has to be specified."),
-                        });
-                    },
-                    _ => {},
+impl<'a> Create<NewUpdate> for UpdateBuilder<'a> {
+    fn check(&self, bodhi: &BodhiService) -> Result<(), QueryError> {
+        match self.source {
+            UpdateSource::Tag { .. } => bodhi.require_version(ServerVersion::SIDE_TAG_UPDATES),
+            UpdateSource::Builds { builds } => {
+                // the minimum number of days in testing depends on the release of the builds
+                if self.stable_days.is_some() {
+                    if let Some(release) = build_release(bodhi, builds)? {
+                        self.validate_with(&ValidationContext::from_release(&release))?;
+                    }
                 }
-            }
+                Ok(())
+            },
         }
+    }
 
-        let csrf_token = bodhi.query(CSRFQuery::new())?;
+    fn request(&self, csrf_token: &str) -> Result<PostRequest, QueryError> {
+        let path = String::from("/updates/");
 
         let bugs: Option<Vec<String>> = self
             .bugs
@@ -259,17 +279,17 @@ impl<'a> Create<NewUpdate> for UpdateBuilder<'a> {
                     None => None,
                 },
                 close_bugs: self.close_bugs,
-                update_type: match self.update_type {
-                    Some(t) => t,
+                update_type: match &self.update_type {
+                    Some(t) => t.clone(),
                     None => UpdateType::Unspecified,
                 },
-                request: self.request,
-                severity: self.severity,
+                request: self.request.clone(),
+                severity: self.severity.clone(),
                 notes: self.notes,
                 autokarma: self.autokarma,
                 stable_karma: self.stable_karma,
                 unstable_karma: self.unstable_karma,
-                suggest: self.suggest,
+                suggest: self.suggest.clone(),
                 edited: None,
                 requirements: match &self.requirements {
                     Some(string) => Some(string),
@@ -279,7 +299,7 @@ impl<'a> Create<NewUpdate> for UpdateBuilder<'a> {
                 require_testcases: self.require_testcases,
                 autotime: self.autotime,
                 stable_days: self.stable_days,
-                csrf_token: &csrf_token,
+                csrf_token,
             },
             UpdateSource::Tag { tag } => UpdateData {
                 builds: None,
@@ -290,17 +310,17 @@ impl<'a> Create<NewUpdate> for UpdateBuilder<'a> {
                     None => None,
                 },
                 close_bugs: self.close_bugs,
-                update_type: match self.update_type {
-                    Some(t) => t,
+                update_type: match &self.update_type {
+                    Some(t) => t.clone(),
                     None => UpdateType::Unspecified,
                 },
-                request: self.request,
-                severity: self.severity,
+                request: self.request.clone(),
+                severity: self.severity.clone(),
                 notes: self.notes,
                 autokarma: self.autokarma,
                 stable_karma: self.stable_karma,
                 unstable_karma: self.unstable_karma,
-                suggest: self.suggest,
+                suggest: self.suggest.clone(),
                 edited: None,
                 requirements: match &self.requirements {
                     Some(string) => Some(string),
@@ -310,7 +330,7 @@ impl<'a> Create<NewUpdate> for UpdateBuilder<'a> {
                 require_testcases: self.require_testcases,
                 autotime: self.autotime,
                 stable_days: self.stable_days,
-                csrf_token: &csrf_token,
+                csrf_token,
             },
         };
 
@@ -319,20 +339,29 @@ impl<'a> Create<NewUpdate> for UpdateBuilder<'a> {
             Err(error) => return Err(QueryError::SerializationError { error }),
         };
 
-        let response = bodhi.post(&path, data)?;
-        let status = response.status();
-
-        if !status.is_success() {
-            let text = response.text().unwrap_or_else(|_| String::from(""));
-
-            let error: BodhiError = serde_json::from_str(&text)?;
-            return Err(QueryError::BodhiError { error });
-        };
-
-        let result = response.text()?;
+        Ok(PostRequest::new(path, data))
+    }
 
-        let new_update: NewUpdate = serde_json::from_str(&result)?;
+    fn parse(&self, string: &str) -> Result<NewUpdate, QueryError> {
+        let new_update: NewUpdate = from_json(string)?;
 
         Ok(new_update)
     }
 }
+
+/// This function returns the release of the first of the given builds, if both the build and its
+/// release are known to the server.
+fn build_release(bodhi: &BodhiService, builds: &[&str]) -> Result<Option<Release>, QueryError> {
+    let build = match builds.first() {
+        Some(nvr) => bodhi.query(BuildNVRQuery::new(&nvr.parse()?))?,
+        None => None,
+    };
+
+    match build.and_then(|build| build.release_id) {
+        Some(release_id) => {
+            let releases = bodhi.query(ReleaseQuery::new().ids(vec![release_id.to_string()]))?;
+            Ok(releases.into_iter().next())
+        },
+        None => Ok(None),
+    }
+}
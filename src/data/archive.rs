@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+
+/// This constant contains the version of the serialized representation of the data types in this
+/// crate. It is incremented whenever a change to a data type affects its JSON representation, so
+/// archived values can be checked for compatibility before they are loaded again.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// This struct wraps values (for example, a list of updates) for archiving them together with the
+/// version of the schema they were serialized with.
+///
+/// ```
+/// # use bodhi::{Archived, SCHEMA_VERSION};
+/// let archived = Archived::new(vec![String::from("FEDORA-2019-cf87377f5f")]);
+///
+/// let json = serde_json::to_string(&archived).unwrap();
+/// assert_eq!(json, r#"{"schema_version":1,"data":["FEDORA-2019-cf87377f5f"]}"#);
+///
+/// let loaded: Archived<Vec<String>> = serde_json::from_str(&json).unwrap();
+/// assert!(loaded.is_current());
+/// ```
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Archived<T> {
+    /// version of the schema the archived data was serialized with
+    pub schema_version: u32,
+    /// archived data
+    pub data: T,
+}
+
+impl<T> Archived<T> {
+    /// This method wraps the given data with the current schema version.
+    pub fn new(data: T) -> Self {
+        Archived {
+            schema_version: SCHEMA_VERSION,
+            data,
+        }
+    }
+
+    /// This method returns `true` if the data was archived with the current schema version.
+    pub fn is_current(&self) -> bool {
+        self.schema_version == SCHEMA_VERSION
+    }
+}
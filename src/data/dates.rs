@@ -4,15 +4,17 @@ use std::fmt::{Display, Formatter};
 use std::str::FromStr;
 
 use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 /// human-readable date format internally used by bodhi
 pub const BODHI_DATETIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
 
 /// This struct wraps a `chrono::DateTime<chrono::Utc>` instance with implementations for converting
-/// to and from the string format that bodhi expects and returns for dates and times.
-#[derive(Debug, Eq)]
+/// to and from the string format that bodhi expects and returns for dates and times. Values are
+/// serialized in the same format, so they survive a round-trip through JSON unchanged.
+#[derive(Clone, Debug, Eq)]
 pub struct BodhiDate {
-    date: DateTime<Utc>,
+    pub(crate) date: DateTime<Utc>,
 }
 
 impl From<DateTime<Utc>> for BodhiDate {
@@ -53,6 +55,30 @@ impl Display for BodhiDate {
     }
 }
 
+impl Serialize for BodhiDate {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for BodhiDate {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        // dates returned by bodhi always use the full format, so values are not padded here
+        let string = String::deserialize(deserializer)?;
+
+        match Utc.datetime_from_str(&string, BODHI_DATETIME_FORMAT) {
+            Ok(date) => Ok(BodhiDate { date }),
+            Err(error) => Err(serde::de::Error::custom(error)),
+        }
+    }
+}
+
 impl PartialEq for BodhiDate {
     fn eq(&self, other: &Self) -> bool {
         self.date.eq(&other.date)
@@ -76,27 +102,20 @@ impl Ord for BodhiDate {
 pub(crate) mod bodhi_date_format {
     use super::BodhiDate;
 
-    use chrono::{TimeZone, Utc};
-    use serde::{self, Deserialize, Deserializer, Serializer};
+    use serde::{self, Deserialize, Deserializer, Serialize, Serializer};
 
     pub fn serialize<S>(date: &BodhiDate, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        let string = date.date.format(super::BODHI_DATETIME_FORMAT).to_string();
-        serializer.serialize_str(&string)
+        date.serialize(serializer)
     }
 
     pub fn deserialize<'de, D>(deserializer: D) -> Result<BodhiDate, D::Error>
     where
         D: Deserializer<'de>,
     {
-        let string = String::deserialize(deserializer)?;
-
-        match Utc.datetime_from_str(&string, super::BODHI_DATETIME_FORMAT) {
-            Ok(result) => Ok(BodhiDate { date: result }),
-            Err(error) => Err(error).map_err(serde::de::Error::custom),
-        }
+        BodhiDate::deserialize(deserializer)
     }
 }
 
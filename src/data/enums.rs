@@ -4,7 +4,7 @@ use std::error::Error;
 use std::fmt::{Display, Formatter};
 use std::str::FromStr;
 
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde_repr::{Deserialize_repr, Serialize_repr};
 
 /// This error is returned when a string fails to be parsed into an enumerated value.
@@ -17,7 +17,7 @@ pub struct InvalidValueError {
 }
 
 impl InvalidValueError {
-    fn new(name: &'static str, value: &str) -> Self {
+    pub(crate) fn new(name: &'static str, value: &str) -> Self {
         InvalidValueError {
             name,
             value: value.to_owned(),
@@ -33,14 +33,82 @@ impl Display for InvalidValueError {
 
 impl Error for InvalidValueError {}
 
+/// This function handles string values that do not match any of the known variants of an enum. By
+/// default, the raw string is preserved, so values that were added on the server side don't break
+/// deserialization. If the `strict-enums` feature is enabled, an error is returned instead, which
+/// is useful for detecting changes in the server API.
+fn unknown(name: &'static str, value: &str) -> Result<String, InvalidValueError> {
+    if cfg!(feature = "strict-enums") {
+        Err(InvalidValueError::new(name, value))
+    } else {
+        Ok(value.to_owned())
+    }
+}
+
+/// This trait is implemented by all enums that are represented by strings, and that can hold
+/// unknown values.
+pub(crate) trait StringEnum {
+    /// This method returns `true` if the value is not one of the known variants.
+    fn is_unknown(&self) -> bool;
+}
+
+// This macro implements (de)serialization for enums that are represented by strings, based on the
+// implementations of `Display` and `TryFrom<&str>`, so unknown values survive round-trips. It also
+// implements checking for unknown values.
+macro_rules! string_enum {
+    ($name:ident) => {
+        impl $name {
+            /// This method returns `true` if the value is not one of the variants that are known
+            /// to this crate.
+            pub fn is_unknown(&self) -> bool {
+                matches!(self, $name::Unknown(_))
+            }
+        }
+
+        impl StringEnum for $name {
+            fn is_unknown(&self) -> bool {
+                $name::is_unknown(self)
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                serializer.collect_str(self)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let string = String::deserialize(deserializer)?;
+                let value = $name::try_from(string.as_str()).map_err(serde::de::Error::custom)?;
+
+                // parsing is case-insensitive, but bodhi always uses lowercase values, so values
+                // with different spelling are not treated as one of the known variants
+                if !value.is_unknown() && value.to_string() != string {
+                    let value = unknown(stringify!($name), &string).map_err(serde::de::Error::custom)?;
+                    return Ok($name::Unknown(value));
+                }
+
+                Ok(value)
+            }
+        }
+    };
+}
+
 /// This enum represents the possible request values for composes.
 #[allow(missing_docs)]
-#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub enum ComposeRequest {
-    #[serde(rename = "stable")]
     Stable,
-    #[serde(rename = "testing")]
     Testing,
+    /// unknown value that is not (yet) supported by this crate
+    Unknown(String),
 }
 
 impl Display for ComposeRequest {
@@ -48,6 +116,7 @@ impl Display for ComposeRequest {
         let value = match self {
             ComposeRequest::Stable => "stable",
             ComposeRequest::Testing => "testing",
+            ComposeRequest::Unknown(value) => value.as_str(),
         };
 
         write!(f, "{}", value)
@@ -61,7 +130,7 @@ impl TryFrom<&str> for ComposeRequest {
         match value.to_lowercase().as_str() {
             "stable" => Ok(ComposeRequest::Stable),
             "testing" => Ok(ComposeRequest::Testing),
-            _ => Err(InvalidValueError::new("ComposeRequest", value)),
+            _ => Ok(ComposeRequest::Unknown(unknown("ComposeRequest", value)?)),
         }
     }
 }
@@ -74,33 +143,26 @@ impl FromStr for ComposeRequest {
     }
 }
 
+string_enum!(ComposeRequest);
+
 
 /// This enum represents the possible status values for composes.
 #[allow(missing_docs)]
-#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum ComposeStatus {
-    #[serde(rename = "cleaning")]
     Cleaning,
-    #[serde(rename = "failed")]
     Failed,
-    #[serde(rename = "initializing")]
     Initializing,
-    #[serde(rename = "notifying")]
     Notifying,
-    #[serde(rename = "pending")]
     Pending,
-    #[serde(rename = "punging")]
     Punging,
-    #[serde(rename = "requested")]
     Requested,
-    #[serde(rename = "signing_repo")]
     SigningRepo,
-    #[serde(rename = "success")]
     Success,
-    #[serde(rename = "syncing_repo")]
     SyncingRepo,
-    #[serde(rename = "updateinfo")]
     UpdateInfo,
+    /// unknown value that is not (yet) supported by this crate
+    Unknown(String),
 }
 
 impl Display for ComposeStatus {
@@ -117,6 +179,7 @@ impl Display for ComposeStatus {
             ComposeStatus::Success => "success",
             ComposeStatus::SyncingRepo => "syncing_repo",
             ComposeStatus::UpdateInfo => "updateinfo",
+            ComposeStatus::Unknown(value) => value.as_str(),
         };
 
         write!(f, "{}", value)
@@ -139,7 +202,7 @@ impl TryFrom<&str> for ComposeStatus {
             "success" => Ok(ComposeStatus::Success),
             "syncing_repo" => Ok(ComposeStatus::SyncingRepo),
             "updateinfo" => Ok(ComposeStatus::UpdateInfo),
-            _ => Err(InvalidValueError::new("ComposeStatus", value)),
+            _ => Ok(ComposeStatus::Unknown(unknown("ComposeStatus", value)?)),
         }
     }
 }
@@ -152,21 +215,21 @@ impl FromStr for ComposeStatus {
     }
 }
 
+string_enum!(ComposeStatus);
+
 /// This enum represents the type of a bodhi update, of a package, and of builds.
-#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum ContentType {
     /// tag for container image updates
-    #[serde(rename = "container")]
     Container,
     /// tag for flatpak updates
-    #[serde(rename = "flatpak")]
     Flatpak,
     /// tag for module updates
-    #[serde(rename = "module")]
     Module,
     /// tag for traditional RPM package updates
-    #[serde(rename = "rpm")]
     RPM,
+    /// unknown value that is not (yet) supported by this crate
+    Unknown(String),
 }
 
 impl Display for ContentType {
@@ -176,6 +239,7 @@ impl Display for ContentType {
             ContentType::Flatpak => "flatpak",
             ContentType::Module => "module",
             ContentType::RPM => "rpm",
+            ContentType::Unknown(value) => value.as_str(),
         };
 
         write!(f, "{}", value)
@@ -191,7 +255,7 @@ impl TryFrom<&str> for ContentType {
             "flatpak" => Ok(ContentType::Flatpak),
             "module" => Ok(ContentType::Module),
             "rpm" => Ok(ContentType::RPM),
-            _ => Err(InvalidValueError::new("ContentType", value)),
+            _ => Ok(ContentType::Unknown(unknown("ContentType", value)?)),
         }
     }
 }
@@ -204,209 +268,7 @@ impl FromStr for ContentType {
     }
 }
 
-
-/// This enum represents a fedora release.
-#[allow(missing_docs)]
-#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
-#[non_exhaustive]
-// When updating this enum for new releases:
-// - also add them in the impls below,
-// - add them to the idem_fedora_release test,
-// - add integration tests and data for them.
-pub enum FedoraRelease {
-    #[serde(rename = "__current__")]
-    Current,
-    #[serde(rename = "__pending__")]
-    Pending,
-    #[serde(rename = "__archived__")]
-    Archived,
-    F36,
-    F36C,
-    F36F,
-    F36M,
-    F35,
-    F35C,
-    F35F,
-    F35M,
-    F34,
-    F34C,
-    F34F,
-    F34M,
-    F33,
-    F33C,
-    F33F,
-    F33M,
-    F32,
-    F32C,
-    F32F,
-    F32M,
-    F31,
-    F31C,
-    F31F,
-    F31M,
-    F30,
-    F30C,
-    F30F,
-    F30M,
-    F29,
-    F29C,
-    F29F,
-    F29M,
-    F28,
-    F28C,
-    F28M,
-    F27,
-    F27M,
-    F26,
-    F25,
-    F24,
-    F23,
-    F22,
-    F21,
-    #[serde(rename = "EPEL-8")]
-    EPEL8,
-    #[serde(rename = "EPEL-8M")]
-    EPEL8M,
-    #[serde(rename = "EPEL-8N")]
-    EPEL8N,
-    #[serde(rename = "EPEL-7")]
-    EPEL7,
-    #[serde(rename = "EL-6")]
-    EL6,
-    #[serde(rename = "EL-5")]
-    EL5,
-    ELN,
-}
-
-impl Display for FedoraRelease {
-    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
-        let value = match self {
-            FedoraRelease::Current => "__current__",
-            FedoraRelease::Pending => "__pending__",
-            FedoraRelease::Archived => "__archived__",
-            FedoraRelease::F36 => "F36",
-            FedoraRelease::F36C => "F36C",
-            FedoraRelease::F36F => "F36F",
-            FedoraRelease::F36M => "F36M",
-            FedoraRelease::F35 => "F35",
-            FedoraRelease::F35C => "F35C",
-            FedoraRelease::F35F => "F35F",
-            FedoraRelease::F35M => "F35M",
-            FedoraRelease::F34 => "F34",
-            FedoraRelease::F34C => "F34C",
-            FedoraRelease::F34F => "F34F",
-            FedoraRelease::F34M => "F34M",
-            FedoraRelease::F33 => "F33",
-            FedoraRelease::F33C => "F33C",
-            FedoraRelease::F33F => "F33F",
-            FedoraRelease::F33M => "F33M",
-            FedoraRelease::F32 => "F32",
-            FedoraRelease::F32C => "F32C",
-            FedoraRelease::F32F => "F32F",
-            FedoraRelease::F32M => "F32M",
-            FedoraRelease::F31 => "F31",
-            FedoraRelease::F31C => "F31C",
-            FedoraRelease::F31F => "F31F",
-            FedoraRelease::F31M => "F31M",
-            FedoraRelease::F30 => "F30",
-            FedoraRelease::F30C => "F30C",
-            FedoraRelease::F30F => "F30F",
-            FedoraRelease::F30M => "F30M",
-            FedoraRelease::F29 => "F29",
-            FedoraRelease::F29C => "F29C",
-            FedoraRelease::F29F => "F29F",
-            FedoraRelease::F29M => "F29M",
-            FedoraRelease::F28 => "F28",
-            FedoraRelease::F28C => "F28C",
-            FedoraRelease::F28M => "F28M",
-            FedoraRelease::F27 => "F27",
-            FedoraRelease::F27M => "F27M",
-            FedoraRelease::F26 => "F26",
-            FedoraRelease::F25 => "F25",
-            FedoraRelease::F24 => "F24",
-            FedoraRelease::F23 => "F23",
-            FedoraRelease::F22 => "F22",
-            FedoraRelease::F21 => "F21",
-            FedoraRelease::EPEL8 => "EPEL-8",
-            FedoraRelease::EPEL8M => "EPEL-8M",
-            FedoraRelease::EPEL8N => "EPEL-8N",
-            FedoraRelease::EPEL7 => "EPEL-7",
-            FedoraRelease::EL6 => "EL-6",
-            FedoraRelease::EL5 => "EL-5",
-            FedoraRelease::ELN => "ELN",
-        };
-
-        write!(f, "{}", value)
-    }
-}
-
-impl TryFrom<&str> for FedoraRelease {
-    type Error = InvalidValueError;
-
-    fn try_from(value: &str) -> Result<Self, Self::Error> {
-        match value {
-            "F36" => Ok(FedoraRelease::F36),
-            "F36C" => Ok(FedoraRelease::F36C),
-            "F36F" => Ok(FedoraRelease::F36F),
-            "F36M" => Ok(FedoraRelease::F36M),
-            "F35" => Ok(FedoraRelease::F35),
-            "F35C" => Ok(FedoraRelease::F35C),
-            "F35F" => Ok(FedoraRelease::F35F),
-            "F35M" => Ok(FedoraRelease::F35M),
-            "F34" => Ok(FedoraRelease::F34),
-            "F34C" => Ok(FedoraRelease::F34C),
-            "F34F" => Ok(FedoraRelease::F34F),
-            "F34M" => Ok(FedoraRelease::F34M),
-            "F33" => Ok(FedoraRelease::F33),
-            "F33C" => Ok(FedoraRelease::F33C),
-            "F33F" => Ok(FedoraRelease::F33F),
-            "F33M" => Ok(FedoraRelease::F33M),
-            "F32" => Ok(FedoraRelease::F32),
-            "F32C" => Ok(FedoraRelease::F32C),
-            "F32F" => Ok(FedoraRelease::F32F),
-            "F32M" => Ok(FedoraRelease::F32M),
-            "F31" => Ok(FedoraRelease::F31),
-            "F31C" => Ok(FedoraRelease::F31C),
-            "F31F" => Ok(FedoraRelease::F31F),
-            "F31M" => Ok(FedoraRelease::F31M),
-            "F30" => Ok(FedoraRelease::F30),
-            "F30C" => Ok(FedoraRelease::F30C),
-            "F30F" => Ok(FedoraRelease::F30F),
-            "F30M" => Ok(FedoraRelease::F30M),
-            "F29" => Ok(FedoraRelease::F29),
-            "F29C" => Ok(FedoraRelease::F29C),
-            "F29F" => Ok(FedoraRelease::F29F),
-            "F29M" => Ok(FedoraRelease::F29M),
-            "F28" => Ok(FedoraRelease::F28),
-            "F28C" => Ok(FedoraRelease::F28C),
-            "F28M" => Ok(FedoraRelease::F28M),
-            "F27" => Ok(FedoraRelease::F27),
-            "F27M" => Ok(FedoraRelease::F27M),
-            "F26" => Ok(FedoraRelease::F26),
-            "F25" => Ok(FedoraRelease::F25),
-            "F24" => Ok(FedoraRelease::F24),
-            "F23" => Ok(FedoraRelease::F23),
-            "F22" => Ok(FedoraRelease::F22),
-            "F21" => Ok(FedoraRelease::F21),
-            "EPEL-8" => Ok(FedoraRelease::EPEL8),
-            "EPEL-8M" => Ok(FedoraRelease::EPEL8M),
-            "EPEL-8N" => Ok(FedoraRelease::EPEL8N),
-            "EPEL-7" => Ok(FedoraRelease::EPEL7),
-            "EL-6" => Ok(FedoraRelease::EL6),
-            "EL-5" => Ok(FedoraRelease::EL5),
-            "ELN" => Ok(FedoraRelease::ELN),
-            _ => Err(InvalidValueError::new("FedoraRelease", value)),
-        }
-    }
-}
-
-impl FromStr for FedoraRelease {
-    type Err = InvalidValueError;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        TryFrom::try_from(s)
-    }
-}
+string_enum!(ContentType);
 
 
 /// This enum represents a "Karma" value, which is either a positive (+1), neutral (±0), or negative
@@ -462,14 +324,13 @@ impl FromStr for Karma {
 
 /// This enum represents the name of the package manager that's in use on a release.
 #[allow(missing_docs)]
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum PackageManager {
-    #[serde(rename = "dnf")]
     DNF,
-    #[serde(rename = "unspecified")]
     Unspecified,
-    #[serde(rename = "yum")]
     YUM,
+    /// unknown value that is not (yet) supported by this crate
+    Unknown(String),
 }
 
 impl Display for PackageManager {
@@ -478,6 +339,7 @@ impl Display for PackageManager {
             PackageManager::DNF => "dnf",
             PackageManager::Unspecified => "unspecified",
             PackageManager::YUM => "yum",
+            PackageManager::Unknown(value) => value.as_str(),
         };
 
         write!(f, "{}", value)
@@ -490,8 +352,9 @@ impl TryFrom<&str> for PackageManager {
     fn try_from(value: &str) -> Result<Self, Self::Error> {
         match value.to_lowercase().as_str() {
             "dnf" => Ok(PackageManager::DNF),
+            "unspecified" => Ok(PackageManager::Unspecified),
             "yum" => Ok(PackageManager::YUM),
-            _ => Err(InvalidValueError::new("PackageManager", value)),
+            _ => Ok(PackageManager::Unknown(unknown("PackageManager", value)?)),
         }
     }
 }
@@ -504,25 +367,24 @@ impl FromStr for PackageManager {
     }
 }
 
+string_enum!(PackageManager);
+
 
 /// This enum represents the state of a release.
-#[derive(Clone, Copy, Debug, PartialEq, Deserialize, Serialize)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum ReleaseState {
     /// release has been archived after it has reached its EOL
-    #[serde(rename = "archived")]
     Archived,
     /// release is currently supported
-    #[serde(rename = "current")]
     Current,
     /// release is disabled
-    #[serde(rename = "disabled")]
     Disabled,
     /// release is frozen
-    #[serde(rename = "frozen")]
     Frozen,
     /// release is in development
-    #[serde(rename = "pending")]
     Pending,
+    /// unknown value that is not (yet) supported by this crate
+    Unknown(String),
 }
 
 impl Display for ReleaseState {
@@ -533,6 +395,7 @@ impl Display for ReleaseState {
             ReleaseState::Disabled => "disabled",
             ReleaseState::Frozen => "frozen",
             ReleaseState::Pending => "pending",
+            ReleaseState::Unknown(value) => value.as_str(),
         };
 
         write!(f, "{}", value)
@@ -549,7 +412,7 @@ impl TryFrom<&str> for ReleaseState {
             "disabled" => Ok(ReleaseState::Disabled),
             "frozen" => Ok(ReleaseState::Frozen),
             "pending" => Ok(ReleaseState::Pending),
-            _ => Err(InvalidValueError::new("ReleaseState", value)),
+            _ => Ok(ReleaseState::Unknown(unknown("ReleaseState", value)?)),
         }
     }
 }
@@ -562,25 +425,22 @@ impl FromStr for ReleaseState {
     }
 }
 
+string_enum!(ReleaseState);
+
 
 /// This enum represents the test gating status from `greenwave`.
 #[allow(missing_docs)]
-#[derive(Clone, Copy, Debug, PartialEq, Deserialize, Serialize)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum TestGatingStatus {
-    #[serde(rename = "failed")]
     Failed,
-    #[serde(rename = "greenwave_failed")]
     GreenwaveFailed,
-    #[serde(rename = "ignored")]
     Ignored,
-    #[serde(rename = "passed")]
     Passed,
-    #[serde(rename = "queued")]
     Queued,
-    #[serde(rename = "running")]
     Running,
-    #[serde(rename = "waiting")]
     Waiting,
+    /// unknown value that is not (yet) supported by this crate
+    Unknown(String),
 }
 
 impl Display for TestGatingStatus {
@@ -593,6 +453,7 @@ impl Display for TestGatingStatus {
             TestGatingStatus::Queued => "queued",
             TestGatingStatus::Running => "running",
             TestGatingStatus::Waiting => "waiting",
+            TestGatingStatus::Unknown(value) => value.as_str(),
         };
 
         write!(f, "{}", value)
@@ -611,7 +472,7 @@ impl TryFrom<&str> for TestGatingStatus {
             "queued" => Ok(TestGatingStatus::Queued),
             "running" => Ok(TestGatingStatus::Running),
             "waiting" => Ok(TestGatingStatus::Waiting),
-            _ => Err(InvalidValueError::new("TestGatingStatus", value)),
+            _ => Ok(TestGatingStatus::Unknown(unknown("TestGatingStatus", value)?)),
         }
     }
 }
@@ -624,49 +485,24 @@ impl FromStr for TestGatingStatus {
     }
 }
 
-
-/// This enum represents the two possible ways to identify a fedora update:
-/// - internal, numerical ID
-/// - public, human-readable "alias" (`FEDORA-2019-1A2BB23E`)
-#[derive(Debug, Deserialize, Serialize)]
-#[serde(untagged)]
-pub(crate) enum UpdateID {
-    /// identified via numerical update ID
-    ID(u32),
-    /// identified via update alias
-    Alias(String),
-}
-
-impl Display for UpdateID {
-    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
-        let value = match self {
-            UpdateID::ID(number) => number.to_string(),
-            UpdateID::Alias(string) => string.to_owned(),
-        };
-
-        write!(f, "{}", value)
-    }
-}
+string_enum!(TestGatingStatus);
 
 
 /// This enum represents a requested state change of an update.
-#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum UpdateRequest {
     /// request for an update to be marked as "obsolete" (usually when another update supersedes it)
-    #[serde(rename = "obsolete")]
     Obsolete,
     /// request for the update to be "revoked" or removed
-    #[serde(rename = "revoke")]
     Revoke,
     /// request for the update to get pushed to stable
-    #[serde(rename = "stable")]
     Stable,
     /// request for the update to get pushed to testing
-    #[serde(rename = "testing")]
     Testing,
     /// request for the update to get "unpushed" (removed) from testing
-    #[serde(rename = "unpush")]
     Unpush,
+    /// unknown value that is not (yet) supported by this crate
+    Unknown(String),
 }
 
 impl Display for UpdateRequest {
@@ -677,6 +513,7 @@ impl Display for UpdateRequest {
             UpdateRequest::Stable => "stable",
             UpdateRequest::Testing => "testing",
             UpdateRequest::Unpush => "unpush",
+            UpdateRequest::Unknown(value) => value.as_str(),
         };
 
         write!(f, "{}", value)
@@ -693,7 +530,7 @@ impl TryFrom<&str> for UpdateRequest {
             "stable" => Ok(UpdateRequest::Stable),
             "testing" => Ok(UpdateRequest::Testing),
             "unpush" => Ok(UpdateRequest::Unpush),
-            _ => Err(InvalidValueError::new("UpdateRequest", value)),
+            _ => Ok(UpdateRequest::Unknown(unknown("UpdateRequest", value)?)),
         }
     }
 }
@@ -706,22 +543,21 @@ impl FromStr for UpdateRequest {
     }
 }
 
+string_enum!(UpdateRequest);
+
 
 /// This enum represents the associated severity of a bodhi update. This field is required to not be
 /// unspecified for updates with [`UpdateType::Security`](enum.UpdateType.html).
 #[allow(missing_docs)]
-#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum UpdateSeverity {
-    #[serde(rename = "high")]
     High,
-    #[serde(rename = "low")]
     Low,
-    #[serde(rename = "medium")]
     Medium,
-    #[serde(rename = "unspecified")]
     Unspecified,
-    #[serde(rename = "urgent")]
     Urgent,
+    /// unknown value that is not (yet) supported by this crate
+    Unknown(String),
 }
 
 impl Display for UpdateSeverity {
@@ -732,6 +568,7 @@ impl Display for UpdateSeverity {
             UpdateSeverity::Medium => "medium",
             UpdateSeverity::Unspecified => "unspecified",
             UpdateSeverity::Urgent => "urgent",
+            UpdateSeverity::Unknown(value) => value.as_str(),
         };
 
         write!(f, "{}", value)
@@ -748,7 +585,7 @@ impl TryFrom<&str> for UpdateSeverity {
             "medium" => Ok(UpdateSeverity::Medium),
             "unspecified" => Ok(UpdateSeverity::Unspecified),
             "urgent" => Ok(UpdateSeverity::Urgent),
-            _ => Err(InvalidValueError::new("UpdateSeverity", value)),
+            _ => Ok(UpdateSeverity::Unknown(unknown("UpdateSeverity", value)?)),
         }
     }
 }
@@ -761,31 +598,28 @@ impl FromStr for UpdateSeverity {
     }
 }
 
+string_enum!(UpdateSeverity);
+
 
 /// This enum represents the current state of a bodhi update.
-#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum UpdateStatus {
     /// tag for updates that have been obsoleted by another update
-    #[serde(rename = "obsolete")]
     Obsolete,
     /// tag for updates that are pending for either testing or stable
-    #[serde(rename = "pending")]
     Pending,
     /// tag for updates that are associated with an active side tag
-    #[serde(rename = "side_tag_active")]
     SideTagActive,
     /// tag for updates that are associated with an expired side tag
-    #[serde(rename = "side_tag_expired")]
     SideTagExpired,
     /// tag for updates that have been pushed to stable
-    #[serde(rename = "stable")]
     Stable,
     /// tag for updates that have been pushed to testing
-    #[serde(rename = "testing")]
     Testing,
     /// tag for updates that have been "unpushed" from testing
-    #[serde(rename = "unpushed")]
     Unpushed,
+    /// unknown value that is not (yet) supported by this crate
+    Unknown(String),
 }
 
 impl Display for UpdateStatus {
@@ -798,6 +632,7 @@ impl Display for UpdateStatus {
             UpdateStatus::Stable => "stable",
             UpdateStatus::Testing => "testing",
             UpdateStatus::Unpushed => "unpushed",
+            UpdateStatus::Unknown(value) => value.as_str(),
         };
 
         write!(f, "{}", value)
@@ -816,7 +651,7 @@ impl TryFrom<&str> for UpdateStatus {
             "stable" => Ok(UpdateStatus::Stable),
             "testing" => Ok(UpdateStatus::Testing),
             "unpushed" => Ok(UpdateStatus::Unpushed),
-            _ => Err(InvalidValueError::new("UpdateStatus", value)),
+            _ => Ok(UpdateStatus::Unknown(unknown("UpdateStatus", value)?)),
         }
     }
 }
@@ -829,19 +664,20 @@ impl FromStr for UpdateStatus {
     }
 }
 
+string_enum!(UpdateStatus);
+
 
 /// This enum represents the associated suggested action for a bodhi update.
-#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum UpdateSuggestion {
     /// recommendation to log out for the update to get applied
-    #[serde(rename = "logout")]
     Logout,
     /// recommendation to reboot for the update to get applied
-    #[serde(rename = "reboot")]
     Reboot,
     /// no recommendation
-    #[serde(rename = "unspecified")]
     Unspecified,
+    /// unknown value that is not (yet) supported by this crate
+    Unknown(String),
 }
 
 impl Display for UpdateSuggestion {
@@ -850,6 +686,7 @@ impl Display for UpdateSuggestion {
             UpdateSuggestion::Logout => "logout",
             UpdateSuggestion::Reboot => "reboot",
             UpdateSuggestion::Unspecified => "unspecified",
+            UpdateSuggestion::Unknown(value) => value.as_str(),
         };
 
         write!(f, "{}", value)
@@ -864,7 +701,7 @@ impl TryFrom<&str> for UpdateSuggestion {
             "logout" => Ok(UpdateSuggestion::Logout),
             "reboot" => Ok(UpdateSuggestion::Reboot),
             "unspecified" => Ok(UpdateSuggestion::Unspecified),
-            _ => Err(InvalidValueError::new("UpdateSuggestion", value)),
+            _ => Ok(UpdateSuggestion::Unknown(unknown("UpdateSuggestion", value)?)),
         }
     }
 }
@@ -877,21 +714,20 @@ impl FromStr for UpdateSuggestion {
     }
 }
 
+string_enum!(UpdateSuggestion);
+
 
 /// This enum represents the type of a bodhi update.
 #[allow(missing_docs)]
-#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum UpdateType {
-    #[serde(rename = "bugfix")]
     BugFix,
-    #[serde(rename = "enhancement")]
     Enhancement,
-    #[serde(rename = "newpackage")]
     NewPackage,
-    #[serde(rename = "security")]
     Security,
-    #[serde(rename = "unspecified")]
     Unspecified,
+    /// unknown value that is not (yet) supported by this crate
+    Unknown(String),
 }
 
 impl Display for UpdateType {
@@ -902,6 +738,7 @@ impl Display for UpdateType {
             UpdateType::NewPackage => "newpackage",
             UpdateType::Security => "security",
             UpdateType::Unspecified => "unspecified",
+            UpdateType::Unknown(value) => value.as_str(),
         };
 
         write!(f, "{}", value)
@@ -918,7 +755,7 @@ impl TryFrom<&str> for UpdateType {
             "newpackage" => Ok(UpdateType::NewPackage),
             "security" => Ok(UpdateType::Security),
             "unspecified" => Ok(UpdateType::Unspecified),
-            _ => Err(InvalidValueError::new("UpdateType", value)),
+            _ => Ok(UpdateType::Unknown(unknown("UpdateType", value)?)),
         }
     }
 }
@@ -930,3 +767,5 @@ impl FromStr for UpdateType {
         TryFrom::try_from(s)
     }
 }
+
+string_enum!(UpdateType);
@@ -0,0 +1,268 @@
+use std::convert::TryFrom;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use url::Url;
+
+use super::enums::InvalidValueError;
+
+/// This struct represents the public, human-readable alias of a bodhi update, which consists of an
+/// ID prefix (`FEDORA`, `FEDORA-EPEL`, `FEDORA-CONTAINER`, etc.), the year the update was created
+/// in, and a unique hash (for example, `FEDORA-2019-cf87377f5f`).
+///
+/// ```
+/// # use bodhi::UpdateAlias;
+/// let alias: UpdateAlias = "FEDORA-EPEL-2020-a1b2c3d4e5".parse().unwrap();
+///
+/// assert_eq!(alias.prefix(), "FEDORA-EPEL");
+/// assert_eq!(alias.year(), 2020);
+/// assert_eq!(alias.hash(), "a1b2c3d4e5");
+///
+/// assert!("FEDORA-2020".parse::<UpdateAlias>().is_err());
+/// ```
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct UpdateAlias {
+    alias: String,
+    year: u32,
+}
+
+impl UpdateAlias {
+    /// This method returns the string representation of this alias.
+    pub fn as_str(&self) -> &str {
+        &self.alias
+    }
+
+    /// This method returns the ID prefix of this alias (for example, `FEDORA-EPEL`).
+    pub fn prefix(&self) -> &str {
+        // the prefix ends before the "-YYYY-" part, which is validated when parsing
+        let end = self.alias.len() - self.hash().len() - 6;
+        &self.alias[..end]
+    }
+
+    /// This method returns the year in which the update was created.
+    pub fn year(&self) -> u32 {
+        self.year
+    }
+
+    /// This method returns the unique hash part of this alias.
+    pub fn hash(&self) -> &str {
+        match self.alias.rfind('-') {
+            Some(index) => &self.alias[index + 1..],
+            None => unreachable!("Update aliases are validated when parsing, this should not happen."),
+        }
+    }
+}
+
+impl AsRef<str> for UpdateAlias {
+    fn as_ref(&self) -> &str {
+        &self.alias
+    }
+}
+
+impl Display for UpdateAlias {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.alias)
+    }
+}
+
+impl TryFrom<&str> for UpdateAlias {
+    type Error = InvalidValueError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let invalid = || InvalidValueError::new("UpdateAlias", value);
+
+        let mut parts = value.rsplitn(3, '-');
+
+        let hash = parts.next().ok_or_else(invalid)?;
+        let year = parts.next().ok_or_else(invalid)?;
+        let prefix = parts.next().ok_or_else(invalid)?;
+
+        if hash.is_empty() || !hash.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return Err(invalid());
+        }
+
+        if year.len() != 4 || !year.chars().all(|c| c.is_ascii_digit()) {
+            return Err(invalid());
+        }
+
+        let valid_prefix = prefix.starts_with("FEDORA")
+            && prefix
+                .split('-')
+                .all(|part| !part.is_empty() && part.chars().all(|c| c.is_ascii_uppercase()));
+
+        if !valid_prefix {
+            return Err(invalid());
+        }
+
+        Ok(UpdateAlias {
+            alias: value.to_owned(),
+            year: year.parse().map_err(|_| invalid())?,
+        })
+    }
+}
+
+impl FromStr for UpdateAlias {
+    type Err = InvalidValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        TryFrom::try_from(s)
+    }
+}
+
+impl Serialize for UpdateAlias {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.alias)
+    }
+}
+
+impl<'de> Deserialize<'de> for UpdateAlias {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let string = String::deserialize(deserializer)?;
+        UpdateAlias::try_from(string.as_str()).map_err(serde::de::Error::custom)
+    }
+}
+
+
+/// This enum represents the two possible ways to identify a fedora update:
+/// - internal, numerical ID
+/// - public, human-readable "alias" (`FEDORA-2019-cf87377f5f`)
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum UpdateID {
+    /// identified via numerical update ID
+    ID(u32),
+    /// identified via update alias
+    Alias(UpdateAlias),
+}
+
+impl From<u32> for UpdateID {
+    fn from(id: u32) -> Self {
+        UpdateID::ID(id)
+    }
+}
+
+impl From<UpdateAlias> for UpdateID {
+    fn from(alias: UpdateAlias) -> Self {
+        UpdateID::Alias(alias)
+    }
+}
+
+impl From<&UpdateAlias> for UpdateID {
+    fn from(alias: &UpdateAlias) -> Self {
+        UpdateID::Alias(alias.clone())
+    }
+}
+
+impl Display for UpdateID {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            UpdateID::ID(number) => write!(f, "{}", number),
+            UpdateID::Alias(alias) => write!(f, "{}", alias),
+        }
+    }
+}
+
+impl TryFrom<&str> for UpdateID {
+    type Error = InvalidValueError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.parse::<u32>() {
+            Ok(number) => Ok(UpdateID::ID(number)),
+            Err(_) => match UpdateAlias::try_from(value) {
+                Ok(alias) => Ok(UpdateID::Alias(alias)),
+                Err(_) => Err(InvalidValueError::new("UpdateID", value)),
+            },
+        }
+    }
+}
+
+impl FromStr for UpdateID {
+    type Err = InvalidValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        TryFrom::try_from(s)
+    }
+}
+
+
+/// This struct represents the ID of a bug on Red Hat BugZilla. When parsing from a string, the
+/// `#1234567` and `rhbz#1234567` notations are also accepted.
+///
+/// ```
+/// # use bodhi::BugId;
+/// let bug: BugId = "rhbz#1234567".parse().unwrap();
+/// assert_eq!(bug, BugId::from(1234567));
+///
+/// assert!("rhbz#12a".parse::<BugId>().is_err());
+/// ```
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(transparent)]
+pub struct BugId {
+    id: u32,
+}
+
+impl BugId {
+    /// This method creates a new `BugId` from its numerical value.
+    pub const fn new(id: u32) -> Self {
+        BugId { id }
+    }
+
+    /// This method returns the numerical value of this bug ID.
+    pub const fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// This method constructs the URL of the bug on Red Hat BugZilla.
+    pub fn url(&self) -> Url {
+        Url::parse(&format!("https://bugzilla.redhat.com/show_bug.cgi?id={}", self.id))
+            .expect("Failed to parse the hard-coded URL, this should not happen.")
+    }
+}
+
+impl From<u32> for BugId {
+    fn from(id: u32) -> Self {
+        BugId { id }
+    }
+}
+
+impl Display for BugId {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.id)
+    }
+}
+
+impl TryFrom<&str> for BugId {
+    type Error = InvalidValueError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let lower = value.trim().to_lowercase();
+        let number = lower
+            .strip_prefix("rhbz#")
+            .or_else(|| lower.strip_prefix('#'))
+            .unwrap_or(&lower);
+
+        if number.is_empty() || !number.chars().all(|c| c.is_ascii_digit()) {
+            return Err(InvalidValueError::new("BugId", value));
+        }
+
+        match number.parse() {
+            Ok(id) if id != 0 => Ok(BugId { id }),
+            _ => Err(InvalidValueError::new("BugId", value)),
+        }
+    }
+}
+
+impl FromStr for BugId {
+    type Err = InvalidValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        TryFrom::try_from(s)
+    }
+}
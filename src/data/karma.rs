@@ -0,0 +1,173 @@
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+
+use super::enums::Karma;
+use super::types::{Comment, Update};
+
+/// user name of the bodhi system user, which posts automated comments
+const BODHI_USER: &str = "bodhi";
+
+/// user names of automation accounts, whose comments are not counted as feedback
+const BOT_USERS: &[&str] = &[BODHI_USER, "autoqa", "taskotron"];
+
+/// substrings of the comments that bodhi posts when builds are added or removed, which resets karma
+const KARMA_RESET_MARKERS: &[&str] = &["Karma has been reset", "New build", "Removed build"];
+
+/// This struct contains the number of positive, neutral, and negative feedback items for one bug or
+/// test case.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct FeedbackTally {
+    /// number of users who gave positive feedback
+    pub positive: u32,
+    /// number of users who gave neutral feedback
+    pub neutral: u32,
+    /// number of users who gave negative feedback
+    pub negative: u32,
+}
+
+impl FeedbackTally {
+    fn add(&mut self, karma: Karma) {
+        match karma {
+            Karma::Positive => self.positive += 1,
+            Karma::Neutral => self.neutral += 1,
+            Karma::Negative => self.negative += 1,
+        }
+    }
+
+    /// This method returns the sum of all feedback (positive minus negative).
+    pub fn total(&self) -> i32 {
+        self.positive as i32 - self.negative as i32
+    }
+}
+
+/// This struct contains the karma and feedback of an update, as computed by
+/// [`Update::karma_summary`](struct.Update.html#method.karma_summary).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct KarmaSummary {
+    /// latest non-neutral karma of every user who voted since karma was last reset
+    pub users: BTreeMap<String, Karma>,
+    /// feedback for every bug, keyed by bug ID
+    pub bugs: BTreeMap<u32, FeedbackTally>,
+    /// feedback for every test case, keyed by test case name
+    pub testcases: BTreeMap<String, FeedbackTally>,
+    /// sum of positive karma
+    pub positive: i32,
+    /// sum of negative karma (zero or less)
+    pub negative: i32,
+    /// flag to indicate whether the update can be pushed to stable automatically based on karma
+    pub autokarma: bool,
+    /// stable karma threshold of the update
+    pub stable_karma: Option<i32>,
+    /// unstable karma threshold of the update
+    pub unstable_karma: Option<i32>,
+}
+
+impl KarmaSummary {
+    /// This method returns the total karma (sum of positive and negative karma).
+    pub fn total(&self) -> i32 {
+        self.positive + self.negative
+    }
+
+    /// This method returns `true` if the total karma has reached the stable karma threshold.
+    pub fn is_stable_karma_reached(&self) -> bool {
+        matches!(self.stable_karma, Some(threshold) if self.total() >= threshold)
+    }
+
+    /// This method returns `true` if the total karma has reached the unstable karma threshold.
+    pub fn is_unstable_karma_reached(&self) -> bool {
+        matches!(self.unstable_karma, Some(threshold) if self.total() <= threshold)
+    }
+
+    /// This method returns `true` if autokarma is enabled and the stable karma threshold has been
+    /// reached, so bodhi will push the update to stable automatically.
+    pub fn is_autokarma_met(&self) -> bool {
+        self.autokarma && self.is_stable_karma_reached()
+    }
+}
+
+impl Update {
+    /// This method computes the karma and feedback of this update from its comments, using the
+    /// same rules as bodhi:
+    ///
+    /// - only comments since karma was last reset (when builds were added or removed) are counted,
+    /// - only the latest positive or negative karma of every user is counted (comments without
+    ///   karma do not replace earlier karma),
+    /// - comments by the submitter of the update and by automation accounts are not counted.
+    ///
+    /// For bugs and test cases, the latest feedback of every user is counted. If the update was
+    /// fetched without its comments, the summary is empty.
+    pub fn karma_summary(&self) -> KarmaSummary {
+        let mut summary = KarmaSummary {
+            autokarma: self.autokarma,
+            stable_karma: self.stable_karma,
+            unstable_karma: self.unstable_karma,
+            ..Default::default()
+        };
+
+        let mut comments: Vec<&Comment> = match &self.comments {
+            Some(comments) => comments.iter().collect(),
+            None => return summary,
+        };
+
+        // newest comments first, so the first comment of every user is their latest one
+        comments.sort_by(|a, b| {
+            b.timestamp
+                .partial_cmp(&a.timestamp)
+                .unwrap_or(Ordering::Equal)
+                .then(b.id.cmp(&a.id))
+        });
+
+        let mut bugs: BTreeMap<(u32, &str), Karma> = BTreeMap::new();
+        let mut testcases: BTreeMap<(&str, &str), Karma> = BTreeMap::new();
+
+        for comment in comments {
+            let user = comment.user.name.as_str();
+
+            if is_karma_reset(comment) {
+                break;
+            }
+
+            if user == self.user.name || BOT_USERS.contains(&user) {
+                continue;
+            }
+
+            // like bodhi, only comments that carry karma count as a user's vote, so a plain comment
+            // does not cancel earlier karma
+            if comment.karma != Karma::Neutral {
+                summary.users.entry(user.to_owned()).or_insert(comment.karma);
+            }
+
+            for feedback in &comment.bug_feedback {
+                bugs.entry((feedback.bug_id, user)).or_insert(feedback.karma);
+            }
+
+            for feedback in &comment.testcase_feedback {
+                testcases
+                    .entry((feedback.testcase.name.as_str(), user))
+                    .or_insert(feedback.karma);
+            }
+        }
+
+        for karma in summary.users.values() {
+            match karma {
+                Karma::Positive => summary.positive += 1,
+                Karma::Neutral => {},
+                Karma::Negative => summary.negative -= 1,
+            }
+        }
+
+        for ((bug_id, _), karma) in bugs {
+            summary.bugs.entry(bug_id).or_default().add(karma);
+        }
+
+        for ((name, _), karma) in testcases {
+            summary.testcases.entry(name.to_owned()).or_default().add(karma);
+        }
+
+        summary
+    }
+}
+
+fn is_karma_reset(comment: &Comment) -> bool {
+    comment.user.name == BODHI_USER && KARMA_RESET_MARKERS.iter().any(|marker| comment.text.contains(marker))
+}
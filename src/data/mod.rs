@@ -6,18 +6,42 @@
 //! set of enumerated values. To abstract this, the corresponding query filters accept some of the
 //! enum types defined here, instead of the String arguments directly.
 
+mod archive;
+pub use archive::*;
+
 mod dates;
 pub use dates::*;
 
 mod enums;
 pub use enums::*;
 
+mod identifiers;
+pub use identifiers::*;
+
+mod karma;
+pub use karma::*;
+
+mod nvr;
+pub use nvr::*;
+
+mod release;
+pub use release::*;
+
+mod rpm;
+pub use rpm::*;
+
+mod report;
+pub use report::*;
+
 mod schemas;
 pub(crate) use schemas::*;
 
 mod types;
 pub use types::*;
 
+mod version;
+pub use version::*;
+
 /// base URL of the fedora bodhi instance
 pub const FEDORA_BODHI_URL: &str = "https://bodhi.fedoraproject.org";
 
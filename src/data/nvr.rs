@@ -0,0 +1,166 @@
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::enums::InvalidValueError;
+use super::rpm::rpmvercmp;
+
+/// This struct represents the "Name-Version-Release" identifier of a build, optionally including
+/// an epoch (`name-epoch:version-release`). Values are equal if they have the same string
+/// representation. Versions are compared separately with [`Nvr::evr_cmp`](#method.evr_cmp) and
+/// [`Nvr::nvr_cmp`](#method.nvr_cmp), which use the same algorithm as RPM (so `1.0` and `1.00` are
+/// different values, but equivalent versions).
+///
+/// ```
+/// # use bodhi::Nvr;
+/// # use std::cmp::Ordering;
+/// let nvr: Nvr = "rust-1.40.0-1.fc31".parse().unwrap();
+///
+/// assert_eq!(nvr.name(), "rust");
+/// assert_eq!(nvr.version(), "1.40.0");
+/// assert_eq!(nvr.release(), "1.fc31");
+///
+/// assert_eq!(nvr.evr_cmp(&"rust-1.40.0-2.fc31".parse().unwrap()), Ordering::Less);
+/// assert_eq!(nvr.evr_cmp(&"rust-1:1.39.0-1.fc31".parse().unwrap()), Ordering::Less);
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Nvr {
+    nvr: String,
+    name: String,
+    epoch: Option<u32>,
+    version: String,
+    release: String,
+}
+
+impl Nvr {
+    /// This method creates a new `Nvr` from its components.
+    pub fn new(name: &str, epoch: Option<u32>, version: &str, release: &str) -> Self {
+        let nvr = match epoch {
+            Some(epoch) => format!("{}-{}:{}-{}", name, epoch, version, release),
+            None => format!("{}-{}-{}", name, version, release),
+        };
+
+        Nvr {
+            nvr,
+            name: name.to_owned(),
+            epoch,
+            version: version.to_owned(),
+            release: release.to_owned(),
+        }
+    }
+
+    /// This method returns the string representation of this value.
+    pub fn as_str(&self) -> &str {
+        &self.nvr
+    }
+
+    /// This method returns the (package) name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// This method returns the epoch, if it was specified.
+    pub fn epoch(&self) -> Option<u32> {
+        self.epoch
+    }
+
+    /// This method returns the version.
+    pub fn version(&self) -> &str {
+        &self.version
+    }
+
+    /// This method returns the release.
+    pub fn release(&self) -> &str {
+        &self.release
+    }
+
+    /// This method compares the epoch, version, and release of two values, ignoring their names.
+    /// A missing epoch is treated like an epoch of `0`.
+    pub fn evr_cmp(&self, other: &Nvr) -> Ordering {
+        self.epoch
+            .unwrap_or(0)
+            .cmp(&other.epoch.unwrap_or(0))
+            .then_with(|| rpmvercmp(&self.version, &other.version))
+            .then_with(|| rpmvercmp(&self.release, &other.release))
+    }
+
+    /// This method compares two values by name first, and then by their epoch, version, and
+    /// release. It can be used for sorting lists of NVRs (`nvrs.sort_by(Nvr::nvr_cmp)`).
+    pub fn nvr_cmp(&self, other: &Nvr) -> Ordering {
+        self.name.cmp(&other.name).then_with(|| self.evr_cmp(other))
+    }
+}
+
+impl AsRef<str> for Nvr {
+    fn as_ref(&self) -> &str {
+        &self.nvr
+    }
+}
+
+impl Display for Nvr {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.nvr)
+    }
+}
+
+impl TryFrom<&str> for Nvr {
+    type Error = InvalidValueError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let invalid = || InvalidValueError::new("Nvr", value);
+
+        let mut parts = value.rsplitn(3, '-');
+
+        let release = parts.next().ok_or_else(invalid)?;
+        let version = parts.next().ok_or_else(invalid)?;
+        let name = parts.next().ok_or_else(invalid)?;
+
+        let (epoch, version) = match version.find(':') {
+            Some(index) => {
+                let epoch = version[..index].parse::<u32>().map_err(|_| invalid())?;
+                (Some(epoch), &version[index + 1..])
+            },
+            None => (None, version),
+        };
+
+        if name.is_empty() || version.is_empty() || release.is_empty() {
+            return Err(invalid());
+        }
+
+        if value.chars().any(char::is_whitespace) || version.contains(':') || release.contains(':') {
+            return Err(invalid());
+        }
+
+        Ok(Nvr::new(name, epoch, version, release))
+    }
+}
+
+impl FromStr for Nvr {
+    type Err = InvalidValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        TryFrom::try_from(s)
+    }
+}
+
+impl Serialize for Nvr {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.nvr)
+    }
+}
+
+impl<'de> Deserialize<'de> for Nvr {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let string = String::deserialize(deserializer)?;
+        Nvr::try_from(string.as_str()).map_err(serde::de::Error::custom)
+    }
+}
@@ -0,0 +1,549 @@
+use std::convert::TryFrom;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::enums::InvalidValueError;
+
+/// This enum represents the product (or distribution) a release belongs to.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum ReleaseProduct {
+    /// Fedora releases (for example, "F36")
+    Fedora,
+    /// Extra Packages for Enterprise Linux (for example, "EPEL-8")
+    EPEL,
+    /// (legacy) Enterprise Linux releases (for example, "EL-6")
+    EL,
+    /// Fedora ELN ("ELN")
+    ELN,
+}
+
+impl Display for ReleaseProduct {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        let value = match self {
+            ReleaseProduct::Fedora => "Fedora",
+            ReleaseProduct::EPEL => "EPEL",
+            ReleaseProduct::EL => "EL",
+            ReleaseProduct::ELN => "ELN",
+        };
+
+        write!(f, "{}", value)
+    }
+}
+
+/// This enum represents the variant of a release, which is identified by a one-letter suffix in
+/// the release name (for example, the "C" in "F36C").
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum ReleaseVariant {
+    /// container images ("C" suffix)
+    Container,
+    /// flatpak applications ("F" suffix)
+    Flatpak,
+    /// modules ("M" suffix)
+    Modular,
+    /// EPEL Next ("N" suffix)
+    Next,
+}
+
+impl ReleaseVariant {
+    fn suffix(self) -> char {
+        match self {
+            ReleaseVariant::Container => 'C',
+            ReleaseVariant::Flatpak => 'F',
+            ReleaseVariant::Modular => 'M',
+            ReleaseVariant::Next => 'N',
+        }
+    }
+
+    fn from_suffix(suffix: char) -> Option<Self> {
+        match suffix {
+            'C' => Some(ReleaseVariant::Container),
+            'F' => Some(ReleaseVariant::Flatpak),
+            'M' => Some(ReleaseVariant::Modular),
+            'N' => Some(ReleaseVariant::Next),
+            _ => None,
+        }
+    }
+}
+
+impl Display for ReleaseVariant {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        let value = match self {
+            ReleaseVariant::Container => "Container",
+            ReleaseVariant::Flatpak => "Flatpak",
+            ReleaseVariant::Modular => "Modular",
+            ReleaseVariant::Next => "Next",
+        };
+
+        write!(f, "{}", value)
+    }
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+enum ReleaseName {
+    Current,
+    Pending,
+    Archived,
+    Release {
+        product: ReleaseProduct,
+        version: Option<u32>,
+        minor: Option<u32>,
+        variant: Option<ReleaseVariant>,
+    },
+    Other(String),
+}
+
+impl ReleaseName {
+    /// This function stores the given name as-is, if it only contains characters that are used in
+    /// release names.
+    fn raw(value: &str) -> Option<Self> {
+        let valid = |c: char| c.is_ascii_alphanumeric() || c == '-' || c == '.' || c == '_';
+
+        if value.is_empty() || !value.chars().all(valid) {
+            return None;
+        }
+
+        Some(ReleaseName::Other(value.to_owned()))
+    }
+}
+
+/// This struct represents the name of a fedora release, as it is used by bodhi (for example, "F36",
+/// "F36C", "EPEL-8N", "EPEL-10.1", or "ELN"). Any name that follows bodhi's naming scheme can be
+/// parsed, so new releases are supported without needing changes in this crate. Constants are
+/// provided for releases that were known when this crate was published.
+///
+/// Any other name that consists of ASCII letters, digits, and the characters `-`, `.`, and `_` is
+/// kept as-is (including names with a known product and version, but an unknown variant suffix),
+/// so new naming schemes do not break deserialization of updates and releases. Parsing and
+/// deserialization accept the same names, so the string representation of every release can be
+/// parsed again.
+///
+/// Additionally, the pseudo-releases `__current__`, `__pending__`, and `__archived__` can be used
+/// as query filters for all releases in the corresponding state.
+///
+/// ```
+/// # use bodhi::{FedoraRelease, ReleaseProduct, ReleaseVariant};
+/// let release: FedoraRelease = "EPEL-9N".parse().unwrap();
+///
+/// assert_eq!(release.product(), Some(ReleaseProduct::EPEL));
+/// assert_eq!(release.version(), Some(9));
+/// assert_eq!(release.variant(), Some(ReleaseVariant::Next));
+/// assert_eq!(release, FedoraRelease::epel(9).with_variant(ReleaseVariant::Next));
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct FedoraRelease {
+    name: ReleaseName,
+}
+
+/// constants for known releases and pseudo-releases
+impl FedoraRelease {
+    /// all current releases
+    #[allow(non_upper_case_globals)]
+    pub const Current: FedoraRelease = FedoraRelease {
+        name: ReleaseName::Current,
+    };
+    /// all pending releases
+    #[allow(non_upper_case_globals)]
+    pub const Pending: FedoraRelease = FedoraRelease {
+        name: ReleaseName::Pending,
+    };
+    /// all archived releases
+    #[allow(non_upper_case_globals)]
+    pub const Archived: FedoraRelease = FedoraRelease {
+        name: ReleaseName::Archived,
+    };
+
+    /// Fedora 37
+    pub const F37: FedoraRelease = FedoraRelease::fedora(37);
+    /// Fedora 37 (Container)
+    pub const F37C: FedoraRelease =
+        FedoraRelease::release_variant(ReleaseProduct::Fedora, 37, ReleaseVariant::Container);
+    /// Fedora 37 (Flatpak)
+    pub const F37F: FedoraRelease = FedoraRelease::release_variant(ReleaseProduct::Fedora, 37, ReleaseVariant::Flatpak);
+    /// Fedora 36
+    pub const F36: FedoraRelease = FedoraRelease::fedora(36);
+    /// Fedora 36 (Container)
+    pub const F36C: FedoraRelease =
+        FedoraRelease::release_variant(ReleaseProduct::Fedora, 36, ReleaseVariant::Container);
+    /// Fedora 36 (Flatpak)
+    pub const F36F: FedoraRelease = FedoraRelease::release_variant(ReleaseProduct::Fedora, 36, ReleaseVariant::Flatpak);
+    /// Fedora 36 (Modular)
+    pub const F36M: FedoraRelease = FedoraRelease::release_variant(ReleaseProduct::Fedora, 36, ReleaseVariant::Modular);
+    /// Fedora 35
+    pub const F35: FedoraRelease = FedoraRelease::fedora(35);
+    /// Fedora 35 (Container)
+    pub const F35C: FedoraRelease =
+        FedoraRelease::release_variant(ReleaseProduct::Fedora, 35, ReleaseVariant::Container);
+    /// Fedora 35 (Flatpak)
+    pub const F35F: FedoraRelease = FedoraRelease::release_variant(ReleaseProduct::Fedora, 35, ReleaseVariant::Flatpak);
+    /// Fedora 35 (Modular)
+    pub const F35M: FedoraRelease = FedoraRelease::release_variant(ReleaseProduct::Fedora, 35, ReleaseVariant::Modular);
+    /// Fedora 34
+    pub const F34: FedoraRelease = FedoraRelease::fedora(34);
+    /// Fedora 34 (Container)
+    pub const F34C: FedoraRelease =
+        FedoraRelease::release_variant(ReleaseProduct::Fedora, 34, ReleaseVariant::Container);
+    /// Fedora 34 (Flatpak)
+    pub const F34F: FedoraRelease = FedoraRelease::release_variant(ReleaseProduct::Fedora, 34, ReleaseVariant::Flatpak);
+    /// Fedora 34 (Modular)
+    pub const F34M: FedoraRelease = FedoraRelease::release_variant(ReleaseProduct::Fedora, 34, ReleaseVariant::Modular);
+    /// Fedora 33
+    pub const F33: FedoraRelease = FedoraRelease::fedora(33);
+    /// Fedora 33 (Container)
+    pub const F33C: FedoraRelease =
+        FedoraRelease::release_variant(ReleaseProduct::Fedora, 33, ReleaseVariant::Container);
+    /// Fedora 33 (Flatpak)
+    pub const F33F: FedoraRelease = FedoraRelease::release_variant(ReleaseProduct::Fedora, 33, ReleaseVariant::Flatpak);
+    /// Fedora 33 (Modular)
+    pub const F33M: FedoraRelease = FedoraRelease::release_variant(ReleaseProduct::Fedora, 33, ReleaseVariant::Modular);
+    /// Fedora 32
+    pub const F32: FedoraRelease = FedoraRelease::fedora(32);
+    /// Fedora 32 (Container)
+    pub const F32C: FedoraRelease =
+        FedoraRelease::release_variant(ReleaseProduct::Fedora, 32, ReleaseVariant::Container);
+    /// Fedora 32 (Flatpak)
+    pub const F32F: FedoraRelease = FedoraRelease::release_variant(ReleaseProduct::Fedora, 32, ReleaseVariant::Flatpak);
+    /// Fedora 32 (Modular)
+    pub const F32M: FedoraRelease = FedoraRelease::release_variant(ReleaseProduct::Fedora, 32, ReleaseVariant::Modular);
+    /// Fedora 31
+    pub const F31: FedoraRelease = FedoraRelease::fedora(31);
+    /// Fedora 31 (Container)
+    pub const F31C: FedoraRelease =
+        FedoraRelease::release_variant(ReleaseProduct::Fedora, 31, ReleaseVariant::Container);
+    /// Fedora 31 (Flatpak)
+    pub const F31F: FedoraRelease = FedoraRelease::release_variant(ReleaseProduct::Fedora, 31, ReleaseVariant::Flatpak);
+    /// Fedora 31 (Modular)
+    pub const F31M: FedoraRelease = FedoraRelease::release_variant(ReleaseProduct::Fedora, 31, ReleaseVariant::Modular);
+    /// Fedora 30
+    pub const F30: FedoraRelease = FedoraRelease::fedora(30);
+    /// Fedora 30 (Container)
+    pub const F30C: FedoraRelease =
+        FedoraRelease::release_variant(ReleaseProduct::Fedora, 30, ReleaseVariant::Container);
+    /// Fedora 30 (Flatpak)
+    pub const F30F: FedoraRelease = FedoraRelease::release_variant(ReleaseProduct::Fedora, 30, ReleaseVariant::Flatpak);
+    /// Fedora 30 (Modular)
+    pub const F30M: FedoraRelease = FedoraRelease::release_variant(ReleaseProduct::Fedora, 30, ReleaseVariant::Modular);
+    /// Fedora 29
+    pub const F29: FedoraRelease = FedoraRelease::fedora(29);
+    /// Fedora 29 (Container)
+    pub const F29C: FedoraRelease =
+        FedoraRelease::release_variant(ReleaseProduct::Fedora, 29, ReleaseVariant::Container);
+    /// Fedora 29 (Flatpak)
+    pub const F29F: FedoraRelease = FedoraRelease::release_variant(ReleaseProduct::Fedora, 29, ReleaseVariant::Flatpak);
+    /// Fedora 29 (Modular)
+    pub const F29M: FedoraRelease = FedoraRelease::release_variant(ReleaseProduct::Fedora, 29, ReleaseVariant::Modular);
+    /// Fedora 28
+    pub const F28: FedoraRelease = FedoraRelease::fedora(28);
+    /// Fedora 28 (Container)
+    pub const F28C: FedoraRelease =
+        FedoraRelease::release_variant(ReleaseProduct::Fedora, 28, ReleaseVariant::Container);
+    /// Fedora 28 (Modular)
+    pub const F28M: FedoraRelease = FedoraRelease::release_variant(ReleaseProduct::Fedora, 28, ReleaseVariant::Modular);
+    /// Fedora 27
+    pub const F27: FedoraRelease = FedoraRelease::fedora(27);
+    /// Fedora 27 (Modular)
+    pub const F27M: FedoraRelease = FedoraRelease::release_variant(ReleaseProduct::Fedora, 27, ReleaseVariant::Modular);
+    /// Fedora 26
+    pub const F26: FedoraRelease = FedoraRelease::fedora(26);
+    /// Fedora 25
+    pub const F25: FedoraRelease = FedoraRelease::fedora(25);
+    /// Fedora 24
+    pub const F24: FedoraRelease = FedoraRelease::fedora(24);
+    /// Fedora 23
+    pub const F23: FedoraRelease = FedoraRelease::fedora(23);
+    /// Fedora 22
+    pub const F22: FedoraRelease = FedoraRelease::fedora(22);
+    /// Fedora 21
+    pub const F21: FedoraRelease = FedoraRelease::fedora(21);
+    /// EPEL 9
+    pub const EPEL9: FedoraRelease = FedoraRelease::epel(9);
+    /// EPEL 9 (Next)
+    pub const EPEL9N: FedoraRelease = FedoraRelease::release_variant(ReleaseProduct::EPEL, 9, ReleaseVariant::Next);
+    /// EPEL 8
+    pub const EPEL8: FedoraRelease = FedoraRelease::epel(8);
+    /// EPEL 8 (Modular)
+    pub const EPEL8M: FedoraRelease = FedoraRelease::release_variant(ReleaseProduct::EPEL, 8, ReleaseVariant::Modular);
+    /// EPEL 8 (Next)
+    pub const EPEL8N: FedoraRelease = FedoraRelease::release_variant(ReleaseProduct::EPEL, 8, ReleaseVariant::Next);
+    /// EPEL 7
+    pub const EPEL7: FedoraRelease = FedoraRelease::epel(7);
+    /// EL 6
+    pub const EL6: FedoraRelease = FedoraRelease::el(6);
+    /// EL 5
+    pub const EL5: FedoraRelease = FedoraRelease::el(5);
+    /// ELN
+    pub const ELN: FedoraRelease = FedoraRelease::eln();
+}
+
+impl FedoraRelease {
+    const fn release(product: ReleaseProduct, version: Option<u32>) -> Self {
+        FedoraRelease {
+            name: ReleaseName::Release {
+                product,
+                version,
+                minor: None,
+                variant: None,
+            },
+        }
+    }
+
+    const fn release_variant(product: ReleaseProduct, version: u32, variant: ReleaseVariant) -> Self {
+        FedoraRelease {
+            name: ReleaseName::Release {
+                product,
+                version: Some(version),
+                minor: None,
+                variant: Some(variant),
+            },
+        }
+    }
+
+    /// This method creates a new `FedoraRelease` for the given Fedora version (for example, "F36").
+    pub const fn fedora(version: u32) -> Self {
+        FedoraRelease::release(ReleaseProduct::Fedora, Some(version))
+    }
+
+    /// This method creates a new `FedoraRelease` for the given EPEL version (for example,
+    /// "EPEL-8").
+    pub const fn epel(version: u32) -> Self {
+        FedoraRelease::release(ReleaseProduct::EPEL, Some(version))
+    }
+
+    /// This method creates a new `FedoraRelease` for the given EL version (for example, "EL-6").
+    pub const fn el(version: u32) -> Self {
+        FedoraRelease::release(ReleaseProduct::EL, Some(version))
+    }
+
+    /// This method creates a new `FedoraRelease` for Fedora ELN ("ELN").
+    pub const fn eln() -> Self {
+        FedoraRelease::release(ReleaseProduct::ELN, None)
+    }
+
+    /// This method returns the variant of this release with the given suffix (for example, "F36C"
+    /// for "F36" and `ReleaseVariant::Container`). It has no effect on pseudo-releases.
+    pub fn with_variant(self, variant: ReleaseVariant) -> Self {
+        match self.name {
+            ReleaseName::Release {
+                product,
+                version,
+                minor,
+                ..
+            } => FedoraRelease {
+                name: ReleaseName::Release {
+                    product,
+                    version,
+                    minor,
+                    variant: Some(variant),
+                },
+            },
+            _ => self,
+        }
+    }
+
+    /// This method returns the minor release of this release with the given minor version (for
+    /// example, "EPEL-10.1" for "EPEL-10" and 1). It has no effect on pseudo-releases.
+    pub fn with_minor(self, minor: u32) -> Self {
+        match self.name {
+            ReleaseName::Release {
+                product,
+                version,
+                variant,
+                ..
+            } => FedoraRelease {
+                name: ReleaseName::Release {
+                    product,
+                    version,
+                    minor: Some(minor),
+                    variant,
+                },
+            },
+            _ => self,
+        }
+    }
+
+    /// This method returns the product of this release, or `None` for pseudo-releases.
+    pub fn product(&self) -> Option<ReleaseProduct> {
+        match self.name {
+            ReleaseName::Release { product, .. } => Some(product),
+            _ => None,
+        }
+    }
+
+    /// This method returns the version number of this release, or `None` for pseudo-releases and
+    /// releases without version number (ELN).
+    pub fn version(&self) -> Option<u32> {
+        match self.name {
+            ReleaseName::Release { version, .. } => version,
+            _ => None,
+        }
+    }
+
+    /// This method returns the minor version number of this release (for example, 1 for
+    /// "EPEL-10.1"), or `None` if the release name does not contain a minor version.
+    pub fn minor_version(&self) -> Option<u32> {
+        match self.name {
+            ReleaseName::Release { minor, .. } => minor,
+            _ => None,
+        }
+    }
+
+    /// This method returns the variant of this release, or `None` if this is not a variant.
+    pub fn variant(&self) -> Option<ReleaseVariant> {
+        match self.name {
+            ReleaseName::Release { variant, .. } => variant,
+            _ => None,
+        }
+    }
+
+    /// This method returns `true` if this is one of the `__current__`, `__pending__`, or
+    /// `__archived__` pseudo-releases, which are only valid as query filters.
+    pub fn is_pseudo(&self) -> bool {
+        matches!(
+            self.name,
+            ReleaseName::Current | ReleaseName::Pending | ReleaseName::Archived
+        )
+    }
+
+    /// This method returns `true` if the name of this release does not follow the known naming
+    /// scheme, and was kept as-is. Product, version, and variant are not known for these releases.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self.name, ReleaseName::Other(_))
+    }
+}
+
+impl Display for FedoraRelease {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        let (product, version, minor, variant) = match &self.name {
+            ReleaseName::Current => return write!(f, "__current__"),
+            ReleaseName::Pending => return write!(f, "__pending__"),
+            ReleaseName::Archived => return write!(f, "__archived__"),
+            ReleaseName::Other(name) => return write!(f, "{}", name),
+            ReleaseName::Release {
+                product,
+                version,
+                minor,
+                variant,
+            } => (product, version, minor, variant),
+        };
+
+        match product {
+            ReleaseProduct::Fedora => write!(f, "F")?,
+            ReleaseProduct::EPEL => write!(f, "EPEL-")?,
+            ReleaseProduct::EL => write!(f, "EL-")?,
+            ReleaseProduct::ELN => write!(f, "ELN")?,
+        };
+
+        if let Some(version) = version {
+            write!(f, "{}", version)?;
+        }
+
+        if let Some(minor) = minor {
+            write!(f, ".{}", minor)?;
+        }
+
+        if let Some(variant) = variant {
+            write!(f, "{}", variant.suffix())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl TryFrom<&str> for FedoraRelease {
+    type Error = InvalidValueError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        // names that do not follow the known naming scheme are kept as-is
+        match parse_name(value).or_else(|| ReleaseName::raw(value)) {
+            Some(name) => Ok(FedoraRelease { name }),
+            None => Err(InvalidValueError::new("FedoraRelease", value)),
+        }
+    }
+}
+
+impl FromStr for FedoraRelease {
+    type Err = InvalidValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        TryFrom::try_from(s)
+    }
+}
+
+impl Serialize for FedoraRelease {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for FedoraRelease {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let string = String::deserialize(deserializer)?;
+        FedoraRelease::try_from(string.as_str()).map_err(serde::de::Error::custom)
+    }
+}
+
+/// This function parses release names that follow the known naming scheme.
+fn parse_name(value: &str) -> Option<ReleaseName> {
+    let (product, rest) = match value {
+        "__current__" => return Some(ReleaseName::Current),
+        "__pending__" => return Some(ReleaseName::Pending),
+        "__archived__" => return Some(ReleaseName::Archived),
+        "ELN" => return Some(FedoraRelease::eln().name),
+        _ if value.starts_with("EPEL-") => (ReleaseProduct::EPEL, &value[5..]),
+        _ if value.starts_with("EL-") => (ReleaseProduct::EL, &value[3..]),
+        _ if value.starts_with('F') => (ReleaseProduct::Fedora, &value[1..]),
+        _ => return None,
+    };
+
+    // the version number can be followed by a minor version number (like "EPEL-10.1")
+    let (version, rest) = split_number(rest);
+
+    if version.is_empty() || version.starts_with('0') {
+        return None;
+    }
+
+    let version: u32 = version.parse().ok()?;
+
+    let (minor, suffix) = match rest.strip_prefix('.') {
+        Some(rest) => {
+            let (minor, suffix) = split_number(rest);
+
+            if minor.is_empty() || (minor.len() > 1 && minor.starts_with('0')) {
+                return None;
+            }
+
+            (Some(minor.parse().ok()?), suffix)
+        },
+        None => (None, rest),
+    };
+
+    // the version is followed by an optional variant suffix, which is a single upper-case letter
+    let mut chars = suffix.chars();
+    let variant = match (chars.next(), chars.next()) {
+        (None, _) => None,
+        (Some(c), None) => Some(ReleaseVariant::from_suffix(c)?),
+        _ => return None,
+    };
+
+    Some(ReleaseName::Release {
+        product,
+        version: Some(version),
+        minor,
+        variant,
+    })
+}
+
+/// This function splits the given string after the leading ASCII digits.
+fn split_number(value: &str) -> (&str, &str) {
+    let digits = value.find(|c: char| !c.is_ascii_digit()).unwrap_or(value.len());
+    value.split_at(digits)
+}
@@ -0,0 +1,192 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt::{Display, Formatter};
+
+use serde_json::Value;
+
+use super::types::*;
+
+/// This trait is implemented for all data types that collect unknown fields in an `extra`
+/// catch-all field, and for collections of them. It is used by
+/// [`SchemaReport`](struct.SchemaReport.html) for walking deserialized values.
+pub trait ExtraFields {
+    /// name of the data type, which is used as the root of the reported paths
+    const NAME: &'static str;
+
+    /// This method adds the unknown fields of this value (and of all nested values) to the report,
+    /// using the given path as a prefix.
+    fn collect_extra(&self, path: &str, report: &mut SchemaReport);
+}
+
+/// This struct contains a summary of one unknown field.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct FieldSummary {
+    /// number of values which contained this field
+    pub count: usize,
+    /// number of values which could have contained this field
+    pub total: usize,
+    /// JSON types of the values of this field (`array`, `bool`, `null`, `number`, `object`,
+    /// `string`)
+    pub types: BTreeSet<&'static str>,
+}
+
+/// This struct summarizes the unknown fields that were collected in the `extra` catch-all fields of
+/// deserialized values. Since bodhi does not version its JSON schema, this can be used to detect
+/// fields that were added to server responses, but which are not supported by this crate yet.
+///
+/// Fields are identified by their path, which consists of the name of the data type and the names
+/// of the fields that contain nested values (for example, `Update.builds.ci_url`).
+///
+/// ```
+/// # use bodhi::{SchemaReport, Update};
+/// let updates: Vec<Update> = Vec::new();
+///
+/// let mut report = SchemaReport::new();
+/// report.add(&updates);
+///
+/// for (path, summary) in report.fields() {
+///     println!("{}: {} of {}", path, summary.count, summary.total);
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct SchemaReport {
+    visited: HashMap<String, usize>,
+    // unknown fields, identified by the path of the containing value and their name
+    fields: BTreeMap<(String, String), FieldSummary>,
+}
+
+impl SchemaReport {
+    /// This method creates a new, empty report.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// This method adds the unknown fields of the given value (and all nested values) to the
+    /// report.
+    pub fn add<T: ExtraFields>(&mut self, value: &T) {
+        value.collect_extra(T::NAME, self);
+    }
+
+    /// This method returns `true` if no unknown fields were found.
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /// This method returns the paths and summaries of all unknown fields, sorted by their path.
+    pub fn fields(&self) -> impl Iterator<Item = (String, FieldSummary)> + '_ {
+        self.fields
+            .iter()
+            .map(move |((parent, key), summary)| (format!("{}.{}", parent, key), self.summary(parent, summary)))
+    }
+
+    /// This method returns the summary of the unknown field with the given name, which was found in
+    /// values at the given path (for example, `report.field("Update.builds", "ci_url")`).
+    pub fn field(&self, parent: &str, key: &str) -> Option<FieldSummary> {
+        self.fields
+            .get(&(parent.to_owned(), key.to_owned()))
+            .map(|summary| self.summary(parent, summary))
+    }
+
+    /// This method records the contents of the `extra` field of one value at the given path. It
+    /// is only needed for implementing [`ExtraFields`](trait.ExtraFields.html).
+    pub fn record(&mut self, path: &str, extra: &HashMap<String, Value>) {
+        *self.visited.entry(path.to_owned()).or_default() += 1;
+
+        for (key, value) in extra {
+            let summary = self.fields.entry((path.to_owned(), key.to_owned())).or_default();
+            summary.count += 1;
+            summary.types.insert(json_type(value));
+        }
+    }
+
+    // The total number of values that could have contained a field is only known after all values
+    // were added, so it is filled in when the summaries are returned.
+    fn summary(&self, parent: &str, summary: &FieldSummary) -> FieldSummary {
+        FieldSummary {
+            total: self.visited.get(parent).copied().unwrap_or(0),
+            ..summary.clone()
+        }
+    }
+}
+
+impl Display for SchemaReport {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        if self.fields.is_empty() {
+            return writeln!(f, "No unknown fields.");
+        }
+
+        for (path, summary) in self.fields() {
+            let types: Vec<&str> = summary.types.iter().copied().collect();
+
+            writeln!(
+                f,
+                "{}: {} of {} ({})",
+                path,
+                summary.count,
+                summary.total,
+                types.join(", ")
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+fn json_type(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "bool",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+impl<T: ExtraFields> ExtraFields for Vec<T> {
+    const NAME: &'static str = T::NAME;
+
+    fn collect_extra(&self, path: &str, report: &mut SchemaReport) {
+        for value in self {
+            value.collect_extra(path, report);
+        }
+    }
+}
+
+impl<T: ExtraFields> ExtraFields for Option<T> {
+    const NAME: &'static str = T::NAME;
+
+    fn collect_extra(&self, path: &str, report: &mut SchemaReport) {
+        if let Some(value) = self {
+            value.collect_extra(path, report);
+        }
+    }
+}
+
+// This macro implements the ExtraFields trait for a data type, including the listed fields that
+// contain nested values.
+macro_rules! extra_fields {
+    ($name:ident $(, $field:ident)*) => {
+        impl ExtraFields for $name {
+            const NAME: &'static str = stringify!($name);
+
+            fn collect_extra(&self, path: &str, report: &mut SchemaReport) {
+                report.record(path, &self.extra);
+                $(self.$field.collect_extra(&format!("{}.{}", path, stringify!($field)), report);)*
+            }
+        }
+    };
+}
+
+extra_fields!(Bug);
+extra_fields!(BugFeedback, bug);
+extra_fields!(Build);
+extra_fields!(Comment, bug_feedback, testcase_feedback, update, user);
+extra_fields!(Compose, release);
+extra_fields!(Group);
+extra_fields!(Override, build, submitter);
+extra_fields!(Package);
+extra_fields!(Release, composes);
+extra_fields!(TestCase, package);
+extra_fields!(TestCaseFeedback, testcase);
+extra_fields!(Update, bugs, builds, comments, compose, release, test_cases, user);
+extra_fields!(User, groups);
@@ -0,0 +1,119 @@
+use std::cmp::Ordering;
+
+/// This function compares two version (or release) strings with the same algorithm that RPM uses.
+/// It is a port of the `rpmvercmp` function from `rpmio/rpmvercmp.c`, including the special
+/// handling of tilde ("sorts before everything") and caret ("sorts after the base version, but
+/// before any other addition") characters.
+///
+/// ```
+/// # use std::cmp::Ordering;
+/// # use bodhi::rpmvercmp;
+/// assert_eq!(rpmvercmp("1.0", "1.0.1"), Ordering::Less);
+/// assert_eq!(rpmvercmp("1.0~rc1", "1.0"), Ordering::Less);
+/// assert_eq!(rpmvercmp("1.0^git1", "1.0"), Ordering::Greater);
+/// assert_eq!(rpmvercmp("10.0001", "10.1"), Ordering::Equal);
+/// ```
+pub fn rpmvercmp(a: &str, b: &str) -> Ordering {
+    if a == b {
+        return Ordering::Equal;
+    }
+
+    let separator = |c: &u8| !c.is_ascii_alphanumeric() && *c != b'~' && *c != b'^';
+
+    let mut one = a.as_bytes();
+    let mut two = b.as_bytes();
+
+    while !one.is_empty() || !two.is_empty() {
+        while !one.is_empty() && separator(&one[0]) {
+            one = &one[1..];
+        }
+        while !two.is_empty() && separator(&two[0]) {
+            two = &two[1..];
+        }
+
+        // handle the tilde separator, it sorts before everything else
+        if one.first() == Some(&b'~') || two.first() == Some(&b'~') {
+            if one.first() != Some(&b'~') {
+                return Ordering::Greater;
+            }
+            if two.first() != Some(&b'~') {
+                return Ordering::Less;
+            }
+            one = &one[1..];
+            two = &two[1..];
+            continue;
+        }
+
+        // handle the caret separator: it sorts after the end of a string, but before anything else
+        if one.first() == Some(&b'^') || two.first() == Some(&b'^') {
+            if one.is_empty() {
+                return Ordering::Less;
+            }
+            if two.is_empty() {
+                return Ordering::Greater;
+            }
+            if one.first() != Some(&b'^') {
+                return Ordering::Greater;
+            }
+            if two.first() != Some(&b'^') {
+                return Ordering::Less;
+            }
+            one = &one[1..];
+            two = &two[1..];
+            continue;
+        }
+
+        if one.is_empty() || two.is_empty() {
+            break;
+        }
+
+        // grab the first completely numeric or alphabetic segment from both strings
+        let numeric = one[0].is_ascii_digit();
+        let class = |c: &u8| {
+            if numeric {
+                c.is_ascii_digit()
+            } else {
+                c.is_ascii_alphabetic()
+            }
+        };
+
+        let len1 = one.iter().take_while(|c| class(c)).count();
+        let len2 = two.iter().take_while(|c| class(c)).count();
+
+        let (seg1, rest1) = one.split_at(len1);
+        let (seg2, rest2) = two.split_at(len2);
+
+        // segments of different types: numeric segments are always newer than alphabetic ones
+        if seg2.is_empty() {
+            return if numeric { Ordering::Greater } else { Ordering::Less };
+        }
+
+        let ordering = if numeric {
+            // leading zeroes are insignificant, and longer numbers are larger
+            let seg1 = trim_zeroes(seg1);
+            let seg2 = trim_zeroes(seg2);
+            seg1.len().cmp(&seg2.len()).then_with(|| seg1.cmp(seg2))
+        } else {
+            seg1.cmp(seg2)
+        };
+
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+
+        one = rest1;
+        two = rest2;
+    }
+
+    // whichever version still has characters left over wins
+    match (one.is_empty(), two.is_empty()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Less,
+        (false, _) => Ordering::Greater,
+    }
+}
+
+fn trim_zeroes(segment: &[u8]) -> &[u8] {
+    let zeroes = segment.iter().take_while(|c| **c == b'0').count();
+    &segment[zeroes..]
+}
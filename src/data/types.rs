@@ -1,4 +1,6 @@
+use std::cmp::Ordering;
 use std::collections::HashMap;
+use std::convert::TryFrom;
 use std::fmt::{Display, Formatter};
 
 use serde::{Deserialize, Serialize};
@@ -6,6 +8,10 @@ use url::Url;
 
 use super::dates::*;
 use super::enums::*;
+use super::identifiers::UpdateID;
+use super::nvr::Nvr;
+use super::release::FedoraRelease;
+use crate::BodhiService;
 
 /// This struct represents a specific BugZilla bug that is associated with an update.
 #[derive(Debug, Deserialize, Serialize)]
@@ -91,6 +97,49 @@ pub struct Build {
     pub extra: HashMap<String, serde_json::Value>,
 }
 
+impl Build {
+    /// This method parses the NVR of this build into its components. Bodhi stores the epoch of
+    /// builds separately, so if this build has a non-zero epoch, it is added to the returned value
+    /// (and to its string representation, which then differs from the NVR of this build).
+    pub fn parse_nvr(&self) -> Result<Nvr, InvalidValueError> {
+        let nvr = Nvr::try_from(self.nvr.as_str())?;
+
+        // a zero epoch is equivalent to no epoch, and is usually omitted
+        match self.epoch {
+            Some(epoch) if epoch != 0 && nvr.epoch().is_none() => {
+                Ok(Nvr::new(nvr.name(), Some(epoch), nvr.version(), nvr.release()))
+            },
+            _ => Ok(nvr),
+        }
+    }
+
+    /// This method compares the epoch, version, and release of two builds with the same algorithm
+    /// that RPM uses, ignoring package names. If the NVR of either build can not be parsed, `None`
+    /// is returned.
+    pub fn evr_cmp(&self, other: &Build) -> Option<Ordering> {
+        match (self.parse_nvr(), other.parse_nvr()) {
+            (Ok(one), Ok(two)) => Some(one.evr_cmp(&two)),
+            _ => None,
+        }
+    }
+
+    /// This method constructs the URL of this build on the given bodhi instance.
+    pub fn web_url(&self, bodhi: &BodhiService) -> Url {
+        bodhi.web_url(&format!("/builds/{}", self.nvr))
+    }
+
+    /// This method constructs the URL for searching this build in the fedora koji build system.
+    pub fn koji_url(&self) -> Url {
+        let mut url = Url::parse("https://koji.fedoraproject.org/koji/search")
+            .expect("Failed to parse the hard-coded URL, this should not happen.");
+        url.query_pairs_mut()
+            .append_pair("terms", &self.nvr)
+            .append_pair("type", "build")
+            .append_pair("match", "exact");
+        url
+    }
+}
+
 impl Display for Build {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         writeln!(f, "Build {}", &self.nvr)?;
@@ -156,9 +205,16 @@ impl Display for Comment {
     }
 }
 
+impl Comment {
+    /// This method constructs the URL of this comment on the given bodhi instance.
+    pub fn web_url(&self, bodhi: &BodhiService) -> Url {
+        bodhi.web_url(&format!("/comments/{}", self.id))
+    }
+}
+
 
 /// This struct represents a currently running compose.
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Compose {
     /// string of JSON-formatted checkpoint data for the compose
     pub checkpoints: String,
@@ -213,6 +269,18 @@ impl Display for Compose {
     }
 }
 
+impl Compose {
+    /// This method constructs the URL of this compose in the web interface of the given bodhi
+    /// instance. If the release of the compose is not known, the URL of the list of all running
+    /// composes is returned instead.
+    pub fn web_url(&self, bodhi: &BodhiService) -> Url {
+        match &self.release {
+            Some(release) => bodhi.web_url(&format!("/composes/{}/{}", release.name, self.request)),
+            None => bodhi.web_url("/composes/"),
+        }
+    }
+}
+
 
 /// This struct represents a group from the fedora accounts system (FAS).
 #[derive(Debug, Deserialize, Serialize)]
@@ -280,6 +348,14 @@ impl Display for Override {
     }
 }
 
+impl Override {
+    /// This method constructs the URL of this buildroot override in the web interface of the given
+    /// bodhi instance.
+    pub fn web_url(&self, bodhi: &BodhiService) -> Url {
+        bodhi.web_url(&format!("/overrides/{}", self.nvr))
+    }
+}
+
 
 /// This struct represents a specific fedora package (or another distributable unit)
 #[derive(Debug, Deserialize, Serialize)]
@@ -308,10 +384,20 @@ impl Display for Package {
     }
 }
 
+impl Package {
+    /// This method constructs the URL of the list of updates for this package in the web interface
+    /// of the given bodhi instance.
+    pub fn web_url(&self, bodhi: &BodhiService) -> Url {
+        let mut url = bodhi.web_url("/updates/");
+        url.query_pairs_mut().append_pair("packages", &self.name);
+        url
+    }
+}
+
 
 /// This struct represents a fedora release as present in the bodhi database. This includes variants
 /// (Modular, Container, Flatpak), identified with the "C", "F", and "M" suffixes.
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Release {
     /// dist-git branch for this release
     pub branch: String,
@@ -331,6 +417,9 @@ pub struct Release {
     pub long_name: String,
     /// name of the email template for errata
     pub mail_template: String,
+    /// minimum number of days updates have to stay in testing (not returned by older bodhi
+    /// versions)
+    pub mandatory_days_in_testing: Option<u32>,
     /// short name of this release
     pub name: FedoraRelease,
     /// package manager in use on this release
@@ -376,6 +465,14 @@ impl Display for Release {
     }
 }
 
+impl Release {
+    /// This method constructs the URL of this release in the web interface of the given bodhi
+    /// instance.
+    pub fn web_url(&self, bodhi: &BodhiService) -> Url {
+        bodhi.web_url(&format!("/releases/{}", self.name))
+    }
+}
+
 
 /// This struct represents a specific test case as associated with a package.
 #[derive(Debug, Deserialize, Serialize)]
@@ -600,10 +697,19 @@ impl Display for Update {
     }
 }
 
+impl Update {
+    /// This method constructs the URL of this update in the web interface of the given bodhi
+    /// instance. Unlike the `url` field, which is always provided by the server the update was
+    /// retrieved from, this can also be used for constructing links to other instances.
+    pub fn web_url(&self, bodhi: &BodhiService) -> Url {
+        bodhi.web_url(&format!("/updates/{}", self.alias))
+    }
+}
+
 
 /// This struct wraps the short update summaries that are included in running
 /// [`Compose`](struct.Compose.html)s.
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct UpdateSummary {
     /// unique update alias identifying the update
     pub alias: String,
@@ -617,6 +723,14 @@ impl Display for UpdateSummary {
     }
 }
 
+impl UpdateSummary {
+    /// This method constructs the URL of the summarized update in the web interface of the given
+    /// bodhi instance.
+    pub fn web_url(&self, bodhi: &BodhiService) -> Url {
+        bodhi.web_url(&format!("/updates/{}", self.alias))
+    }
+}
+
 
 /// This struct represents one fedora user that bodhi is aware of.
 #[derive(Debug, Deserialize, Serialize)]
@@ -660,3 +774,11 @@ impl Display for User {
         Ok(())
     }
 }
+
+impl User {
+    /// This method constructs the URL of the profile of this user in the web interface of the
+    /// given bodhi instance.
+    pub fn web_url(&self, bodhi: &BodhiService) -> Url {
+        bodhi.web_url(&format!("/users/{}", self.name))
+    }
+}
@@ -0,0 +1,109 @@
+use std::convert::TryFrom;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use url::Url;
+
+use super::enums::InvalidValueError;
+
+/// This struct represents the version of a remote bodhi server, as reported by its `/api_version`
+/// endpoint. Versions are compared by their numerical components, so they can be used for checking
+/// whether a server supports a certain feature.
+///
+/// ```
+/// # use bodhi::ServerVersion;
+/// let version: ServerVersion = "5.7.1".parse().unwrap();
+/// assert!(version >= ServerVersion::new(5, 0, 0));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ServerVersion {
+    /// major version number
+    pub major: u32,
+    /// minor version number
+    pub minor: u32,
+    /// patch version number (`0` if the server did not report one)
+    pub patch: u32,
+}
+
+impl ServerVersion {
+    /// first version of bodhi that supports creating updates from side tags
+    pub const SIDE_TAG_UPDATES: ServerVersion = ServerVersion::new(5, 0, 0);
+
+    /// This method creates a new `ServerVersion` from its numerical components.
+    pub const fn new(major: u32, minor: u32, patch: u32) -> Self {
+        ServerVersion { major, minor, patch }
+    }
+
+    /// This method returns `true` if this version of bodhi supports creating updates from side
+    /// tags.
+    pub fn supports_side_tag_updates(&self) -> bool {
+        *self >= Self::SIDE_TAG_UPDATES
+    }
+}
+
+/// This struct contains information about a remote bodhi server: the root URL of its REST API, the
+/// version of the server software, and the notice that is displayed on its front page. Which
+/// features the server supports is determined by its version.
+///
+/// ```
+/// # use bodhi::BodhiServiceBuilder;
+/// let bodhi = BodhiServiceBuilder::default().build().unwrap();
+///
+/// # #[cfg(feature = "online-tests")]
+/// # {
+/// let info = bodhi.server_info().unwrap();
+/// assert!(info.supports_side_tag_updates());
+/// # }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ServerInfo {
+    /// root URL of the REST API
+    pub api_root: Url,
+    /// version of the server software
+    pub version: ServerVersion,
+    /// notice that is displayed on the front page (if it is set)
+    pub notice: Option<String>,
+}
+
+impl ServerInfo {
+    /// This method returns `true` if the server supports creating updates from side tags.
+    pub fn supports_side_tag_updates(&self) -> bool {
+        self.version.supports_side_tag_updates()
+    }
+}
+
+impl Display for ServerVersion {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+impl TryFrom<&str> for ServerVersion {
+    type Error = InvalidValueError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        // versions of development snapshots can have suffixes like "5.7.1.dev0" or "5.7.1+git",
+        // only the leading numerical components are relevant for comparisons
+        let mut parts = value.trim().split(&['.', '-', '+'][..]).map(|part| part.parse::<u32>());
+
+        let mut next = |required: bool| match parts.next() {
+            Some(Ok(number)) => Ok(number),
+            None | Some(Err(_)) if !required => Ok(0),
+            _ => Err(InvalidValueError::new("ServerVersion", value)),
+        };
+
+        let major = next(true)?;
+        let minor = next(true)?;
+        let patch = next(false)?;
+
+        Ok(ServerVersion { major, minor, patch })
+    }
+}
+
+impl FromStr for ServerVersion {
+    type Err = InvalidValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        TryFrom::try_from(s)
+    }
+}
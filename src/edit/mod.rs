@@ -7,5 +7,5 @@ pub(crate) use traits::Edit;
 mod overrides;
 pub use overrides::{EditedOverride, OverrideEditor};
 
-mod updates;
-pub use updates::{EditedUpdate, UpdateEditor, UpdateStatusRequester, UpdateTestResultWaiver};
+pub(crate) mod updates;
+pub use updates::{EditedUpdate, FieldChange, UpdateEditor, UpdateStatusRequester, UpdateTestResultWaiver};
@@ -2,8 +2,10 @@ use std::collections::HashMap;
 
 use serde::Deserialize;
 
-use crate::error::{BodhiError, QueryError};
-use crate::{BodhiDate, BodhiService, CSRFQuery, Edit, Override, OverrideData};
+use crate::error::{QueryError, ValidationError};
+use crate::query::json::from_json;
+use crate::validate::{check_expiration, check_notes};
+use crate::{BodhiDate, Edit, Override, OverrideData, PostRequest, Validate, ValidationContext};
 
 /// This struct contains the values that are returned when editing a buildroot override.
 #[derive(Debug, Deserialize)]
@@ -56,19 +58,31 @@ impl<'a> OverrideEditor<'a> {
     }
 }
 
+impl<'a> Validate for OverrideEditor<'a> {
+    fn validate_with(&self, _context: &ValidationContext) -> Result<(), ValidationError> {
+        let mut problems = Vec::new();
+
+        check_notes(self.notes, &mut problems);
+        // the expiration date of overrides that are being expired is irrelevant
+        if self.expired != Some(true) {
+            check_expiration(self.expiration_date, &mut problems);
+        }
+
+        ValidationError::check(problems)
+    }
+}
+
 impl<'a> Edit<EditedOverride> for OverrideEditor<'a> {
-    fn edit(&self, bodhi: &BodhiService) -> Result<EditedOverride, QueryError> {
+    fn request(&self, csrf_token: &str) -> Result<PostRequest, QueryError> {
         let path = String::from("/overrides/");
 
-        let csrf_token = bodhi.query(CSRFQuery::new())?;
-
         let override_edit = OverrideData {
             nvr: self.edited,
             notes: self.notes,
             expiration_date: self.expiration_date,
             expired: self.expired,
             edited: Some(self.edited),
-            csrf_token: &csrf_token,
+            csrf_token,
         };
 
         let data = match serde_json::to_string(&override_edit) {
@@ -76,18 +90,11 @@ impl<'a> Edit<EditedOverride> for OverrideEditor<'a> {
             Err(error) => return Err(QueryError::SerializationError { error }),
         };
 
-        let response = bodhi.post(&path, data)?;
-        let status = response.status();
-
-        if !status.is_success() {
-            let text = response.text().unwrap_or_else(|_| String::from(""));
-
-            let error: BodhiError = serde_json::from_str(&text)?;
-            return Err(QueryError::BodhiError { error });
-        };
+        Ok(PostRequest::new(path, data))
+    }
 
-        let result = response.text()?;
-        let edited_override: EditedOverride = serde_json::from_str(&result)?;
+    fn parse(&self, string: &str) -> Result<EditedOverride, QueryError> {
+        let edited_override: EditedOverride = from_json(string)?;
 
         Ok(edited_override)
     }
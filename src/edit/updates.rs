@@ -1,18 +1,26 @@
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::{Display, Formatter};
 
 use serde::{Deserialize, Serialize};
 
-use crate::error::{BodhiError, QueryError};
+use crate::error::{QueryError, ValidationError, ValidationProblem};
+use crate::query::json::from_json;
+use crate::validate::{check_builds, check_karma, check_known, check_notes, check_severity, check_stable_days};
 use crate::{
     BodhiService,
-    CSRFQuery,
+    BugId,
     Edit,
+    PostRequest,
     Update,
+    UpdateAlias,
     UpdateData,
+    UpdateIDQuery,
     UpdateRequest,
     UpdateSeverity,
     UpdateSuggestion,
     UpdateType,
+    Validate,
+    ValidationContext,
 };
 
 /// This struct contains the values that are returned when editing an update.
@@ -25,17 +33,52 @@ pub struct EditedUpdate {
     pub caveats: Vec<HashMap<String, String>>,
 }
 
+/// This struct represents a change of one field of an update, with the current and the new value.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FieldChange {
+    /// name of the changed field (as used by the REST API)
+    pub field: &'static str,
+    /// current value of the field
+    pub old: serde_json::Value,
+    /// new value of the field
+    pub new: serde_json::Value,
+}
+
+impl Display for FieldChange {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{}: {} → {}", self.field, self.old, self.new)
+    }
+}
+
 /// This struct contains all the possible arguments for editing an update. Methods to supply
 /// optional arguments are also available.
+///
+/// Updates that were created from a side tag are edited as side tag updates: their builds are
+/// always taken from the side tag, so the list of builds can not be changed directly.
+///
+/// The editor keeps track of the fields that were changed, which can be inspected with the
+/// [`diff`](#method.diff) method. Since bodhi expects all fields to be submitted when editing an
+/// update, the values of unchanged fields are taken from the update the editor was created from.
+/// To avoid overwriting changes that were made by somebody else in the meantime, the update is
+/// fetched again before the edit is submitted, and editing fails with a `ConcurrentModification`
+/// error if it was modified since (or with an `UpdateNotFound` error if it does not exist anymore).
 #[derive(Debug)]
 pub struct UpdateEditor<'a> {
+    // the update that is edited
+    update: &'a Update,
+    // names of the fields that were changed
+    changed: BTreeSet<&'static str>,
+
     // mandatory fields
     builds: Vec<&'a str>,
     notes: &'a str,
 
+    // side tag this update takes its builds from
+    from_tag: Option<&'a str>,
+
     // optional fields
-    bugs: Vec<u32>,
-    display_name: Option<&'a str>,
+    bugs: Vec<BugId>,
+    display_name: Option<String>,
     close_bugs: Option<bool>,
     update_type: Option<UpdateType>,
     request: Option<UpdateRequest>,
@@ -45,7 +88,7 @@ pub struct UpdateEditor<'a> {
     unstable_karma: Option<i32>,
     suggest: Option<UpdateSuggestion>,
     edited: Option<&'a str>,
-    requirements: Option<&'a str>,
+    requirements: Option<String>,
     require_bugs: Option<bool>,
     require_testcases: Option<bool>,
     autotime: Option<bool>,
@@ -57,24 +100,26 @@ impl<'a> UpdateEditor<'a> {
     /// fields with the current values.
     pub fn from_update(update: &'a Update) -> Self {
         UpdateEditor {
+            update,
+            changed: BTreeSet::new(),
+
             builds: update.builds.iter().map(|b| b.nvr.as_str()).collect(),
             notes: &update.notes,
 
-            bugs: update.bugs.iter().map(|bug| bug.bug_id).collect(),
-            display_name: Some(&update.display_name),
+            from_tag: update.from_tag.as_deref(),
+
+            bugs: update.bugs.iter().map(|bug| BugId::new(bug.bug_id)).collect(),
+            display_name: Some(update.display_name.clone()),
             close_bugs: Some(update.close_bugs),
-            update_type: Some(update.update_type),
-            request: update.request,
-            severity: Some(update.severity),
+            update_type: Some(update.update_type.clone()),
+            request: update.request.clone(),
+            severity: Some(update.severity.clone()),
             autokarma: Some(update.autokarma),
             stable_karma: update.stable_karma,
             unstable_karma: update.unstable_karma,
-            suggest: Some(update.suggest),
+            suggest: Some(update.suggest.clone()),
             edited: Some(&update.alias),
-            requirements: match &update.requirements {
-                Some(string) => Some(string),
-                None => None,
-            },
+            requirements: update.requirements.clone(),
             require_bugs: Some(update.require_bugs),
             require_testcases: Some(update.require_testcases),
             autotime: Some(update.autotime),
@@ -83,44 +128,64 @@ impl<'a> UpdateEditor<'a> {
     }
 
     /// Add a build to the update.
+    ///
+    /// This is not supported for updates that were created from a side tag.
     pub fn add_build(mut self, build: &'a str) -> Self {
         self.builds.push(build);
+        self.changed.insert("builds");
         self
     }
 
     /// Remove a build to the update.
+    ///
+    /// This is not supported for updates that were created from a side tag.
     pub fn remove_build(mut self, build: &'a str) -> Self {
         self.builds.retain(|b| *b != build);
+        self.changed.insert("builds");
+        self
+    }
+
+    /// Change the side tag the builds of this update are taken from. The builds of the update are
+    /// refreshed from the (new) side tag when the edit is submitted.
+    pub fn from_tag(mut self, tag: &'a str) -> Self {
+        self.from_tag = Some(tag);
+        self.changed.insert("from_tag");
         self
     }
 
     /// Change the update notes.
     pub fn notes(mut self, notes: &'a str) -> Self {
         self.notes = notes;
+        self.changed.insert("notes");
         self
     }
 
     /// Add a related bug to the update.
-    pub fn add_bug(mut self, bug: u32) -> Self {
-        self.bugs.push(bug);
+    pub fn add_bug(mut self, bug: impl Into<BugId>) -> Self {
+        self.bugs.push(bug.into());
+        self.changed.insert("bugs");
         self
     }
 
     /// Remove a related bug from the update.
-    pub fn remove_bug(mut self, bug: u32) -> Self {
+    pub fn remove_bug(mut self, bug: impl Into<BugId>) -> Self {
+        let bug = bug.into();
         self.bugs.retain(|b| *b != bug);
+        self.changed.insert("bugs");
         self
     }
 
     /// Change the custom, user-visible title of the update.
-    pub fn display_name(mut self, display_name: &'a str) -> Self {
-        self.display_name = Some(display_name);
+    pub fn display_name(mut self, display_name: impl Into<String>) -> Self {
+        self.display_name = Some(display_name.into());
+        self.changed.insert("display_name");
         self
     }
 
     /// Set the flag whether bugs will be closed when the update is pushed to stable.
     pub fn close_bugs(mut self, close_bugs: bool) -> Self {
         self.close_bugs = Some(close_bugs);
+        self.changed.insert("close_bugs");
         self
     }
 
@@ -128,6 +193,7 @@ impl<'a> UpdateEditor<'a> {
     /// unspecified). For security updates, the severity also has to be specified.
     pub fn update_type(mut self, update_type: UpdateType) -> Self {
         self.update_type = Some(update_type);
+        self.changed.insert("type");
         self
     }
 
@@ -135,6 +201,7 @@ impl<'a> UpdateEditor<'a> {
     /// mandatory).
     pub fn severity(mut self, severity: UpdateSeverity) -> Self {
         self.severity = Some(severity);
+        self.changed.insert("severity");
         self
     }
 
@@ -142,42 +209,49 @@ impl<'a> UpdateEditor<'a> {
     /// specified stable karma.
     pub fn autokarma(mut self, autokarma: bool) -> Self {
         self.autokarma = Some(autokarma);
+        self.changed.insert("autokarma");
         self
     }
 
     /// Manually set the stable karma feedback threshold.
     pub fn stable_karma(mut self, stable_karma: i32) -> Self {
         self.stable_karma = Some(stable_karma);
+        self.changed.insert("stable_karma");
         self
     }
 
     /// Manually set the unstable karma feedback threshold.
     pub fn unstable_karma(mut self, unstable_karma: i32) -> Self {
         self.unstable_karma = Some(unstable_karma);
+        self.changed.insert("unstable_karma");
         self
     }
 
     /// Flag to specify whether users should log out or reboot to successfully apply an update.
     pub fn suggest(mut self, suggestion: UpdateSuggestion) -> Self {
         self.suggest = Some(suggestion);
+        self.changed.insert("suggest");
         self
     }
 
     /// Set custom taskotron requirements.
-    pub fn requirements(mut self, requirements: &'a str) -> Self {
-        self.requirements = Some(requirements);
+    pub fn requirements(mut self, requirements: impl Into<String>) -> Self {
+        self.requirements = Some(requirements.into());
+        self.changed.insert("requirements");
         self
     }
 
     /// Flag to indicate whether bug feedback is required for karma to be counted.
     pub fn require_bugs(mut self, require_bugs: bool) -> Self {
         self.require_bugs = Some(require_bugs);
+        self.changed.insert("require_bugs");
         self
     }
 
     /// Flag to indicate whether test case feedback is required for karma to be counted.
     pub fn require_testcases(mut self, require_testcases: bool) -> Self {
         self.require_testcases = Some(require_testcases);
+        self.changed.insert("require_testcases");
         self
     }
 
@@ -185,6 +259,7 @@ impl<'a> UpdateEditor<'a> {
     /// specified days in testing.
     pub fn autotime(mut self, autotime: bool) -> Self {
         self.autotime = Some(autotime);
+        self.changed.insert("autotime");
         self
     }
 
@@ -194,103 +269,155 @@ impl<'a> UpdateEditor<'a> {
     /// packages, and 3 days for fedora pre-releases.
     pub fn stable_days(mut self, stable_days: u32) -> Self {
         self.stable_days = Some(stable_days);
+        self.changed.insert("stable_days");
         self
     }
 }
 
-impl<'a> Edit<EditedUpdate> for UpdateEditor<'a> {
-    fn edit(&self, bodhi: &BodhiService) -> Result<EditedUpdate, QueryError> {
-        let path = String::from("/updates/");
+impl<'a> Validate for UpdateEditor<'a> {
+    /// This method checks the data with the settings of the release of the edited update (like the
+    /// minimum number of days in testing).
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.validate_with(&ValidationContext::from_release(&self.update.release))
+    }
+
+    fn validate_with(&self, context: &ValidationContext) -> Result<(), ValidationError> {
+        let mut problems = Vec::new();
 
-        // do some data sanity verification
-        if let Some(karma) = self.stable_karma {
-            if karma < 1 {
-                return Err(QueryError::InvalidDataError {
-                    error: String::from("Stable karma must be positive."),
-                });
-            }
+        check_notes(self.notes, &mut problems);
+        if self.from_tag.is_none() {
+            check_builds(&self.builds, &mut problems);
+        } else if self.changed.contains("builds") {
+            problems.push(ValidationProblem::SideTagBuildsEdited);
         }
-        if let Some(karma) = self.unstable_karma {
-            if karma > -1 {
-                return Err(QueryError::InvalidDataError {
-                    error: String::from("Unstable karma must be negative."),
-                });
-            }
+        check_karma(self.stable_karma, self.unstable_karma, &mut problems);
+        check_severity(self.update_type.as_ref(), self.severity.as_ref(), &mut problems);
+        // unknown values that were returned by the server can be sent back unchanged
+        if self.changed.contains("type") {
+            check_known("type", self.update_type.as_ref(), &mut problems);
         }
-
-        if let Some(update_type) = self.update_type {
-            if update_type == UpdateType::Security {
-                match self.severity {
-                    Some(value) if value == UpdateSeverity::Unspecified => {
-                        return Err(QueryError::InvalidDataError {
-                            error: String::from("For security updates, severity has to be specified."),
-                        });
-                    },
-                    None => {
-                        return Err(QueryError::InvalidDataError {
-                            error: String::from("For security updates, severity has to be specified."),
-                        });
-                    },
-                    _ => {},
-                }
-            }
+        if self.changed.contains("severity") {
+            check_known("severity", self.severity.as_ref(), &mut problems);
+        }
+        if self.changed.contains("suggest") {
+            check_known("suggest", self.suggest.as_ref(), &mut problems);
         }
+        check_stable_days(self.stable_days, context, &mut problems);
 
-        let csrf_token = bodhi.query(CSRFQuery::new())?;
+        ValidationError::check(problems)
+    }
+}
+
+impl<'a> UpdateEditor<'a> {
+    /// This method returns the changes that will be submitted, with the current and new values of
+    /// all fields that were changed (using the field names of the REST API). Fields that were
+    /// set to their current value are not included.
+    pub fn diff(&self) -> Vec<FieldChange> {
+        let (old, new) = match (UpdateEditor::from_update(self.update).payload(""), self.payload("")) {
+            (Ok(old), Ok(new)) => (old, new),
+            _ => return Vec::new(),
+        };
+
+        self.changed
+            .iter()
+            .filter(|field| old.get(**field) != new.get(**field))
+            .map(|field| FieldChange {
+                field,
+                old: old.get(*field).cloned().unwrap_or_default(),
+                new: new.get(*field).cloned().unwrap_or_default(),
+            })
+            .collect()
+    }
 
+    fn payload(&self, csrf_token: &str) -> Result<serde_json::Value, serde_json::Error> {
         let bugs: Vec<String> = self.bugs.iter().map(|b| format!("{}", b)).collect();
 
         let update_edit = UpdateData {
-            builds: Some(&self.builds),
-            from_tag: None,
+            // builds of side tag updates are always taken from the side tag
+            builds: match self.from_tag {
+                Some(_) => None,
+                None => Some(&self.builds),
+            },
+            from_tag: self.from_tag,
             bugs: Some(&bugs),
-            display_name: self.display_name,
+            display_name: self.display_name.as_deref(),
             close_bugs: self.close_bugs,
-            update_type: match self.update_type {
-                Some(t) => t,
-                None => UpdateType::Unspecified,
+            update_type: match &self.update_type {
+                Some(t) => t.clone(),
+                None => self.update.update_type.clone(),
             },
-            request: self.request,
-            severity: self.severity,
+            request: self.request.clone(),
+            severity: self.severity.clone(),
             notes: self.notes,
             autokarma: self.autokarma,
             stable_karma: self.stable_karma,
             unstable_karma: self.unstable_karma,
-            suggest: self.suggest,
+            suggest: self.suggest.clone(),
             edited: match &self.edited {
                 Some(string) => Some(string),
                 None => None,
             },
-            requirements: self.requirements,
+            requirements: self.requirements.as_deref(),
             require_bugs: self.require_bugs,
             require_testcases: self.require_testcases,
             autotime: self.autotime,
             stable_days: self.stable_days,
-            csrf_token: &csrf_token,
+            csrf_token,
         };
 
-        let data = match serde_json::to_string(&update_edit) {
+        serde_json::to_value(&update_edit)
+    }
+}
+
+impl<'a> Edit<EditedUpdate> for UpdateEditor<'a> {
+    fn request(&self, csrf_token: &str) -> Result<PostRequest, QueryError> {
+        let path = String::from("/updates/");
+
+        let data = match self
+            .payload(csrf_token)
+            .and_then(|payload| serde_json::to_string(&payload))
+        {
             Ok(data) => data,
             Err(error) => return Err(QueryError::SerializationError { error }),
         };
 
-        let response = bodhi.post(&path, data)?;
-        let status = response.status();
-
-        if !status.is_success() {
-            let text = response.text().unwrap_or_else(|_| String::from(""));
+        Ok(PostRequest::new(path, data))
+    }
 
-            let error: BodhiError = serde_json::from_str(&text)?;
-            return Err(QueryError::BodhiError { error });
-        };
+    fn check(&self, bodhi: &BodhiService) -> Result<(), QueryError> {
+        // check that the update was not modified since it was fetched, so unchanged fields do not
+        // overwrite the changes that were made by somebody else in the meantime
+        let current = bodhi.query(UpdateIDQuery::new(self.update.alias.parse::<UpdateAlias>()?))?;
+        check_unmodified(self.update, current)
+    }
 
-        let result = response.text()?;
-        let edited_update: EditedUpdate = serde_json::from_str(&result)?;
+    fn parse(&self, string: &str) -> Result<EditedUpdate, QueryError> {
+        let edited_update: EditedUpdate = from_json(string)?;
 
         Ok(edited_update)
     }
 }
 
+/// This function checks that the current state of an update on the server (if it still exists) is
+/// the same as the state it had when it was fetched, based on its modification date and version
+/// hash.
+pub(crate) fn check_unmodified(fetched: &Update, current: Option<Update>) -> Result<(), QueryError> {
+    match current {
+        Some(current)
+            if current.date_modified == fetched.date_modified && current.version_hash == fetched.version_hash =>
+        {
+            Ok(())
+        },
+        Some(current) => Err(QueryError::ConcurrentModification {
+            alias: current.alias,
+            date_modified: current.date_modified,
+        }),
+        None => Err(QueryError::UpdateNotFound {
+            alias: fetched.alias.clone(),
+        }),
+    }
+}
+
 
 #[derive(Debug, Deserialize)]
 struct RequestedUpdate {
@@ -314,12 +441,18 @@ impl<'a> UpdateStatusRequester<'a> {
     }
 }
 
+impl<'a> Validate for UpdateStatusRequester<'a> {
+    fn validate_with(&self, _context: &ValidationContext) -> Result<(), ValidationError> {
+        let mut problems = Vec::new();
+        check_known("request", Some(&self.request), &mut problems);
+        ValidationError::check(problems)
+    }
+}
+
 impl<'a> Edit<Update> for UpdateStatusRequester<'a> {
-    fn edit(&self, bodhi: &BodhiService) -> Result<Update, QueryError> {
+    fn request(&self, csrf_token: &str) -> Result<PostRequest, QueryError> {
         let path = format!("/updates/{}/request", &self.alias);
 
-        let csrf_token = bodhi.query(CSRFQuery::new())?;
-
         #[derive(Serialize)]
         struct RequestEdit<'a> {
             request: UpdateRequest,
@@ -327,8 +460,8 @@ impl<'a> Edit<Update> for UpdateStatusRequester<'a> {
         }
 
         let request_edit = RequestEdit {
-            request: self.request,
-            csrf_token: &csrf_token,
+            request: self.request.clone(),
+            csrf_token,
         };
 
         let data = match serde_json::to_string(&request_edit) {
@@ -336,18 +469,11 @@ impl<'a> Edit<Update> for UpdateStatusRequester<'a> {
             Err(error) => return Err(QueryError::SerializationError { error }),
         };
 
-        let response = bodhi.post(&path, data)?;
-        let status = response.status();
-
-        if !status.is_success() {
-            let text = response.text().unwrap_or_else(|_| String::from(""));
-
-            let error: BodhiError = serde_json::from_str(&text)?;
-            return Err(QueryError::BodhiError { error });
-        };
+        Ok(PostRequest::new(path, data))
+    }
 
-        let result = response.text()?;
-        let requested_update: RequestedUpdate = serde_json::from_str(&result)?;
+    fn parse(&self, string: &str) -> Result<Update, QueryError> {
+        let requested_update: RequestedUpdate = from_json(string)?;
 
         Ok(requested_update.update)
     }
@@ -376,12 +502,18 @@ impl<'a> UpdateTestResultWaiver<'a> {
     }
 }
 
+impl<'a> Validate for UpdateTestResultWaiver<'a> {
+    fn validate_with(&self, _context: &ValidationContext) -> Result<(), ValidationError> {
+        let mut problems = Vec::new();
+        check_notes(self.comment, &mut problems);
+        ValidationError::check(problems)
+    }
+}
+
 impl<'a> Edit<Update> for UpdateTestResultWaiver<'a> {
-    fn edit(&self, bodhi: &BodhiService) -> Result<Update, QueryError> {
+    fn request(&self, csrf_token: &str) -> Result<PostRequest, QueryError> {
         let path = format!("/updates/{}/waive-test-results", &self.alias);
 
-        let csrf_token = bodhi.query(CSRFQuery::new())?;
-
         #[derive(Serialize)]
         struct RequestWaiver<'a> {
             comment: &'a str,
@@ -392,7 +524,7 @@ impl<'a> Edit<Update> for UpdateTestResultWaiver<'a> {
         let request_waiver = RequestWaiver {
             comment: self.comment,
             // tests: ?
-            csrf_token: &csrf_token,
+            csrf_token,
         };
 
         let data = match serde_json::to_string(&request_waiver) {
@@ -400,18 +532,11 @@ impl<'a> Edit<Update> for UpdateTestResultWaiver<'a> {
             Err(error) => return Err(QueryError::SerializationError { error }),
         };
 
-        let response = bodhi.post(&path, data)?;
-        let status = response.status();
-
-        if !status.is_success() {
-            let text = response.text().unwrap_or_else(|_| String::from(""));
-
-            let error: BodhiError = serde_json::from_str(&text)?;
-            return Err(QueryError::BodhiError { error });
-        };
+        Ok(PostRequest::new(path, data))
+    }
 
-        let result = response.text()?;
-        let waived_update: WaivedUpdate = serde_json::from_str(&result)?;
+    fn parse(&self, string: &str) -> Result<Update, QueryError> {
+        let waived_update: WaivedUpdate = from_json(string)?;
 
         Ok(waived_update.update)
     }
@@ -0,0 +1,250 @@
+//! This module contains idempotent helpers for creating buildroot overrides and updates. Instead of
+//! failing (or creating duplicates) when the override or update already exists, they only change
+//! what is necessary, and report which action was taken. This makes it possible to re-run
+//! automation after a partial failure.
+//!
+//! ```
+//! # use bodhi::{BodhiDate, BodhiServiceBuilder, OverrideAction};
+//! # use std::convert::TryFrom;
+//! let bodhi = BodhiServiceBuilder::default().build().unwrap();
+//! let expiration_date = BodhiDate::try_from("2030-01-01").unwrap();
+//!
+//! # #[cfg(feature = "online-tests")]
+//! let ensured = bodhi
+//!     .ensure_override("rust-1.40.0-1.fc31", "rust 1.40.0", &expiration_date)
+//!     .unwrap();
+//! # #[cfg(feature = "online-tests")]
+//! if ensured.action != OverrideAction::Unchanged {
+//!     println!("{}: {}", ensured.over_ride.nvr, ensured.action);
+//! }
+//! ```
+
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::{Display, Formatter};
+
+use crate::error::QueryError;
+use crate::{
+    BodhiDate,
+    BodhiService,
+    Nvr,
+    Override,
+    OverrideBuilder,
+    OverrideNVRQuery,
+    Update,
+    UpdateBuilder,
+    UpdateQuery,
+};
+
+/// This enum represents the action that was taken to ensure that a buildroot override exists.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum OverrideAction {
+    /// There was no buildroot override for the build, and a new one was created.
+    Created,
+    /// The buildroot override existed, but expired too early, so its expiration date was extended.
+    Extended,
+    /// The buildroot override existed, but had already expired, so it was re-activated.
+    Unexpired,
+    /// The buildroot override existed and did not need to be changed.
+    Unchanged,
+}
+
+impl Display for OverrideAction {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        let value = match self {
+            OverrideAction::Created => "created",
+            OverrideAction::Extended => "extended",
+            OverrideAction::Unexpired => "unexpired",
+            OverrideAction::Unchanged => "unchanged",
+        };
+
+        write!(f, "{}", value)
+    }
+}
+
+/// This struct contains the buildroot override that is returned by
+/// [`BodhiService::ensure_override`](../service/struct.BodhiService.html#method.ensure_override),
+/// and the action that was taken.
+#[derive(Debug)]
+pub struct EnsuredOverride {
+    /// action that was taken
+    pub action: OverrideAction,
+    /// the current state of the buildroot override
+    pub over_ride: Override,
+    /// additional server messages (empty if the override was not changed)
+    pub caveats: Vec<HashMap<String, String>>,
+}
+
+/// This enum represents the action that was taken to ensure that an update exists.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum UpdateAction {
+    /// There was no update for the builds, and a new one was created.
+    Created,
+    /// There already was an update that contains all the builds.
+    Existing,
+}
+
+impl Display for UpdateAction {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        let value = match self {
+            UpdateAction::Created => "created",
+            UpdateAction::Existing => "existing",
+        };
+
+        write!(f, "{}", value)
+    }
+}
+
+/// This struct contains the update that is returned by
+/// [`BodhiService::ensure_update`](../service/struct.BodhiService.html#method.ensure_update), and
+/// the action that was taken.
+#[derive(Debug)]
+pub struct EnsuredUpdate {
+    /// action that was taken
+    pub action: UpdateAction,
+    /// the new or existing update
+    pub update: Update,
+    /// additional server messages (empty for existing updates)
+    pub caveats: Vec<HashMap<String, String>>,
+}
+
+impl BodhiService {
+    /// This method makes sure that a buildroot override for the build with the given NVR exists,
+    /// and that it does not expire before the given expiration date. If there is no buildroot
+    /// override yet, a new one is created with the given notes. Existing buildroot overrides keep
+    /// their notes, but are extended or re-activated if necessary.
+    pub fn ensure_override(
+        &self,
+        nvr: &str,
+        notes: &str,
+        expiration_date: &BodhiDate,
+    ) -> Result<EnsuredOverride, QueryError> {
+        let over_ride = match self.query(OverrideNVRQuery::new(&nvr.parse()?))? {
+            Some(over_ride) => over_ride,
+            None => {
+                let new_override = self.create(&OverrideBuilder::new(nvr, notes, expiration_date))?;
+
+                return Ok(EnsuredOverride {
+                    action: OverrideAction::Created,
+                    over_ride: new_override.over_ride,
+                    caveats: new_override.caveats,
+                });
+            },
+        };
+
+        let action = override_action(&over_ride, expiration_date);
+        let editor = over_ride
+            .edit()
+            .expiration_date(override_expiration(&over_ride, expiration_date));
+
+        let edited = match action {
+            OverrideAction::Extended => self.edit(&editor)?,
+            OverrideAction::Unexpired => self.edit(&editor.expired(false))?,
+            _ => {
+                return Ok(EnsuredOverride {
+                    action,
+                    over_ride,
+                    caveats: Vec::new(),
+                })
+            },
+        };
+
+        Ok(EnsuredOverride {
+            action,
+            over_ride: edited.over_ride,
+            caveats: edited.caveats,
+        })
+    }
+
+    /// This method makes sure that an update for the builds of the given builder exists. If there
+    /// already is an update that contains all of these builds, it is returned unchanged. Otherwise,
+    /// a new update is created from the builder. If some (but not all) builds are already part of
+    /// other updates, an error is returned, since a build can only be part of one update.
+    ///
+    /// Updates for side tags can not be looked up by their builds, so builders for side tag
+    /// updates are rejected.
+    pub fn ensure_update(&self, builder: &UpdateBuilder) -> Result<EnsuredUpdate, QueryError> {
+        let builds = match builder.builds() {
+            Some(builds) => builds,
+            None => {
+                return Err(QueryError::InvalidDataError {
+                    error: String::from("Updates for side tags can not be looked up by their builds."),
+                })
+            },
+        };
+
+        let nvrs = builds.iter().map(|nvr| nvr.parse()).collect::<Result<Vec<Nvr>, _>>()?;
+        let updates = self.query(UpdateQuery::new().builds(nvrs))?;
+
+        match find_update(updates, builds)? {
+            Some(update) => Ok(EnsuredUpdate {
+                action: UpdateAction::Existing,
+                update,
+                caveats: Vec::new(),
+            }),
+            None => {
+                let new_update = self.create(builder)?;
+
+                Ok(EnsuredUpdate {
+                    action: UpdateAction::Created,
+                    update: new_update.update,
+                    caveats: new_update.caveats,
+                })
+            },
+        }
+    }
+}
+
+/// This function determines which action is necessary to make sure that the given existing
+/// buildroot override does not expire before the given expiration date.
+pub(crate) fn override_action(over_ride: &Override, expiration_date: &BodhiDate) -> OverrideAction {
+    if over_ride.expired_date.is_some() {
+        OverrideAction::Unexpired
+    } else if over_ride.expiration_date < *expiration_date {
+        OverrideAction::Extended
+    } else {
+        OverrideAction::Unchanged
+    }
+}
+
+/// This function returns the expiration date for an existing buildroot override that needs to be
+/// changed, which is the later one of its current and the requested expiration date.
+pub(crate) fn override_expiration<'a>(over_ride: &'a Override, expiration_date: &'a BodhiDate) -> &'a BodhiDate {
+    std::cmp::max(&over_ride.expiration_date, expiration_date)
+}
+
+/// This function returns the update that contains all of the given builds, if there is one. If
+/// some of the builds are part of other updates instead, an error is returned.
+pub(crate) fn find_update(updates: Vec<Update>, builds: &[&str]) -> Result<Option<Update>, QueryError> {
+    let wanted: BTreeSet<&str> = builds.iter().copied().collect();
+    let mut conflicts: Vec<Update> = Vec::new();
+
+    for update in updates {
+        let contained: BTreeSet<&str> = update.builds.iter().map(|build| build.nvr.as_str()).collect();
+
+        if wanted.is_subset(&contained) {
+            return Ok(Some(update));
+        }
+
+        if !wanted.is_disjoint(&contained) {
+            conflicts.push(update);
+        }
+    }
+
+    if conflicts.is_empty() {
+        return Ok(None);
+    }
+
+    let mut conflicting: BTreeSet<String> = BTreeSet::new();
+    for update in &conflicts {
+        for build in &update.builds {
+            if wanted.contains(build.nvr.as_str()) {
+                conflicting.insert(build.nvr.clone());
+            }
+        }
+    }
+
+    Err(QueryError::ConflictingBuilds {
+        builds: conflicting.into_iter().collect(),
+        aliases: conflicts.into_iter().map(|update| update.alias).collect(),
+    })
+}
@@ -1,21 +1,127 @@
 //! This module contains some common error types for wrapping networking-related issues,
 //! server-side issues, and client-side issues (including JSON deserialization problems).
-use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
 
-use serde::Deserialize;
+use reqwest::blocking::Response;
+use reqwest::header::CONTENT_TYPE;
+use serde::{Deserialize, Serialize};
+
+use crate::{BodhiDate, InvalidValueError, ServerVersion};
+
+/// This struct represents one entry of an error response from bodhi. Bodhi uses the error format
+/// of [cornice](https://cornice.readthedocs.io), where every entry contains the location of the
+/// invalid value (for example, `body` or `querystring`), the name of the argument, and a
+/// human-readable description of the problem.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(default)]
+pub struct BodhiErrorEntry {
+    /// location of the invalid value (`body`, `querystring`, `url`, `header`, `cookies`)
+    pub location: String,
+    /// name of the invalid argument (empty for errors that are not associated with an argument)
+    pub name: String,
+    /// description of the error
+    pub description: String,
+}
+
+impl Display for BodhiErrorEntry {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match (self.location.is_empty(), self.name.is_empty()) {
+            (false, false) => write!(f, "{}.{}: {}", self.location, self.name, self.description),
+            (false, true) => write!(f, "{}: {}", self.location, self.description),
+            (true, false) => write!(f, "{}: {}", self.name, self.description),
+            (true, true) => write!(f, "{}", self.description),
+        }
+    }
+}
+
+/// This enum represents common categories of errors that are returned by bodhi, which is useful
+/// for deciding how to handle failures without matching on error messages.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum BodhiErrorCategory {
+    /// The CSRF token was missing or invalid (for example, because the session expired).
+    CsrfFailure,
+    /// The update is locked (for example, because it is part of a running compose).
+    LockedUpdate,
+    /// The karma values or thresholds were invalid, or the karma threshold has not been reached.
+    KarmaThreshold,
+    /// One of the builds was invalid (for example, it does not exist, or has the wrong tags).
+    InvalidBuild,
+    /// The user is not allowed to perform the requested operation.
+    PermissionDenied,
+    /// The error does not belong to any of the known categories.
+    Other,
+}
 
 /// This struct contains error messages that are deserialized from bodhi's error responses.
 #[derive(Debug, Deserialize, thiserror::Error)]
 pub struct BodhiError {
     /// This field contains a list of server-side error messages.
-    pub errors: Vec<HashMap<String, String>>,
+    pub errors: Vec<BodhiErrorEntry>,
     /// This field contains the server-side status message for the failure.
     pub status: String,
+    /// This field contains the HTTP status code of the response, if it is known.
+    #[serde(skip)]
+    pub http_status: Option<u16>,
+}
+
+impl BodhiError {
+    pub(crate) fn from_response(http_status: u16, text: &str) -> Result<Self, serde_json::Error> {
+        let mut error: BodhiError = serde_json::from_str(text)?;
+        error.http_status = Some(http_status);
+        Ok(error)
+    }
+
+    /// This method returns the error entries for the argument with the given name.
+    pub fn entries_for<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a BodhiErrorEntry> {
+        self.errors.iter().filter(move |entry| entry.name == name)
+    }
+
+    /// This method classifies this error into one of the common error categories, based on the
+    /// names and descriptions of its entries, and the HTTP status code.
+    pub fn category(&self) -> BodhiErrorCategory {
+        let matches = |check: &dyn Fn(&str, &str) -> bool| {
+            self.errors
+                .iter()
+                .any(|entry| check(&entry.name.to_lowercase(), &entry.description.to_lowercase()))
+        };
+
+        if matches(&|name, description| name == "csrf_token" || description.contains("csrf")) {
+            BodhiErrorCategory::CsrfFailure
+        } else if matches(&|_, description| description.contains("locked")) {
+            BodhiErrorCategory::LockedUpdate
+        } else if matches(&|name, description| name.contains("karma") || description.contains("karma")) {
+            BodhiErrorCategory::KarmaThreshold
+        } else if self.http_status == Some(403)
+            || matches(&|_, description| {
+                description.contains("permission")
+                    || description.contains("not allowed")
+                    || description.contains("commit access")
+            })
+        {
+            // ACL failures are reported for the "builds" argument, so they need to be checked first
+            BodhiErrorCategory::PermissionDenied
+        } else if matches(&|name, _| name == "builds" || name == "nvr") {
+            BodhiErrorCategory::InvalidBuild
+        } else {
+            BodhiErrorCategory::Other
+        }
+    }
 }
 
-impl std::fmt::Display for BodhiError {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
-        write!(f, "{:#?}", self)
+impl Display for BodhiError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self.http_status {
+            Some(code) => write!(f, "{} (HTTP {})", self.status, code)?,
+            None => write!(f, "{}", self.status)?,
+        };
+
+        for (i, entry) in self.errors.iter().enumerate() {
+            write!(f, "{} {}", if i == 0 { ":" } else { ";" }, entry)?;
+        }
+
+        Ok(())
     }
 }
 
@@ -30,12 +136,25 @@ pub enum QueryError {
         error: reqwest::Error,
     },
     /// This error represents an issue with deserializing JSON request data. If this ever happens,
-    /// it is almost certainly a bug in this crate.
-    #[error("Failed to deserialize JSON response: {error}")]
+    /// it is almost certainly a bug in this crate (or a change in the JSON format of the server).
+    #[error(
+        "Failed to deserialize JSON response{}: {error}",
+        deserialization_context(.endpoint, .page, .path, .dump)
+    )]
     DeserializationError {
         /// The inner error contains the deserialization error message from
         /// [`serde_json`](https://docs.rs/serde_json).
         error: serde_json::Error,
+        /// This field contains the JSON path of the value that could not be deserialized (for
+        /// example, `updates[17].builds[2].epoch`), if it could be determined.
+        path: Option<String>,
+        /// This field contains the API endpoint that returned the response, if it is known.
+        endpoint: Option<String>,
+        /// This field contains the page number of paginated results, if it is known.
+        page: Option<u32>,
+        /// This field contains the path of the file that the response was saved to, if a dump
+        /// directory was configured for the [`BodhiService`](../service/struct.BodhiService.html).
+        dump: Option<PathBuf>,
     },
     /// This error represents an issue with serializing request data for POST requests. Since all
     /// data that can be supplied to POST request builders should be valid, this should never
@@ -73,11 +192,236 @@ pub enum QueryError {
         /// This inner error contains a the reason why the data was considered invalid.
         error: String,
     },
+    /// This error represents data that failed client-side validation before it was sent to the
+    /// server.
+    #[error("Invalid data: {error}")]
+    ValidationError {
+        /// The inner error contains the list of problems that were found.
+        error: ValidationError,
+    },
+    /// This error represents an update that was modified on the server after it was fetched, so
+    /// editing it could overwrite changes that were made by somebody else in the meantime.
+    #[error("Update {alias} was modified concurrently (last modified: {})", display_date(.date_modified))]
+    ConcurrentModification {
+        /// This field contains the alias of the modified update.
+        alias: String,
+        /// This field contains the date of the last modification of the update on the server.
+        date_modified: Option<BodhiDate>,
+    },
+    /// This error represents an update that was expected to exist on the server, but was not found
+    /// (for example, because it was deleted after it was fetched).
+    #[error("Update does not exist: {alias}")]
+    UpdateNotFound {
+        /// This field contains the alias of the missing update.
+        alias: String,
+    },
+    /// This error represents builds that can not be included in a new update, because they are
+    /// already part of other updates.
+    #[error("Builds are already part of other updates: {} ({})", .builds.join(", "), .aliases.join(", "))]
+    ConflictingBuilds {
+        /// This field contains the NVRs of the builds that are already part of other updates.
+        builds: Vec<String>,
+        /// This field contains the aliases of the updates that contain these builds.
+        aliases: Vec<String>,
+    },
+    /// This error represents a remote bodhi instance that runs a server version which is not
+    /// supported for the requested operation.
+    #[error("Unsupported bodhi server version: {version} (required: {required} or later)")]
+    UnsupportedServerVersion {
+        /// This field contains the version of the remote bodhi instance.
+        version: ServerVersion,
+        /// This field contains the minimum version that is required for the operation.
+        required: ServerVersion,
+    },
+    /// This error represents an error response from the server that could not be parsed as a bodhi
+    /// error message (for example, an HTML error page from a proxy, or an empty response).
+    #[error("Unexpected response from bodhi service (HTTP {status}): {body}")]
+    UnexpectedResponse {
+        /// This field contains the HTTP status code of the response.
+        status: u16,
+        /// This field contains the value of the `Content-Type` header of the response, if present.
+        content_type: Option<String>,
+        /// This field contains the body of the response, truncated to at most 1024 bytes.
+        body: String,
+    },
+}
+
+/// maximum length of response bodies that are included in error messages
+const MAX_BODY_LENGTH: usize = 1024;
+
+impl QueryError {
+    pub(crate) fn from_response(response: Response) -> Self {
+        let status = response.status().as_u16();
+        let content_type = response
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .map(String::from);
+        let body = response.text().unwrap_or_default();
+
+        Self::from_parts(status, content_type, body)
+    }
+
+    pub(crate) fn from_parts(status: u16, content_type: Option<String>, body: String) -> Self {
+        match BodhiError::from_response(status, &body) {
+            Ok(error) => QueryError::BodhiError { error },
+            Err(_) => QueryError::UnexpectedResponse {
+                status,
+                content_type,
+                body: truncate(body),
+            },
+        }
+    }
+
+    /// This method returns `true` if the failed operation could succeed when it is retried later
+    /// without changes, for example, for network timeouts, or when the server is overloaded or
+    /// temporarily unavailable. Errors that are caused by invalid data or missing permissions are
+    /// not retryable.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            QueryError::RequestError { error } => is_retryable_request(error),
+            QueryError::DeserializationError { .. } => false,
+            QueryError::SerializationError { .. } => false,
+            QueryError::BodhiError { error } => {
+                matches!(error.http_status, Some(status) if is_retryable_status(status))
+            },
+            QueryError::ServiceError { error } => match error {
+                ServiceError::RequestError { error } => is_retryable_request(error),
+                ServiceError::UrlParsingError { .. } => false,
+                ServiceError::EmptyResponseError => true,
+                ServiceError::RetryError => true,
+            },
+            QueryError::UrlEncodedError { .. } => false,
+            QueryError::InvalidDataError { .. } => false,
+            QueryError::ValidationError { .. } => false,
+            QueryError::ConcurrentModification { .. } => false,
+            QueryError::UpdateNotFound { .. } => false,
+            QueryError::ConflictingBuilds { .. } => false,
+            QueryError::UnsupportedServerVersion { .. } => false,
+            QueryError::UnexpectedResponse { status, .. } => is_retryable_status(*status),
+        }
+    }
+}
+
+fn is_retryable_request(error: &reqwest::Error) -> bool {
+    error.is_timeout()
+        || error.is_connect()
+        || matches!(error.status(), Some(status) if is_retryable_status(status.as_u16()))
+}
+
+// request timeouts, rate limits, and (temporary) server-side or gateway failures
+fn is_retryable_status(status: u16) -> bool {
+    matches!(status, 408 | 429 | 500 | 502 | 503 | 504)
+}
+
+fn truncate(mut body: String) -> String {
+    if body.len() > MAX_BODY_LENGTH {
+        let mut end = MAX_BODY_LENGTH;
+        while !body.is_char_boundary(end) {
+            end -= 1;
+        }
+
+        body.truncate(end);
+        body.push_str("...");
+    }
+
+    body
+}
+
+fn display_date(date: &Option<BodhiDate>) -> String {
+    match date {
+        Some(date) => date.to_string(),
+        None => String::from("unknown"),
+    }
+}
+
+fn deserialization_context(
+    endpoint: &Option<String>,
+    page: &Option<u32>,
+    path: &Option<String>,
+    dump: &Option<PathBuf>,
+) -> String {
+    let mut context = String::new();
+
+    if let Some(endpoint) = endpoint {
+        context.push_str(&format!(" from {}", endpoint));
+    }
+    if let Some(page) = page {
+        context.push_str(&format!(" (page {})", page));
+    }
+    if let Some(path) = path {
+        context.push_str(&format!(" at {}", path));
+    }
+    if let Some(dump) = dump {
+        context.push_str(&format!(" (saved to {})", dump.display()));
+    }
+
+    context
 }
 
 impl From<serde_json::Error> for QueryError {
     fn from(error: serde_json::Error) -> Self {
-        QueryError::DeserializationError { error }
+        QueryError::DeserializationError {
+            error,
+            path: None,
+            endpoint: None,
+            page: None,
+            dump: None,
+        }
+    }
+}
+
+/// This struct contains the result of a lenient query, together with the items of paginated
+/// results that could not be deserialized, and which were skipped (see
+/// [`BodhiService::query_lenient`](../service/struct.BodhiService.html#method.query_lenient)).
+#[derive(Debug)]
+pub struct Lenient<T> {
+    /// This field contains the result of the query, without the skipped items.
+    pub result: T,
+    /// This field contains the items that were skipped, in the order they were encountered.
+    pub skipped: Vec<SkippedItem>,
+}
+
+/// This struct contains information about an item of paginated results that could not be
+/// deserialized, and which was skipped by a lenient query.
+#[derive(Clone, Debug)]
+pub struct SkippedItem {
+    /// This field contains the API endpoint that returned the response.
+    pub endpoint: String,
+    /// This field contains the page number of paginated results, if it is known.
+    pub page: Option<u32>,
+    /// This field contains the JSON path of the value that could not be deserialized.
+    pub path: String,
+    /// This field contains the deserialization error message.
+    pub error: String,
+    /// This field contains the path of the file that the skipped item was saved to, if a dump
+    /// directory was configured.
+    pub dump: Option<PathBuf>,
+}
+
+impl Display for SkippedItem {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "Skipped malformed item {} from {}", self.path, self.endpoint)?;
+
+        if let Some(page) = self.page {
+            write!(f, " (page {})", page)?;
+        }
+
+        write!(f, ": {}", self.error)
+    }
+}
+
+impl From<InvalidValueError> for QueryError {
+    fn from(error: InvalidValueError) -> Self {
+        QueryError::InvalidDataError {
+            error: error.to_string(),
+        }
+    }
+}
+
+impl From<ValidationError> for QueryError {
+    fn from(error: ValidationError) -> Self {
+        QueryError::ValidationError { error }
     }
 }
 
@@ -139,3 +483,141 @@ impl From<url::ParseError> for ServiceError {
         ServiceError::UrlParsingError { error }
     }
 }
+
+/// This enum encapsulates the different ways in which parsing a bodhi URL into a query can fail.
+#[derive(Debug, thiserror::Error)]
+pub enum UrlError {
+    /// This error represents a string that is not a valid URL.
+    #[error("Failed to parse URL: {error}")]
+    ParseError {
+        /// The inner error contains the error that occurred when parsing the URL.
+        error: url::ParseError,
+    },
+    /// This error represents a URL path that does not correspond to a supported bodhi resource.
+    #[error("Unsupported URL path: {path}")]
+    UnsupportedPath {
+        /// This field contains the path of the URL.
+        path: String,
+    },
+    /// This error represents a query parameter that is not supported for the resource.
+    #[error("Unknown query parameter for {resource}: {parameter}")]
+    UnknownParameter {
+        /// This field contains the name of the resource (or query).
+        resource: &'static str,
+        /// This field contains the name of the unknown query parameter.
+        parameter: String,
+    },
+    /// This error represents a query parameter (or path component) with an invalid value.
+    #[error("Invalid value for {parameter}: {error}")]
+    InvalidValue {
+        /// This field contains the name of the query parameter.
+        parameter: String,
+        /// This field contains the reason why the value was considered invalid.
+        error: String,
+    },
+}
+
+impl From<url::ParseError> for UrlError {
+    fn from(error: url::ParseError) -> Self {
+        UrlError::ParseError { error }
+    }
+}
+
+/// This enum represents the problems that can be found by client-side validation of the data that
+/// is about to be sent to bodhi with a `Create` or `Edit` request.
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+#[non_exhaustive]
+pub enum ValidationProblem {
+    /// The notes (or the text of a comment or waiver) are empty.
+    #[error("Notes must not be empty.")]
+    EmptyNotes,
+    /// The comment contains neither text, nor karma, nor feedback.
+    #[error("Comments must contain text, karma, or feedback.")]
+    EmptyComment,
+    /// The build is not a valid NVR.
+    #[error("Invalid build: {nvr} ({error})")]
+    InvalidBuild {
+        /// This field contains the invalid build.
+        nvr: String,
+        /// This field contains the reason why the build is invalid.
+        error: String,
+    },
+    /// The build is included more than once.
+    #[error("Duplicate build: {nvr}")]
+    DuplicateBuild {
+        /// This field contains the duplicate build.
+        nvr: String,
+    },
+    /// The builds are from different releases (as determined from their dist tags).
+    #[error("Builds are from different releases: {}", .dist_tags.join(", "))]
+    MixedReleases {
+        /// This field contains the different dist tags of the builds.
+        dist_tags: Vec<String>,
+    },
+    /// The expiration date is in the past.
+    #[error("Expiration date is in the past: {expiration_date}")]
+    ExpirationInPast {
+        /// This field contains the invalid expiration date.
+        expiration_date: BodhiDate,
+    },
+    /// The stable karma threshold is not positive.
+    #[error("Stable karma must be positive (is {karma}).")]
+    InvalidStableKarma {
+        /// This field contains the invalid karma threshold.
+        karma: i32,
+    },
+    /// The unstable karma threshold is not negative.
+    #[error("Unstable karma must be negative (is {karma}).")]
+    InvalidUnstableKarma {
+        /// This field contains the invalid karma threshold.
+        karma: i32,
+    },
+    /// The update is a security update, but its severity is not specified.
+    #[error("For security updates, severity has to be specified.")]
+    MissingSeverity,
+    /// The number of days in testing is below the minimum of the release.
+    #[error("Days in testing must be at least {minimum} (is {stable_days}).")]
+    StableDaysBelowMinimum {
+        /// This field contains the invalid number of days.
+        stable_days: u32,
+        /// This field contains the minimum number of days for the release.
+        minimum: u32,
+    },
+    /// The builds of an update that was created from a side tag were edited directly.
+    #[error("Builds of side tag updates can not be edited directly.")]
+    SideTagBuildsEdited,
+    /// The value of a field is not one of the values that are known to this crate, and would be
+    /// rejected by the server.
+    #[error("Unknown value for {field}: {value}")]
+    UnknownValue {
+        /// This field contains the name of the field.
+        field: &'static str,
+        /// This field contains the unknown value.
+        value: String,
+    },
+}
+
+/// This struct contains all problems that were found by client-side validation.
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+#[error("{}", problem_list(.problems))]
+pub struct ValidationError {
+    /// This field contains the list of problems (which is never empty).
+    pub problems: Vec<ValidationProblem>,
+}
+
+impl ValidationError {
+    /// This method returns `Ok(())` if the list of problems is empty, and a `ValidationError` with
+    /// all problems otherwise.
+    pub fn check(problems: Vec<ValidationProblem>) -> Result<(), ValidationError> {
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(ValidationError { problems })
+        }
+    }
+}
+
+fn problem_list(problems: &[ValidationProblem]) -> String {
+    let problems: Vec<String> = problems.iter().map(|problem| problem.to_string()).collect();
+    problems.join(" ")
+}
@@ -0,0 +1,244 @@
+//! This module contains a client-side abstraction for groups of interdependent updates.
+//!
+//! Bodhi has no concept of updates that need to be pushed together, so an
+//! [`UpdateGroup`](struct.UpdateGroup.html) tracks a set of update aliases, can report their
+//! combined gating and karma state, and can submit status requests for all members. If submitting
+//! a request fails for one member, the requests that were already submitted for the other members
+//! are rolled back again.
+//!
+//! ```
+//! # use bodhi::{BodhiServiceBuilder, UpdateGroup, UpdateRequest};
+//! let bodhi = BodhiServiceBuilder::default().build().unwrap();
+//!
+//! let group = UpdateGroup::new(vec!["FEDORA-2019-cf87377f5f", "FEDORA-2019-24c9d17287"]);
+//!
+//! # #[cfg(feature = "online-tests")]
+//! let status = group.status(&bodhi).unwrap();
+//! # #[cfg(feature = "online-tests")]
+//! if status.is_ready_for_stable() {
+//!     // group.request(&bodhi, UpdateRequest::Stable).unwrap();
+//! }
+//! ```
+
+use crate::error::QueryError;
+use crate::{
+    BodhiService,
+    TestGatingStatus,
+    Update,
+    UpdateAlias,
+    UpdateIDQuery,
+    UpdateRequest,
+    UpdateStatus,
+    UpdateStatusRequester,
+};
+
+/// This enum contains variants for all the ways in which operations on an
+/// [`UpdateGroup`](struct.UpdateGroup.html) can fail.
+#[derive(Debug, thiserror::Error)]
+pub enum UpdateGroupError {
+    /// This error represents a failure to query the current state of a member of the group.
+    #[error("Failed to query update {alias}: {error}")]
+    QueryError {
+        /// alias of the update that could not be queried
+        alias: String,
+        /// The inner error contains the reason why the query failed.
+        error: QueryError,
+    },
+    /// This error represents a member of the group that does not exist on the server.
+    #[error("Update {alias} does not exist.")]
+    MissingUpdate {
+        /// alias of the missing update
+        alias: String,
+    },
+    /// This error represents a member of the group that can not be changed right now (for example,
+    /// because it is locked while it is part of a running compose).
+    #[error("Update {alias} is locked.")]
+    LockedUpdate {
+        /// alias of the locked update
+        alias: String,
+    },
+    /// This error represents a failure to submit a status request for a member of the group. The
+    /// requests for the other members that were submitted before the failure were rolled back.
+    #[error("Failed to submit request for update {alias}: {error}")]
+    RequestError {
+        /// alias of the update for which the request failed
+        alias: String,
+        /// The inner error contains the reason why the request failed.
+        error: Box<QueryError>,
+        /// aliases of the updates for which the request was successfully rolled back
+        rolled_back: Vec<String>,
+        /// aliases of the updates for which rolling back the request failed, with the reason
+        rollback_errors: Vec<(String, QueryError)>,
+    },
+}
+
+/// This struct represents a group of interdependent updates that need to be pushed to testing and
+/// stable together.
+#[derive(Debug)]
+pub struct UpdateGroup<'a> {
+    aliases: Vec<&'a str>,
+}
+
+impl<'a> UpdateGroup<'a> {
+    /// This method creates a new `UpdateGroup` for the updates with the given aliases.
+    pub fn new(aliases: Vec<&'a str>) -> Self {
+        UpdateGroup { aliases }
+    }
+
+    /// Add an update to the group.
+    pub fn add_update(mut self, alias: &'a str) -> Self {
+        if !self.aliases.contains(&alias) {
+            self.aliases.push(alias);
+        }
+        self
+    }
+
+    /// This method returns the aliases of the updates that are members of this group.
+    pub fn aliases(&self) -> &[&'a str] {
+        &self.aliases
+    }
+
+    /// This method queries the current state of all members of this group.
+    pub fn status(&self, bodhi: &BodhiService) -> Result<UpdateGroupStatus, UpdateGroupError> {
+        let mut updates = Vec::new();
+
+        for alias in &self.aliases {
+            let update = alias
+                .parse::<UpdateAlias>()
+                .map_err(QueryError::from)
+                .and_then(|id| bodhi.query(UpdateIDQuery::new(id)));
+
+            match update {
+                Ok(Some(update)) => updates.push(update),
+                Ok(None) => {
+                    return Err(UpdateGroupError::MissingUpdate {
+                        alias: alias.to_string(),
+                    })
+                },
+                Err(error) => {
+                    return Err(UpdateGroupError::QueryError {
+                        alias: alias.to_string(),
+                        error,
+                    })
+                },
+            }
+        }
+
+        Ok(UpdateGroupStatus { updates })
+    }
+
+    /// This method submits the given status request for all members of this group, and returns
+    /// the updated values.
+    ///
+    /// Before submitting anything, the current state of all members is checked, so missing or
+    /// locked updates cause the whole operation to fail early. If submitting the request fails for
+    /// one member, the requests that were already submitted for other members are rolled back:
+    /// members that had a pending request before are sent that request again, and members that had
+    /// no pending request have their request revoked with
+    /// [`UpdateRequest::Revoke`](../data/enum.UpdateRequest.html). This leaves the group in a
+    /// consistent state where possible, but bodhi itself can not guarantee atomicity across
+    /// updates.
+    pub fn request(&self, bodhi: &BodhiService, request: UpdateRequest) -> Result<Vec<Update>, UpdateGroupError> {
+        let status = self.status(bodhi)?;
+
+        if let Some(update) = status.updates.iter().find(|u| u.locked) {
+            return Err(UpdateGroupError::LockedUpdate {
+                alias: update.alias.clone(),
+            });
+        }
+
+        let mut requested: Vec<Update> = Vec::new();
+
+        for update in &status.updates {
+            match bodhi.edit(&UpdateStatusRequester::from_update(update, request.clone())) {
+                Ok(result) => requested.push(result),
+                Err(error) => {
+                    let mut rolled_back = Vec::new();
+                    let mut rollback_errors = Vec::new();
+
+                    for (previous, done) in status.updates.iter().zip(&requested) {
+                        let rollback = match rollback_request(previous.request.as_ref(), &request) {
+                            Some(rollback) => rollback,
+                            None => continue,
+                        };
+
+                        match bodhi.edit(&UpdateStatusRequester::from_update(done, rollback)) {
+                            Ok(_) => rolled_back.push(done.alias.clone()),
+                            Err(error) => rollback_errors.push((done.alias.clone(), error)),
+                        }
+                    }
+
+                    return Err(UpdateGroupError::RequestError {
+                        alias: update.alias.clone(),
+                        error: Box::new(error),
+                        rolled_back,
+                        rollback_errors,
+                    });
+                },
+            }
+        }
+
+        Ok(requested)
+    }
+}
+
+/// This struct contains the current state of all members of an
+/// [`UpdateGroup`](struct.UpdateGroup.html), with methods for computing their combined state.
+#[derive(Debug)]
+pub struct UpdateGroupStatus {
+    /// current values of all members of the group
+    pub updates: Vec<Update>,
+}
+
+impl UpdateGroupStatus {
+    /// This method returns the common status of all members, or `None` if they differ.
+    pub fn status(&self) -> Option<UpdateStatus> {
+        let mut statuses = self.updates.iter().map(|u| &u.status);
+        let first = statuses.next()?;
+
+        if statuses.all(|status| status == first) {
+            Some(first.clone())
+        } else {
+            None
+        }
+    }
+
+    /// This method returns `true` if no member of the group is blocked by test gating. Updates for
+    /// which gating is not enabled are not considered to be blocked.
+    pub fn is_gating_passed(&self) -> bool {
+        self.updates.iter().all(|u| match u.test_gating_status {
+            Some(TestGatingStatus::Passed) | Some(TestGatingStatus::Ignored) | None => true,
+            Some(_) => false,
+        })
+    }
+
+    /// This method returns the lowest karma of all members of the group.
+    pub fn min_karma(&self) -> Option<i32> {
+        self.updates.iter().map(|u| u.karma.unwrap_or(0)).min()
+    }
+
+    /// This method returns `true` if all members of the group have reached their stable karma
+    /// threshold.
+    pub fn is_karma_reached(&self) -> bool {
+        self.updates.iter().all(|u| match u.stable_karma {
+            Some(threshold) => u.karma.unwrap_or(0) >= threshold,
+            None => true,
+        })
+    }
+
+    /// This method returns `true` if all members of the group meet the testing requirements
+    /// (either by karma or by time in testing), and are not blocked by test gating.
+    pub fn is_ready_for_stable(&self) -> bool {
+        self.updates.iter().all(|u| u.meets_testing_requirements) && self.is_gating_passed()
+    }
+}
+
+/// This function returns the request that restores the previous request of an update after the
+/// given request was submitted for it, or `None` if the request did not change anything.
+pub(crate) fn rollback_request(previous: Option<&UpdateRequest>, submitted: &UpdateRequest) -> Option<UpdateRequest> {
+    match previous {
+        Some(previous) if previous == submitted => None,
+        Some(previous) => Some(previous.clone()),
+        None => Some(UpdateRequest::Revoke),
+    }
+}
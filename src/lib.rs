@@ -13,6 +13,13 @@
 //!   a Rust-y API
 //! - a set of `Create` implementations for creating new data on bodhi
 //! - a set of `Edit` implementations for editing data on bodhi
+//! - a [`Validate`](validate/trait.Validate.html) trait for checking data before it is sent
+//! - [`BatchOptions`](batch/struct.BatchOptions.html) for creating or editing many things at once
+//! - idempotent [`ensure_*`](ensure/index.html) helpers for buildroot overrides and updates
+//! - an [`UpdateGroup`](group/struct.UpdateGroup.html) for pushing interdependent updates together
+//! - an [`OverrideManager`](manager/struct.OverrideManager.html) for extending and expiring
+//!   buildroot overrides in bulk
+//! - a [`ComposeWatcher`](watch/struct.ComposeWatcher.html) for monitoring running composes
 //! - data type and enum definitions, used for (de)serializing JSON values with [serde]
 //!
 //! [serde]: https://docs.rs/serde
@@ -58,7 +65,7 @@ pub mod data;
 pub use data::*;
 
 pub mod service;
-pub use service::{BodhiService, BodhiServiceBuilder};
+pub use service::{BodhiService, BodhiServiceBuilder, PostRequest};
 
 pub mod error;
 
@@ -68,8 +75,26 @@ pub use create::*;
 pub mod edit;
 pub use edit::*;
 
+pub mod batch;
+pub use batch::{BatchOptions, BatchReport, BatchResult};
+
+pub mod ensure;
+pub use ensure::{EnsuredOverride, EnsuredUpdate, OverrideAction, UpdateAction};
+
+pub mod validate;
+pub use validate::{Validate, ValidationContext};
+
+pub mod group;
+pub use group::{UpdateGroup, UpdateGroupError, UpdateGroupStatus};
+
+pub mod manager;
+pub use manager::{OverrideManager, OverrideReport};
+
 pub mod query;
 pub use query::*;
 
+pub mod watch;
+pub use watch::{ComposeEvent, ComposeWatcher};
+
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,197 @@
+//! This module contains a client-side manager for the lifecycle of buildroot overrides.
+//!
+//! Buildroot overrides that are created with an
+//! [`OverrideBuilder`](../create/struct.OverrideBuilder.html) are easily forgotten: they either
+//! expire while they are still needed, or they linger long after the builds that depend on them
+//! have landed. An [`OverrideManager`](struct.OverrideManager.html) lists the active buildroot
+//! overrides of some users, finds the ones that are about to expire, and extends or expires them in
+//! bulk. Bulk operations are sent with
+//! [`BodhiService::edit_many`](../service/struct.BodhiService.html#method.edit_many), and produce
+//! an [`OverrideReport`](struct.OverrideReport.html).
+//!
+//! ```
+//! # use bodhi::{BodhiDate, BodhiServiceBuilder, OverrideManager};
+//! # use std::convert::TryFrom;
+//! # use std::time::Duration;
+//! let bodhi = BodhiServiceBuilder::default().build().unwrap();
+//! let manager = OverrideManager::new(vec!["decathorpe"]);
+//!
+//! # #[cfg(feature = "online-tests")]
+//! let expiring = manager.expiring(&bodhi, Duration::from_secs(3 * 24 * 60 * 60)).unwrap();
+//!
+//! let expiration_date = BodhiDate::try_from("2030-01-01").unwrap();
+//! # #[cfg(feature = "online-tests")]
+//! let report = manager.extend(&bodhi, &expiring, &expiration_date).unwrap();
+//! ```
+
+use std::collections::BTreeSet;
+use std::time::Duration;
+
+use chrono::Utc;
+
+use crate::error::QueryError;
+use crate::{
+    BatchOptions,
+    BatchReport,
+    BatchResult,
+    BodhiDate,
+    BodhiService,
+    EditedOverride,
+    Nvr,
+    Override,
+    OverrideEditor,
+    OverrideQuery,
+    Update,
+    UpdateQuery,
+    UpdateStatus,
+};
+
+/// This struct manages the active buildroot overrides of a set of users.
+#[derive(Debug)]
+pub struct OverrideManager<'a> {
+    users: Vec<&'a str>,
+    options: BatchOptions,
+}
+
+impl<'a> OverrideManager<'a> {
+    /// This method creates a new `OverrideManager` for the buildroot overrides of the given users.
+    /// By default, bulk operations continue after individual buildroot overrides failed to be
+    /// edited.
+    pub fn new(users: Vec<&'a str>) -> Self {
+        OverrideManager {
+            users,
+            options: BatchOptions::new().continue_on_error(true),
+        }
+    }
+
+    /// This method sets the options (concurrency, rate limit, error handling) for bulk operations.
+    pub fn batch_options(mut self, options: BatchOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// This method returns all active (not expired) buildroot overrides of the managed users.
+    pub fn active(&self, bodhi: &BodhiService) -> Result<Vec<Override>, QueryError> {
+        bodhi.query(OverrideQuery::new().users(self.users.clone()).expired(false))
+    }
+
+    /// This method returns the active buildroot overrides of the managed users that will expire
+    /// within the given duration.
+    pub fn expiring(&self, bodhi: &BodhiService, within: Duration) -> Result<Vec<Override>, QueryError> {
+        let deadline = chrono::Duration::from_std(within)
+            .ok()
+            .and_then(|within| Utc::now().checked_add_signed(within));
+
+        let overrides = self.active(bodhi)?;
+
+        Ok(match deadline {
+            Some(deadline) => expiring_before(overrides, &BodhiDate::from(deadline)),
+            None => overrides,
+        })
+    }
+
+    /// This method extends the expiration date of the given buildroot overrides to the given date.
+    /// Buildroot overrides that already expire on or after this date are not changed.
+    pub fn extend(
+        &self,
+        bodhi: &BodhiService,
+        overrides: &[Override],
+        expiration_date: &BodhiDate,
+    ) -> Result<OverrideReport, QueryError> {
+        let (extended, unchanged): (Vec<&Override>, Vec<&Override>) = overrides
+            .iter()
+            .partition(|over_ride| over_ride.expiration_date < *expiration_date);
+
+        let editors: Vec<OverrideEditor> = extended
+            .iter()
+            .map(|over_ride| over_ride.edit().expiration_date(expiration_date))
+            .collect();
+
+        let batch = bodhi.edit_many(&editors, &self.options)?;
+
+        Ok(OverrideReport::new(&extended, &unchanged, batch))
+    }
+
+    /// This method expires those of the given buildroot overrides whose builds are already part of
+    /// stable updates. The other buildroot overrides are not changed.
+    pub fn expire_stable(&self, bodhi: &BodhiService, overrides: &[Override]) -> Result<OverrideReport, QueryError> {
+        // querying updates without any builds would return all updates
+        if overrides.is_empty() {
+            return Ok(OverrideReport::default());
+        }
+
+        let nvrs = overrides
+            .iter()
+            .map(|over_ride| over_ride.nvr.parse())
+            .collect::<Result<Vec<Nvr>, _>>()?;
+        let updates = bodhi.query(UpdateQuery::new().builds(nvrs))?;
+        let stable = stable_nvrs(&updates);
+
+        let (expired, unchanged): (Vec<&Override>, Vec<&Override>) = overrides
+            .iter()
+            .partition(|over_ride| stable.contains(over_ride.nvr.as_str()));
+
+        let editors: Vec<OverrideEditor> = expired.iter().map(|over_ride| over_ride.edit().expired(true)).collect();
+
+        let batch = bodhi.edit_many(&editors, &self.options)?;
+
+        Ok(OverrideReport::new(&expired, &unchanged, batch))
+    }
+}
+
+/// This struct contains the results of a bulk operation of an
+/// [`OverrideManager`](struct.OverrideManager.html).
+#[derive(Debug, Default)]
+pub struct OverrideReport {
+    /// buildroot overrides that were changed, with their new values
+    pub changed: Vec<Override>,
+    /// NVRs of buildroot overrides that did not need to be changed
+    pub unchanged: Vec<String>,
+    /// NVRs of buildroot overrides that could not be changed, with the reason
+    pub failed: Vec<(String, QueryError)>,
+    /// NVRs of buildroot overrides that were not processed, because the operation was stopped
+    /// after a failure
+    pub skipped: Vec<String>,
+}
+
+impl OverrideReport {
+    pub(crate) fn new(edited: &[&Override], unchanged: &[&Override], batch: BatchReport<EditedOverride>) -> Self {
+        let mut report = OverrideReport {
+            unchanged: unchanged.iter().map(|over_ride| over_ride.nvr.clone()).collect(),
+            ..Default::default()
+        };
+
+        for (over_ride, result) in edited.iter().zip(batch.results) {
+            match result {
+                BatchResult::Succeeded(edited) => report.changed.push(edited.over_ride),
+                BatchResult::Failed(error) => report.failed.push((over_ride.nvr.clone(), error)),
+                BatchResult::Skipped => report.skipped.push(over_ride.nvr.clone()),
+            }
+        }
+
+        report
+    }
+
+    /// This method returns `true` if all buildroot overrides that needed to be changed were
+    /// changed successfully.
+    pub fn is_success(&self) -> bool {
+        self.failed.is_empty() && self.skipped.is_empty()
+    }
+}
+
+/// This function returns the buildroot overrides that expire before the given date.
+pub(crate) fn expiring_before(overrides: Vec<Override>, deadline: &BodhiDate) -> Vec<Override> {
+    overrides
+        .into_iter()
+        .filter(|over_ride| over_ride.expiration_date < *deadline)
+        .collect()
+}
+
+/// This function returns the NVRs of all builds that are part of stable updates.
+pub(crate) fn stable_nvrs(updates: &[Update]) -> BTreeSet<&str> {
+    updates
+        .iter()
+        .filter(|update| update.status == UpdateStatus::Stable)
+        .flat_map(|update| update.builds.iter().map(|build| build.nvr.as_str()))
+        .collect()
+}
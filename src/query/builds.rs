@@ -11,10 +11,13 @@
 use std::fmt::{Debug, Formatter};
 
 use serde::{Deserialize, Serialize};
+use url::Url;
 
-use crate::error::{QueryError, ServiceError};
+use crate::error::{QueryError, ServiceError, SkippedItem, UrlError};
+use crate::query::json::from_json;
+use crate::query::urls::{collection_url, extend_list, parse_value, query_params, unknown_parameter};
 use crate::service::DEFAULT_ROWS;
-use crate::{BodhiService, Build, FedoraRelease, Query, SinglePageQuery};
+use crate::{BodhiService, Build, Callback, FedoraRelease, Nvr, Query, SinglePageQuery, UpdateAlias};
 
 /// Use this for querying bodhi for a specific build, by its NVR (Name-Version-Release) string. It
 /// will either return an `Ok(Some(Build))` matching the specified NVR, return `Ok(None)` if it
@@ -25,31 +28,36 @@ use crate::{BodhiService, Build, FedoraRelease, Query, SinglePageQuery};
 /// let bodhi = BodhiServiceBuilder::default().build().unwrap();
 ///
 /// # #[cfg(feature = "online-tests")]
-/// let build = bodhi.query(BuildNVRQuery::new("rust-1.34.1-1.fc29")).unwrap();
+/// let build = bodhi.query(BuildNVRQuery::new(&"rust-1.34.1-1.fc29".parse().unwrap())).unwrap();
 /// ```
 ///
 /// API documentation: <https://bodhi.fedoraproject.org/docs/server_api/rest/builds.html#service-0>
-#[derive(Debug)]
-pub struct BuildNVRQuery<'a> {
+#[derive(Clone, Debug)]
+pub struct BuildNVRQuery {
     /// NVR of the build to query (Name-Version-Release format, without Epoch)
-    nvr: &'a str,
+    nvr: String,
 }
 
-impl<'a> BuildNVRQuery<'a> {
+impl BuildNVRQuery {
     /// This method is the only way to create a new [`BuildNVRQuery`](struct.BuildNVRQuery.html)
     /// instance.
-    pub fn new(nvr: &'a str) -> Self {
-        BuildNVRQuery { nvr }
+    pub fn new(nvr: &Nvr) -> Self {
+        BuildNVRQuery { nvr: nvr.to_string() }
+    }
+
+    /// This method constructs the URL of the requested build on the given bodhi instance.
+    pub fn web_url(&self, bodhi: &BodhiService) -> Url {
+        bodhi.web_url(&format!("/builds/{}", self.nvr))
     }
 }
 
-impl<'a> SinglePageQuery<Option<Build>> for BuildNVRQuery<'a> {
+impl SinglePageQuery<Option<Build>> for BuildNVRQuery {
     fn path(&self) -> Result<String, QueryError> {
         Ok(format!("/builds/{}", self.nvr))
     }
 
     fn parse(string: &str) -> Result<Option<Build>, QueryError> {
-        let build: Build = serde_json::from_str(string)?;
+        let build: Build = from_json(string)?;
         Ok(Some(build))
     }
 
@@ -58,9 +66,9 @@ impl<'a> SinglePageQuery<Option<Build>> for BuildNVRQuery<'a> {
     }
 }
 
-impl<'a> Query<Option<Build>> for BuildNVRQuery<'a> {
-    fn query(self, bodhi: &BodhiService) -> Result<Option<Build>, QueryError> {
-        <Self as SinglePageQuery<Option<Build>>>::query(self, bodhi)
+impl Query<Option<Build>> for BuildNVRQuery {
+    fn query(self, bodhi: &BodhiService, skipped: Option<&mut Vec<SkippedItem>>) -> Result<Option<Build>, QueryError> {
+        <Self as SinglePageQuery<Option<Build>>>::query(self, bodhi, skipped)
     }
 }
 
@@ -69,6 +77,9 @@ impl<'a> Query<Option<Build>> for BuildNVRQuery<'a> {
 /// builds will be returned if any criteria match. This is consistent with both the web interface
 /// and REST API behavior.
 ///
+/// The query parameters (but not the progress callback) can be serialized and deserialized, using
+/// the same parameter names as the REST API.
+///
 /// ```
 /// # use bodhi::{BuildQuery, FedoraRelease, BodhiServiceBuilder};
 /// let bodhi = BodhiServiceBuilder::default().build().unwrap();
@@ -83,20 +94,25 @@ impl<'a> Query<Option<Build>> for BuildNVRQuery<'a> {
 ///     .unwrap();
 /// ```
 ///
+/// Cloning a query does not clone its [`callback`](#method.callback): the clone sends the same
+/// requests, but it does not report any progress unless a new callback is set.
+///
 /// API documentation: <https://bodhi.fedoraproject.org/docs/server_api/rest/builds.html#service-1>
-#[derive(Default)]
+#[derive(Clone, Default, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
 pub struct BuildQuery<'a> {
     /// NVR of the build to query (Name-Version-Release format, without Epoch)
-    nvr: Option<&'a str>,
+    nvr: Option<Nvr>,
     /// list of packages to request builds for
-    packages: Option<Vec<&'a str>>,
+    packages: Option<Vec<String>>,
     /// list of releases to request builds for
     releases: Option<Vec<FedoraRelease>>,
     /// list of updates to request builds for
-    updates: Option<Vec<&'a str>>,
+    updates: Option<Vec<UpdateAlias>>,
 
     /// optional callback function for reporting progress
-    callback: Option<Box<dyn FnMut(u32, u32) + 'a>>,
+    #[serde(skip)]
+    callback: Callback<'a>,
 }
 
 impl<'a> Debug for BuildQuery<'a> {
@@ -117,28 +133,32 @@ impl<'a> BuildQuery<'a> {
             packages: None,
             releases: None,
             updates: None,
-            callback: None,
+            callback: Callback::default(),
         }
     }
 
     /// Add a callback function for reporting back query progress for long-running queries.
     /// The function will be called with the current page and the total number of pages for
-    /// paginated queries.
-    pub fn callback(mut self, fun: impl FnMut(u32, u32) + 'a) -> Self {
-        self.callback = Some(Box::new(fun));
+    /// paginated queries. The callback is the only part of a query that can borrow data, and it
+    /// must be `Send`, so the query can be sent to other threads.
+    ///
+    /// **Note**: The callback is *not* cloned together with the query, so clones of this query do
+    /// not report any progress.
+    pub fn callback(mut self, fun: impl FnMut(u32, u32) + Send + 'a) -> Self {
+        self.callback = Callback::new(fun);
         self
     }
 
     /// Restrict the returned results to builds with the given NVR. If this is the only required
     /// filter, consider using a [`BuildNVRQuery`](struct.BuildNVRQuery.html) instead.
-    pub fn nvr(mut self, nvr: &'a str) -> Self {
+    pub fn nvr(mut self, nvr: Nvr) -> Self {
         self.nvr = Some(nvr);
         self
     }
 
     /// Restrict the returned results to builds of the given package(s).
-    pub fn packages(mut self, packages: Vec<&'a str>) -> Self {
-        self.packages = Some(packages);
+    pub fn packages(mut self, packages: Vec<impl Into<String>>) -> Self {
+        self.packages = Some(packages.into_iter().map(Into::into).collect());
         self
     }
 
@@ -149,28 +169,58 @@ impl<'a> BuildQuery<'a> {
     }
 
     /// Restrict the returned results to builds for the given update(s).
-    pub fn updates(mut self, updates: Vec<&'a str>) -> Self {
+    pub fn updates(mut self, updates: Vec<UpdateAlias>) -> Self {
         self.updates = Some(updates);
         self
     }
 
+    /// This method parses a URL of the bodhi web interface or REST API for listing builds (for
+    /// example, `https://bodhi.fedoraproject.org/builds/?packages=rust&releases=F31`) into the
+    /// equivalent query. Unknown query parameters and invalid values are rejected.
+    pub fn from_url(url: &str) -> Result<Self, UrlError> {
+        let url = collection_url(url, "builds")?;
+        Self::from_params(&url)
+    }
+
+    pub(crate) fn from_params(url: &Url) -> Result<Self, UrlError> {
+        let mut query = Self::new();
+
+        for (key, value) in query_params(url) {
+            match key.as_str() {
+                "nvr" => query.nvr = Some(parse_value(&key, &value)?),
+                "packages" => extend_list(&mut query.packages, &key, &value)?,
+                "releases" => extend_list(&mut query.releases, &key, &value)?,
+                "updates" => extend_list(&mut query.updates, &key, &value)?,
+                _ => return Err(unknown_parameter("BuildQuery", key)),
+            }
+        }
+
+        Ok(query)
+    }
+
+    /// This method constructs the URL for viewing the results of this query on the given bodhi
+    /// instance (for example, in the web interface).
+    pub fn web_url(&self, bodhi: &BodhiService) -> Result<Url, QueryError> {
+        Ok(bodhi.web_url(&format!("/builds/?{}", serde_url_params::to_string(self)?)))
+    }
+
     /// Query the remote bodhi instance with the given parameters.
-    fn query(mut self, bodhi: &BodhiService) -> Result<Vec<Build>, QueryError> {
+    fn query(
+        mut self,
+        bodhi: &BodhiService,
+        mut skipped: Option<&mut Vec<SkippedItem>>,
+    ) -> Result<Vec<Build>, QueryError> {
         let mut builds: Vec<Build> = Vec::new();
         let mut page = 1;
 
         // initial progress: 0 out of some
-        if let Some(ref mut fun) = self.callback {
-            fun(0, 1);
-        }
+        self.callback.call(0, 1);
 
         loop {
             let query = self.page_query(page, DEFAULT_ROWS);
-            let result = query.query(bodhi)?;
+            let result = query.query(bodhi, skipped.as_deref_mut())?;
 
-            if let Some(ref mut fun) = self.callback {
-                fun(page, result.pages);
-            }
+            self.callback.call(page, result.pages);
 
             builds.extend(result.builds);
             page += 1;
@@ -185,7 +235,7 @@ impl<'a> BuildQuery<'a> {
 
     fn page_query(&self, page: u32, rows_per_page: u32) -> BuildPageQuery {
         BuildPageQuery {
-            nvr: self.nvr,
+            nvr: self.nvr.as_ref(),
             packages: self.packages.as_ref(),
             releases: self.releases.as_ref(),
             updates: self.updates.as_ref(),
@@ -196,8 +246,8 @@ impl<'a> BuildQuery<'a> {
 }
 
 impl<'a> Query<Vec<Build>> for BuildQuery<'a> {
-    fn query(self, bodhi: &BodhiService) -> Result<Vec<Build>, QueryError> {
-        BuildQuery::query(self, bodhi)
+    fn query(self, bodhi: &BodhiService, skipped: Option<&mut Vec<SkippedItem>>) -> Result<Vec<Build>, QueryError> {
+        BuildQuery::query(self, bodhi, skipped)
     }
 }
 
@@ -212,10 +262,10 @@ struct BuildListPage {
 
 #[derive(Debug, Serialize)]
 struct BuildPageQuery<'a> {
-    nvr: Option<&'a str>,
-    packages: Option<&'a Vec<&'a str>>,
+    nvr: Option<&'a Nvr>,
+    packages: Option<&'a Vec<String>>,
     releases: Option<&'a Vec<FedoraRelease>>,
-    updates: Option<&'a Vec<&'a str>>,
+    updates: Option<&'a Vec<UpdateAlias>>,
     page: u32,
     rows_per_page: u32,
 }
@@ -226,7 +276,7 @@ impl<'a> SinglePageQuery<BuildListPage> for BuildPageQuery<'a> {
     }
 
     fn parse(string: &str) -> Result<BuildListPage, QueryError> {
-        let build_page: BuildListPage = serde_json::from_str(string)?;
+        let build_page: BuildListPage = from_json(string)?;
         Ok(build_page)
     }
 
@@ -235,4 +285,8 @@ impl<'a> SinglePageQuery<BuildListPage> for BuildPageQuery<'a> {
             error: ServiceError::EmptyResponseError,
         })
     }
+
+    fn page(&self) -> Option<u32> {
+        Some(self.page)
+    }
 }
@@ -11,10 +11,13 @@
 use std::fmt::{Debug, Formatter};
 
 use serde::{Deserialize, Serialize};
+use url::Url;
 
-use crate::error::{QueryError, ServiceError};
+use crate::error::{QueryError, ServiceError, SkippedItem, UrlError};
+use crate::query::json::from_json;
+use crate::query::urls::{collection_url, extend_list, parse_value, query_params, unknown_parameter};
 use crate::service::DEFAULT_ROWS;
-use crate::{BodhiDate, BodhiService, Comment, Query, SinglePageQuery};
+use crate::{BodhiDate, BodhiService, Callback, Comment, Query, SinglePageQuery, UpdateAlias};
 
 /// Use this for querying bodhi for a specific comment by its ID. It will either return an
 /// `Ok(Some(Comment))` matching the specified ID, return `Ok(None)` if it doesn't exist, or return
@@ -29,7 +32,7 @@ use crate::{BodhiDate, BodhiService, Comment, Query, SinglePageQuery};
 /// ```
 ///
 /// API documentation: <https://bodhi.fedoraproject.org/docs/server_api/rest/comments.html#service-0>
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct CommentIDQuery {
     id: u32,
 }
@@ -45,6 +48,11 @@ impl CommentIDQuery {
     pub fn new(id: u32) -> Self {
         CommentIDQuery { id }
     }
+
+    /// This method constructs the URL of the requested comment on the given bodhi instance.
+    pub fn web_url(&self, bodhi: &BodhiService) -> Url {
+        bodhi.web_url(&format!("/comments/{}", self.id))
+    }
 }
 
 impl SinglePageQuery<Option<Comment>> for CommentIDQuery {
@@ -53,7 +61,7 @@ impl SinglePageQuery<Option<Comment>> for CommentIDQuery {
     }
 
     fn parse(string: &str) -> Result<Option<Comment>, QueryError> {
-        let comment: CommentPage = serde_json::from_str(string)?;
+        let comment: CommentPage = from_json(string)?;
         Ok(Some(comment.comment))
     }
 
@@ -63,8 +71,12 @@ impl SinglePageQuery<Option<Comment>> for CommentIDQuery {
 }
 
 impl Query<Option<Comment>> for CommentIDQuery {
-    fn query(self, bodhi: &BodhiService) -> Result<Option<Comment>, QueryError> {
-        <Self as SinglePageQuery<Option<Comment>>>::query(self, bodhi)
+    fn query(
+        self,
+        bodhi: &BodhiService,
+        skipped: Option<&mut Vec<SkippedItem>>,
+    ) -> Result<Option<Comment>, QueryError> {
+        <Self as SinglePageQuery<Option<Comment>>>::query(self, bodhi, skipped)
     }
 }
 
@@ -73,6 +85,9 @@ impl Query<Option<Comment>> for CommentIDQuery {
 /// comments will be returned if any criteria match. This is consistent with both the web interface
 /// and REST API behavior.
 ///
+/// The query parameters (but not the progress callback) can be serialized and deserialized, using
+/// the same parameter names as the REST API.
+///
 /// ```
 /// # use bodhi::{BodhiServiceBuilder, CommentQuery};
 /// let bodhi = BodhiServiceBuilder::default().build().unwrap();
@@ -83,21 +98,28 @@ impl Query<Option<Comment>> for CommentIDQuery {
 ///     .unwrap();
 /// ```
 ///
+/// Cloning a query does not clone its [`callback`](#method.callback): the clone sends the same
+/// requests, but it does not report any progress unless a new callback is set.
+///
 /// API documentation: <https://bodhi.fedoraproject.org/docs/server_api/rest/comments.html#service-1>
-#[derive(Default)]
+#[derive(Clone, Default, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
 pub struct CommentQuery<'a> {
     anonymous: Option<bool>,
-    ignore_users: Option<Vec<&'a str>>,
-    like: Option<&'a str>,
-    packages: Option<Vec<&'a str>>,
-    search: Option<&'a str>,
-    since: Option<&'a BodhiDate>,
-    update_owners: Option<Vec<&'a str>>,
-    updates: Option<Vec<&'a str>>,
-    users: Option<Vec<&'a str>>,
+    ignore_users: Option<Vec<String>>,
+    like: Option<String>,
+    packages: Option<Vec<String>>,
+    search: Option<String>,
+    #[serde(with = "crate::option_bodhi_date_format")]
+    since: Option<BodhiDate>,
+    update_owners: Option<Vec<String>>,
+    updates: Option<Vec<UpdateAlias>>,
+    #[serde(rename = "user")]
+    users: Option<Vec<String>>,
 
     /// optional callback function for reporting progress
-    callback: Option<Box<dyn FnMut(u32, u32) + 'a>>,
+    #[serde(skip)]
+    callback: Callback<'a>,
 }
 
 impl<'a> Debug for CommentQuery<'a> {
@@ -131,15 +153,19 @@ impl<'a> CommentQuery<'a> {
             update_owners: None,
             updates: None,
             users: None,
-            callback: None,
+            callback: Callback::default(),
         }
     }
 
     /// Add a callback function for reporting back query progress for long-running queries.
     /// The function will be called with the current page and the total number of pages for
-    /// paginated queries.
-    pub fn callback(mut self, fun: impl FnMut(u32, u32) + 'a) -> Self {
-        self.callback = Some(Box::new(fun));
+    /// paginated queries. The callback is the only part of a query that can borrow data, and it
+    /// must be `Send`, so the query can be sent to other threads.
+    ///
+    /// **Note**: The callback is *not* cloned together with the query, so clones of this query do
+    /// not report any progress.
+    pub fn callback(mut self, fun: impl FnMut(u32, u32) + Send + 'a) -> Self {
+        self.callback = Callback::new(fun);
         self
     }
 
@@ -152,71 +178,106 @@ impl<'a> CommentQuery<'a> {
     }
 
     /// Restrict results to ignore comments by certain users.
-    pub fn ignore_users(mut self, ignore_users: Vec<&'a str>) -> Self {
-        self.ignore_users = Some(ignore_users);
+    pub fn ignore_users(mut self, ignore_users: Vec<impl Into<String>>) -> Self {
+        self.ignore_users = Some(ignore_users.into_iter().map(Into::into).collect());
         self
     }
 
     /// Restrict search to comments *like* the given argument (in the SQL sense).
-    pub fn like(mut self, like: &'a str) -> CommentQuery {
-        self.like = Some(like);
+    pub fn like(mut self, like: impl Into<String>) -> Self {
+        self.like = Some(like.into());
         self
     }
 
     /// Restrict the returned results to comments filed against updates for the given package(s).
-    pub fn packages(mut self, packages: Vec<&'a str>) -> Self {
-        self.packages = Some(packages);
+    pub fn packages(mut self, packages: Vec<impl Into<String>>) -> Self {
+        self.packages = Some(packages.into_iter().map(Into::into).collect());
         self
     }
 
     /// Restrict search to comments containing the given argument.
-    pub fn search(mut self, search: &'a str) -> Self {
-        self.search = Some(search);
+    pub fn search(mut self, search: impl Into<String>) -> Self {
+        self.search = Some(search.into());
         self
     }
 
     /// Restrict the returned results to comments filed since the given date and time.
-    pub fn since(mut self, since: &'a BodhiDate) -> Self {
-        self.since = Some(since);
+    pub fn since(mut self, since: &BodhiDate) -> Self {
+        self.since = Some(since.clone());
         self
     }
 
     /// Restrict the returned results to comments filed against updates created by the specified
     /// user(s).
-    pub fn update_owners(mut self, update_owners: Vec<&'a str>) -> Self {
-        self.update_owners = Some(update_owners);
+    pub fn update_owners(mut self, update_owners: Vec<impl Into<String>>) -> Self {
+        self.update_owners = Some(update_owners.into_iter().map(Into::into).collect());
         self
     }
 
     /// Restrict the returned results to comments filed against the given update(s).
-    pub fn updates(mut self, updates: Vec<&'a str>) -> Self {
+    pub fn updates(mut self, updates: Vec<UpdateAlias>) -> Self {
         self.updates = Some(updates);
         self
     }
 
     /// Restrict the returned results to comments filed by the given user(s).
-    pub fn users(mut self, users: Vec<&'a str>) -> Self {
-        self.users = Some(users);
+    pub fn users(mut self, users: Vec<impl Into<String>>) -> Self {
+        self.users = Some(users.into_iter().map(Into::into).collect());
         self
     }
 
+    /// This method parses a URL of the bodhi web interface or REST API for listing comments (for
+    /// example, `https://bodhi.fedoraproject.org/comments/?user=decathorpe`) into the equivalent
+    /// query. Unknown query parameters and invalid values are rejected.
+    pub fn from_url(url: &str) -> Result<Self, UrlError> {
+        let url = collection_url(url, "comments")?;
+        Self::from_params(&url)
+    }
+
+    pub(crate) fn from_params(url: &Url) -> Result<Self, UrlError> {
+        let mut query = Self::new();
+
+        for (key, value) in query_params(url) {
+            match key.as_str() {
+                "anonymous" => query.anonymous = Some(parse_value(&key, &value)?),
+                "ignore_users" => extend_list(&mut query.ignore_users, &key, &value)?,
+                "like" => query.like = Some(value),
+                "packages" => extend_list(&mut query.packages, &key, &value)?,
+                "search" => query.search = Some(value),
+                "since" => query.since = Some(parse_value(&key, &value)?),
+                "update_owners" => extend_list(&mut query.update_owners, &key, &value)?,
+                "updates" => extend_list(&mut query.updates, &key, &value)?,
+                "user" => extend_list(&mut query.users, &key, &value)?,
+                _ => return Err(unknown_parameter("CommentQuery", key)),
+            }
+        }
+
+        Ok(query)
+    }
+
+    /// This method constructs the URL for viewing the results of this query on the given bodhi
+    /// instance (for example, in the web interface).
+    pub fn web_url(&self, bodhi: &BodhiService) -> Result<Url, QueryError> {
+        Ok(bodhi.web_url(&format!("/comments/?{}", serde_url_params::to_string(self)?)))
+    }
+
     /// Query the remote bodhi instance with the given parameters.
-    fn query(mut self, bodhi: &BodhiService) -> Result<Vec<Comment>, QueryError> {
+    fn query(
+        mut self,
+        bodhi: &BodhiService,
+        mut skipped: Option<&mut Vec<SkippedItem>>,
+    ) -> Result<Vec<Comment>, QueryError> {
         let mut comments: Vec<Comment> = Vec::new();
         let mut page = 1;
 
         // initial progress: 0 out of some
-        if let Some(ref mut fun) = self.callback {
-            fun(0, 1);
-        }
+        self.callback.call(0, 1);
 
         loop {
             let query = self.page_query(page, DEFAULT_ROWS);
-            let result = query.query(bodhi)?;
+            let result = query.query(bodhi, skipped.as_deref_mut())?;
 
-            if let Some(ref mut fun) = self.callback {
-                fun(page, result.pages)
-            }
+            self.callback.call(page, result.pages);
 
             comments.extend(result.comments);
             page += 1;
@@ -233,10 +294,10 @@ impl<'a> CommentQuery<'a> {
         CommentPageQuery {
             anonymous: self.anonymous,
             ignore_users: self.ignore_users.as_ref(),
-            like: self.like,
+            like: self.like.as_deref(),
             packages: self.packages.as_ref(),
-            search: self.search,
-            since: self.since,
+            search: self.search.as_deref(),
+            since: self.since.as_ref(),
             update_owners: self.update_owners.as_ref(),
             updates: self.updates.as_ref(),
             users: self.users.as_ref(),
@@ -247,8 +308,8 @@ impl<'a> CommentQuery<'a> {
 }
 
 impl<'a> Query<Vec<Comment>> for CommentQuery<'a> {
-    fn query(self, bodhi: &BodhiService) -> Result<Vec<Comment>, QueryError> {
-        CommentQuery::query(self, bodhi)
+    fn query(self, bodhi: &BodhiService, skipped: Option<&mut Vec<SkippedItem>>) -> Result<Vec<Comment>, QueryError> {
+        CommentQuery::query(self, bodhi, skipped)
     }
 }
 
@@ -264,16 +325,16 @@ struct CommentListPage {
 #[derive(Debug, Serialize)]
 struct CommentPageQuery<'a> {
     anonymous: Option<bool>,
-    ignore_users: Option<&'a Vec<&'a str>>,
+    ignore_users: Option<&'a Vec<String>>,
     like: Option<&'a str>,
-    packages: Option<&'a Vec<&'a str>>,
+    packages: Option<&'a Vec<String>>,
     search: Option<&'a str>,
     #[serde(with = "crate::option_bodhi_date_format_ref")]
     since: Option<&'a BodhiDate>,
-    update_owners: Option<&'a Vec<&'a str>>,
-    updates: Option<&'a Vec<&'a str>>,
+    update_owners: Option<&'a Vec<String>>,
+    updates: Option<&'a Vec<UpdateAlias>>,
     #[serde(rename = "user")]
-    users: Option<&'a Vec<&'a str>>,
+    users: Option<&'a Vec<String>>,
 
     page: u32,
     rows_per_page: u32,
@@ -285,7 +346,7 @@ impl<'a> SinglePageQuery<CommentListPage> for CommentPageQuery<'a> {
     }
 
     fn parse(string: &str) -> Result<CommentListPage, QueryError> {
-        let comment_page: CommentListPage = serde_json::from_str(string)?;
+        let comment_page: CommentListPage = from_json(string)?;
         Ok(comment_page)
     }
 
@@ -294,4 +355,8 @@ impl<'a> SinglePageQuery<CommentListPage> for CommentPageQuery<'a> {
             error: ServiceError::EmptyResponseError,
         })
     }
+
+    fn page(&self) -> Option<u32> {
+        Some(self.page)
+    }
 }
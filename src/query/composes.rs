@@ -1,9 +1,23 @@
 //! The contents of this module can be used to query a bodhi instance for running composes.
 
 use serde::Deserialize;
-
-use crate::error::{QueryError, ServiceError};
-use crate::{BodhiService, Compose, ComposeRequest, FedoraRelease, Query, SinglePageQuery};
+use url::Url;
+
+use crate::error::{QueryError, ServiceError, SkippedItem};
+use crate::query::json::from_json;
+use crate::{
+    BodhiService,
+    Compose,
+    ComposeRequest,
+    ComposeStatus,
+    ContentType,
+    FedoraRelease,
+    Query,
+    SinglePageQuery,
+    Update,
+    UpdateAlias,
+    UpdateQuery,
+};
 
 /// Use this for querying bodhi for a specific compose by its release and request. It will either
 /// return an `Ok(Some(Compose))` matching the specified values, return `Ok(None)` if it doesn't
@@ -21,7 +35,7 @@ use crate::{BodhiService, Compose, ComposeRequest, FedoraRelease, Query, SingleP
 ///     ))
 ///     .unwrap();
 /// ```
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct ComposeReleaseRequestQuery {
     release: FedoraRelease,
     request: ComposeRequest,
@@ -38,6 +52,11 @@ impl ComposeReleaseRequestQuery {
     pub fn new(release: FedoraRelease, request: ComposeRequest) -> Self {
         ComposeReleaseRequestQuery { release, request }
     }
+
+    /// This method constructs the URL of the requested compose on the given bodhi instance.
+    pub fn web_url(&self, bodhi: &BodhiService) -> Url {
+        bodhi.web_url(&format!("/composes/{}/{}", self.release, self.request))
+    }
 }
 
 impl SinglePageQuery<Option<Compose>> for ComposeReleaseRequestQuery {
@@ -46,7 +65,7 @@ impl SinglePageQuery<Option<Compose>> for ComposeReleaseRequestQuery {
     }
 
     fn parse(string: &str) -> Result<Option<Compose>, QueryError> {
-        let page: ComposePage = serde_json::from_str(string)?;
+        let page: ComposePage = from_json(string)?;
         Ok(Some(page.compose))
     }
 
@@ -56,24 +75,36 @@ impl SinglePageQuery<Option<Compose>> for ComposeReleaseRequestQuery {
 }
 
 impl Query<Option<Compose>> for ComposeReleaseRequestQuery {
-    fn query(self, bodhi: &BodhiService) -> Result<Option<Compose>, QueryError> {
-        <Self as SinglePageQuery<Option<Compose>>>::query(self, bodhi)
+    fn query(
+        self,
+        bodhi: &BodhiService,
+        skipped: Option<&mut Vec<SkippedItem>>,
+    ) -> Result<Option<Compose>, QueryError> {
+        <Self as SinglePageQuery<Option<Compose>>>::query(self, bodhi, skipped)
     }
 }
 
 /// This query can be used to fetch information about currently running composes from bodhi.
 ///
+/// The bodhi REST API does not support filtering composes server-side, so all running composes are
+/// fetched, and the filters that were specified with the builder pattern are applied afterwards.
+///
 /// ```
-/// # use bodhi::{BodhiServiceBuilder, ComposeQuery};
+/// # use bodhi::{BodhiServiceBuilder, ComposeQuery, ComposeStatus};
 /// let bodhi = BodhiServiceBuilder::default().build().unwrap();
 ///
 /// # #[cfg(feature = "online-tests")]
-/// let composes = bodhi.query(ComposeQuery::new()).unwrap();
+/// let composes = bodhi.query(ComposeQuery::new().state(ComposeStatus::Failed)).unwrap();
 /// ```
 ///
 /// API documentation: <https://bodhi.fedoraproject.org/docs/server_api/rest/composes.html>
-#[derive(Debug, Default)]
-pub struct ComposeQuery {}
+#[derive(Clone, Debug, Default)]
+pub struct ComposeQuery {
+    content_type: Option<ContentType>,
+    releases: Option<Vec<FedoraRelease>>,
+    request: Option<ComposeRequest>,
+    state: Option<ComposeStatus>,
+}
 
 #[derive(Debug, Deserialize)]
 struct ComposeListPage {
@@ -81,9 +112,73 @@ struct ComposeListPage {
 }
 
 impl ComposeQuery {
-    /// This method creates a new [`ComposeQuery`](struct.ComposeQuery.html).
+    /// This method creates a new [`ComposeQuery`](struct.ComposeQuery.html) with *no* filters set.
     pub fn new() -> Self {
-        ComposeQuery {}
+        ComposeQuery {
+            content_type: None,
+            releases: None,
+            request: None,
+            state: None,
+        }
+    }
+
+    /// Restrict the returned results to composes with the given content type.
+    pub fn content_type(mut self, content_type: ContentType) -> Self {
+        self.content_type = Some(content_type);
+        self
+    }
+
+    /// Restrict the returned results to composes for the given release(s).
+    pub fn releases(mut self, releases: Vec<FedoraRelease>) -> Self {
+        self.releases = Some(releases);
+        self
+    }
+
+    /// Restrict the returned results to composes with the given request.
+    pub fn request(mut self, request: ComposeRequest) -> Self {
+        self.request = Some(request);
+        self
+    }
+
+    /// Restrict the returned results to composes in the given state.
+    pub fn state(mut self, state: ComposeStatus) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    /// This method constructs the URL of the list of running composes on the given bodhi instance.
+    /// Since composes are filtered on the client side, the filters of this query are not included.
+    pub fn web_url(&self, bodhi: &BodhiService) -> Url {
+        bodhi.web_url("/composes/")
+    }
+
+    pub(crate) fn matches(&self, compose: &Compose) -> bool {
+        if let Some(content_type) = &self.content_type {
+            if compose.content_type.as_ref() != Some(content_type) {
+                return false;
+            }
+        }
+
+        if let Some(releases) = &self.releases {
+            match &compose.release {
+                Some(release) if releases.contains(&release.name) => {},
+                _ => return false,
+            }
+        }
+
+        if let Some(request) = &self.request {
+            if &compose.request != request {
+                return false;
+            }
+        }
+
+        if let Some(state) = &self.state {
+            if &compose.state != state {
+                return false;
+            }
+        }
+
+        true
     }
 }
 
@@ -93,7 +188,7 @@ impl SinglePageQuery<Vec<Compose>> for ComposeQuery {
     }
 
     fn parse(string: &str) -> Result<Vec<Compose>, QueryError> {
-        let page: ComposeListPage = serde_json::from_str(string)?;
+        let page: ComposeListPage = from_json(string)?;
         Ok(page.composes)
     }
 
@@ -105,7 +200,37 @@ impl SinglePageQuery<Vec<Compose>> for ComposeQuery {
 }
 
 impl Query<Vec<Compose>> for ComposeQuery {
-    fn query(self, bodhi: &BodhiService) -> Result<Vec<Compose>, QueryError> {
-        <Self as SinglePageQuery<Vec<Compose>>>::query(self, bodhi)
+    fn query(self, bodhi: &BodhiService, skipped: Option<&mut Vec<SkippedItem>>) -> Result<Vec<Compose>, QueryError> {
+        let composes = <Self as SinglePageQuery<Vec<Compose>>>::query(self.clone(), bodhi, skipped)?;
+        Ok(composes.into_iter().filter(|compose| self.matches(compose)).collect())
+    }
+}
+
+impl Compose {
+    /// This method fetches the complete data of all updates that are part of this `Compose`
+    /// (instead of only the [`UpdateSummary`](../../data/types/struct.UpdateSummary.html) values
+    /// that are included in the compose itself).
+    ///
+    /// ```
+    /// # use bodhi::{BodhiServiceBuilder, ComposeQuery, ComposeStatus};
+    /// let bodhi = BodhiServiceBuilder::default().build().unwrap();
+    ///
+    /// # #[cfg(feature = "online-tests")]
+    /// for compose in bodhi.query(ComposeQuery::new().state(ComposeStatus::Failed)).unwrap() {
+    ///     let updates = compose.updates(&bodhi).unwrap();
+    /// }
+    /// ```
+    pub fn updates(&self, bodhi: &BodhiService) -> Result<Vec<Update>, QueryError> {
+        // an empty alias filter would match all updates
+        if self.update_summary.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let aliases = self
+            .update_summary
+            .iter()
+            .map(|s| s.alias.parse())
+            .collect::<Result<Vec<UpdateAlias>, _>>()?;
+        bodhi.query(UpdateQuery::new().aliases(aliases))
     }
 }
@@ -2,7 +2,8 @@
 
 use serde::Deserialize;
 
-use crate::error::{QueryError, ServiceError};
+use crate::error::{QueryError, ServiceError, SkippedItem};
+use crate::query::json::from_json;
 use crate::{BodhiService, Query, SinglePageQuery};
 
 /// Use this for querying bodhi for a new CSRF token. It will return either an `Ok(String)` with the
@@ -17,7 +18,7 @@ use crate::{BodhiService, Query, SinglePageQuery};
 /// ```
 ///
 /// API documentation: <https://bodhi.fedoraproject.org/docs/server_api/rest/csrf.html>
-#[derive(Debug, Default)]
+#[derive(Clone, Debug, Default)]
 pub struct CSRFQuery {}
 
 #[derive(Debug, Deserialize)]
@@ -38,7 +39,7 @@ impl SinglePageQuery<String> for CSRFQuery {
     }
 
     fn parse(string: &str) -> Result<String, QueryError> {
-        let page: CSRFPage = serde_json::from_str(string)?;
+        let page: CSRFPage = from_json(string)?;
         Ok(page.csrf_token)
     }
 
@@ -50,7 +51,7 @@ impl SinglePageQuery<String> for CSRFQuery {
 }
 
 impl Query<String> for CSRFQuery {
-    fn query(self, bodhi: &BodhiService) -> Result<String, QueryError> {
-        <Self as SinglePageQuery<String>>::query(self, bodhi)
+    fn query(self, bodhi: &BodhiService, skipped: Option<&mut Vec<SkippedItem>>) -> Result<String, QueryError> {
+        <Self as SinglePageQuery<String>>::query(self, bodhi, skipped)
     }
 }
@@ -0,0 +1,141 @@
+//! This module contains helpers for deserializing JSON responses from bodhi, which attach the JSON
+//! path of the offending value to deserialization errors, and which implement lenient queries
+//! (skipping malformed items of paginated results instead of failing the whole query).
+
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::error::{QueryError, SkippedItem};
+use crate::BodhiService;
+
+/// This function deserializes a JSON string into the given type. If deserialization fails, the
+/// returned `DeserializationError` contains the JSON path of the value that could not be
+/// deserialized (for example, `updates[17].builds[2].epoch`).
+pub(crate) fn from_json<T: DeserializeOwned>(string: &str) -> Result<T, QueryError> {
+    let mut deserializer = serde_json::Deserializer::from_str(string);
+
+    match serde_path_to_error::deserialize(&mut deserializer) {
+        Ok(value) => {
+            deserializer.end()?;
+            Ok(value)
+        },
+        Err(error) => {
+            // paths are only meaningful for data errors, and not for the top-level value
+            let path = match error.inner().classify() {
+                serde_json::error::Category::Data => Some(error.path().to_string()).filter(|path| path != "."),
+                _ => None,
+            };
+
+            Err(QueryError::DeserializationError {
+                error: error.into_inner(),
+                path,
+                endpoint: None,
+                page: None,
+                dump: None,
+            })
+        },
+    }
+}
+
+/// This function parses the response from the given endpoint with the given function, and adds the
+/// endpoint and page number to deserialization errors. If a dump directory is configured for the
+/// service, the offending payload is written to a file in that directory.
+///
+/// If a list for skipped items is given, items of paginated results that cannot be deserialized
+/// are removed from the response (and added to that list) until parsing succeeds.
+pub(crate) fn parse_response<T>(
+    bodhi: &BodhiService,
+    endpoint: &str,
+    page: Option<u32>,
+    mut body: String,
+    mut skipped: Option<&mut Vec<SkippedItem>>,
+    parse: impl Fn(&str) -> Result<T, QueryError>,
+) -> Result<T, QueryError> {
+    // indices of the items that were already removed from the response (in the original response)
+    let mut removed: Vec<usize> = Vec::new();
+
+    loop {
+        match parse(&body) {
+            Err(QueryError::DeserializationError { error, path, .. }) => {
+                if let Some(skipped) = skipped.as_deref_mut() {
+                    if let Some((key, index, rest)) = path.as_deref().and_then(item_index) {
+                        if let Some((item, remaining)) = remove_item(&body, key, index) {
+                            let original = original_index(&mut removed, index);
+                            let dump = bodhi.dump_payload(endpoint, page, Some(original), &item);
+
+                            skipped.push(SkippedItem {
+                                endpoint: endpoint.to_owned(),
+                                page,
+                                path: format!("{}[{}]{}", key, original, rest),
+                                error: error.to_string(),
+                                dump,
+                            });
+
+                            body = remaining;
+                            continue;
+                        }
+                    }
+                }
+
+                let dump = bodhi.dump_payload(endpoint, page, None, &body);
+
+                return Err(QueryError::DeserializationError {
+                    error,
+                    path,
+                    endpoint: Some(endpoint.to_owned()),
+                    page,
+                    dump,
+                });
+            },
+            result => return result,
+        }
+    }
+}
+
+/// This function returns the name of the top-level list, the index of the item in that list, and
+/// the rest of the path if the given path points into an item of a top-level list (like
+/// `updates[17].builds[2].epoch`).
+fn item_index(path: &str) -> Option<(&str, usize, &str)> {
+    let (key, rest) = path.split_at(path.find('[')?);
+    let end = rest.find(']')?;
+
+    if key.is_empty() || key.contains('.') {
+        return None;
+    }
+
+    Some((key, rest[1..end].parse().ok()?, &rest[end + 1..]))
+}
+
+/// This function converts the index of an item in the current response into its index in the
+/// original response (before other items were removed), and adds it to the removed indices.
+fn original_index(removed: &mut Vec<usize>, index: usize) -> usize {
+    let mut original = index;
+
+    for removed in removed.iter() {
+        if *removed <= original {
+            original += 1;
+        }
+    }
+
+    let position = removed.partition_point(|removed| *removed < original);
+    removed.insert(position, original);
+
+    original
+}
+
+/// This function removes the item with the given index from the top-level list with the given name,
+/// and returns both the serialized item and the remaining response.
+fn remove_item(body: &str, key: &str, index: usize) -> Option<(String, String)> {
+    let mut value: Value = serde_json::from_str(body).ok()?;
+
+    let list = value.get_mut(key)?.as_array_mut()?;
+    if index >= list.len() {
+        return None;
+    }
+    let item = list.remove(index);
+
+    Some((
+        serde_json::to_string_pretty(&item).ok()?,
+        serde_json::to_string(&value).ok()?,
+    ))
+}
@@ -17,6 +17,8 @@ pub use composes::{ComposeQuery, ComposeReleaseRequestQuery};
 pub mod csrf;
 pub use csrf::CSRFQuery;
 
+pub(crate) mod json;
+
 pub mod overrides;
 pub use overrides::{OverrideNVRQuery, OverrideQuery};
 
@@ -29,8 +31,14 @@ pub use releases::{ReleaseNameQuery, ReleaseQuery};
 pub mod updates;
 pub use updates::{UpdateIDQuery, UpdateQuery};
 
+pub mod urls;
+pub use urls::BodhiResource;
+
 pub mod users;
 pub use users::{UserNameQuery, UserQuery};
 
+pub mod version;
+pub use version::{ServerInfoQuery, ServerVersionQuery};
+
 pub(crate) mod traits;
 pub(crate) use traits::*;
@@ -12,10 +12,13 @@
 use std::fmt::{Debug, Formatter};
 
 use serde::{Deserialize, Serialize};
+use url::Url;
 
-use crate::error::{QueryError, ServiceError};
+use crate::error::{QueryError, ServiceError, SkippedItem, UrlError};
+use crate::query::json::from_json;
+use crate::query::urls::{collection_url, extend_list, parse_value, query_params, unknown_parameter};
 use crate::service::DEFAULT_ROWS;
-use crate::{BodhiService, FedoraRelease, Override, Query, SinglePageQuery};
+use crate::{BodhiService, Callback, FedoraRelease, Nvr, Override, Query, SinglePageQuery};
 
 /// Use this for querying bodhi for a specific override, by its NVR (Name-Version-Release) string.
 /// It will return either an `Ok(Some(Override))` matching the specified NVR, return `Ok(None)` if
@@ -26,13 +29,13 @@ use crate::{BodhiService, FedoraRelease, Override, Query, SinglePageQuery};
 /// let bodhi = BodhiServiceBuilder::default().build().unwrap();
 ///
 /// # #[cfg(feature = "online-tests")]
-/// let over_ride = bodhi.query(OverrideNVRQuery::new("wingpanel-2.2.1-1.fc28")).unwrap();
+/// let over_ride = bodhi.query(OverrideNVRQuery::new(&"wingpanel-2.2.1-1.fc28".parse().unwrap())).unwrap();
 /// ```
 ///
 /// API documentation: <https://bodhi.fedoraproject.org/docs/server_api/rest/overrides.html#service-0>
-#[derive(Debug)]
-pub struct OverrideNVRQuery<'a> {
-    nvr: &'a str,
+#[derive(Clone, Debug)]
+pub struct OverrideNVRQuery {
+    nvr: String,
 }
 
 #[derive(Debug, Deserialize)]
@@ -40,21 +43,27 @@ struct OverridePage {
     r#override: Override,
 }
 
-impl<'a> OverrideNVRQuery<'a> {
+impl OverrideNVRQuery {
     /// This method is the only way to create a new
     /// [`OverrideNVRQuery`](struct.OverrideNVRQuery.html) instance.
-    pub fn new(nvr: &'a str) -> Self {
-        OverrideNVRQuery { nvr }
+    pub fn new(nvr: &Nvr) -> Self {
+        OverrideNVRQuery { nvr: nvr.to_string() }
+    }
+
+    /// This method constructs the URL of the requested buildroot override on the given bodhi
+    /// instance.
+    pub fn web_url(&self, bodhi: &BodhiService) -> Url {
+        bodhi.web_url(&format!("/overrides/{}", self.nvr))
     }
 }
 
-impl<'a> SinglePageQuery<Option<Override>> for OverrideNVRQuery<'a> {
+impl SinglePageQuery<Option<Override>> for OverrideNVRQuery {
     fn path(&self) -> Result<String, QueryError> {
         Ok(format!("/overrides/{}", self.nvr))
     }
 
     fn parse(string: &str) -> Result<Option<Override>, QueryError> {
-        let override_page: OverridePage = serde_json::from_str(string)?;
+        let override_page: OverridePage = from_json(string)?;
         Ok(Some(override_page.r#override))
     }
 
@@ -63,9 +72,13 @@ impl<'a> SinglePageQuery<Option<Override>> for OverrideNVRQuery<'a> {
     }
 }
 
-impl<'a> Query<Option<Override>> for OverrideNVRQuery<'a> {
-    fn query(self, bodhi: &BodhiService) -> Result<Option<Override>, QueryError> {
-        <Self as SinglePageQuery<Option<Override>>>::query(self, bodhi)
+impl Query<Option<Override>> for OverrideNVRQuery {
+    fn query(
+        self,
+        bodhi: &BodhiService,
+        skipped: Option<&mut Vec<SkippedItem>>,
+    ) -> Result<Option<Override>, QueryError> {
+        <Self as SinglePageQuery<Option<Override>>>::query(self, bodhi, skipped)
     }
 }
 
@@ -74,6 +87,9 @@ impl<'a> Query<Option<Override>> for OverrideNVRQuery<'a> {
 /// overrides will be returned if any criteria match. This is consistent with both the web interface
 /// and REST API behavior.
 ///
+/// The query parameters (but not the progress callback) can be serialized and deserialized, using
+/// the same parameter names as the REST API.
+///
 /// ```
 /// # use bodhi::{BodhiServiceBuilder, FedoraRelease, OverrideQuery};
 /// let bodhi = BodhiServiceBuilder::default().build().unwrap();
@@ -88,19 +104,25 @@ impl<'a> Query<Option<Override>> for OverrideNVRQuery<'a> {
 ///     .unwrap();
 /// ```
 ///
+/// Cloning a query does not clone its [`callback`](#method.callback): the clone sends the same
+/// requests, but it does not report any progress unless a new callback is set.
+///
 /// API documentation: <https://bodhi.fedoraproject.org/docs/server_api/rest/overrides.html#service-1>
-#[derive(Default)]
+#[derive(Clone, Default, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
 pub struct OverrideQuery<'a> {
-    builds: Option<Vec<&'a str>>,
+    builds: Option<Vec<Nvr>>,
     expired: Option<bool>,
-    like: Option<&'a str>,
-    packages: Option<Vec<&'a str>>,
+    like: Option<String>,
+    packages: Option<Vec<String>>,
     releases: Option<Vec<FedoraRelease>>,
-    search: Option<&'a str>,
-    users: Option<Vec<&'a str>>,
+    search: Option<String>,
+    #[serde(rename = "user")]
+    users: Option<Vec<String>>,
 
     /// optional callback function for reporting progress
-    callback: Option<Box<dyn FnMut(u32, u32) + 'a>>,
+    #[serde(skip)]
+    callback: Callback<'a>,
 }
 
 impl<'a> Debug for OverrideQuery<'a> {
@@ -125,20 +147,24 @@ impl<'a> OverrideQuery<'a> {
             releases: None,
             search: None,
             users: None,
-            callback: None,
+            callback: Callback::default(),
         }
     }
 
     /// Add a callback function for reporting back query progress for long-running queries.
     /// The function will be called with the current page and the total number of pages for
-    /// paginated queries.
-    pub fn callback(mut self, fun: impl FnMut(u32, u32) + 'a) -> Self {
-        self.callback = Some(Box::new(fun));
+    /// paginated queries. The callback is the only part of a query that can borrow data, and it
+    /// must be `Send`, so the query can be sent to other threads.
+    ///
+    /// **Note**: The callback is *not* cloned together with the query, so clones of this query do
+    /// not report any progress.
+    pub fn callback(mut self, fun: impl FnMut(u32, u32) + Send + 'a) -> Self {
+        self.callback = Callback::new(fun);
         self
     }
 
     /// Restrict the returned results to overrides for the given build(s).
-    pub fn builds(mut self, builds: Vec<&'a str>) -> Self {
+    pub fn builds(mut self, builds: Vec<Nvr>) -> Self {
         self.builds = Some(builds);
         self
     }
@@ -150,14 +176,14 @@ impl<'a> OverrideQuery<'a> {
     }
 
     /// Restrict search to overrides *like* the given argument (in the SQL sense).
-    pub fn like(mut self, like: &'a str) -> Self {
-        self.like = Some(like);
+    pub fn like(mut self, like: impl Into<String>) -> Self {
+        self.like = Some(like.into());
         self
     }
 
     /// Restrict the returned results to overrides for the given package(s).
-    pub fn packages(mut self, packages: Vec<&'a str>) -> Self {
-        self.packages = Some(packages);
+    pub fn packages(mut self, packages: Vec<impl Into<String>>) -> Self {
+        self.packages = Some(packages.into_iter().map(Into::into).collect());
         self
     }
 
@@ -168,34 +194,67 @@ impl<'a> OverrideQuery<'a> {
     }
 
     /// Restrict search to overrides containing the given argument.
-    pub fn search(mut self, search: &'a str) -> Self {
-        self.search = Some(search);
+    pub fn search(mut self, search: impl Into<String>) -> Self {
+        self.search = Some(search.into());
         self
     }
 
     /// Restrict the returned results to overrides created by the given user(s).
-    pub fn users(mut self, users: Vec<&'a str>) -> Self {
-        self.users = Some(users);
+    pub fn users(mut self, users: Vec<impl Into<String>>) -> Self {
+        self.users = Some(users.into_iter().map(Into::into).collect());
         self
     }
 
+    /// This method parses a URL of the bodhi web interface or REST API for listing overrides (for
+    /// example, `https://bodhi.fedoraproject.org/overrides/?packages=rust&expired=false`) into the
+    /// equivalent query. Unknown query parameters and invalid values are rejected.
+    pub fn from_url(url: &str) -> Result<Self, UrlError> {
+        let url = collection_url(url, "overrides")?;
+        Self::from_params(&url)
+    }
+
+    pub(crate) fn from_params(url: &Url) -> Result<Self, UrlError> {
+        let mut query = Self::new();
+
+        for (key, value) in query_params(url) {
+            match key.as_str() {
+                "builds" => extend_list(&mut query.builds, &key, &value)?,
+                "expired" => query.expired = Some(parse_value(&key, &value)?),
+                "like" => query.like = Some(value),
+                "packages" => extend_list(&mut query.packages, &key, &value)?,
+                "releases" => extend_list(&mut query.releases, &key, &value)?,
+                "search" => query.search = Some(value),
+                "user" => extend_list(&mut query.users, &key, &value)?,
+                _ => return Err(unknown_parameter("OverrideQuery", key)),
+            }
+        }
+
+        Ok(query)
+    }
+
+    /// This method constructs the URL for viewing the results of this query on the given bodhi
+    /// instance (for example, in the web interface).
+    pub fn web_url(&self, bodhi: &BodhiService) -> Result<Url, QueryError> {
+        Ok(bodhi.web_url(&format!("/overrides/?{}", serde_url_params::to_string(self)?)))
+    }
+
     /// Query the remote bodhi instance with the given parameters.
-    fn query(mut self, bodhi: &BodhiService) -> Result<Vec<Override>, QueryError> {
+    fn query(
+        mut self,
+        bodhi: &BodhiService,
+        mut skipped: Option<&mut Vec<SkippedItem>>,
+    ) -> Result<Vec<Override>, QueryError> {
         let mut overrides: Vec<Override> = Vec::new();
         let mut page = 1;
 
         // initial progress: 0 out of some
-        if let Some(ref mut fun) = self.callback {
-            fun(0, 1);
-        }
+        self.callback.call(0, 1);
 
         loop {
             let query = self.page_query(page, DEFAULT_ROWS);
-            let result = query.query(bodhi)?;
+            let result = query.query(bodhi, skipped.as_deref_mut())?;
 
-            if let Some(ref mut fun) = self.callback {
-                fun(page, result.pages)
-            }
+            self.callback.call(page, result.pages);
 
             overrides.extend(result.r#overrides);
             page += 1;
@@ -212,10 +271,10 @@ impl<'a> OverrideQuery<'a> {
         OverridePageQuery {
             builds: self.builds.as_ref(),
             expired: self.expired,
-            like: self.like,
+            like: self.like.as_deref(),
             packages: self.packages.as_ref(),
             releases: self.releases.as_ref(),
-            search: self.search.as_ref(),
+            search: self.search.as_deref(),
             users: self.users.as_ref(),
             page,
             rows_per_page,
@@ -224,8 +283,8 @@ impl<'a> OverrideQuery<'a> {
 }
 
 impl<'a> Query<Vec<Override>> for OverrideQuery<'a> {
-    fn query(self, bodhi: &BodhiService) -> Result<Vec<Override>, QueryError> {
-        OverrideQuery::query(self, bodhi)
+    fn query(self, bodhi: &BodhiService, skipped: Option<&mut Vec<SkippedItem>>) -> Result<Vec<Override>, QueryError> {
+        OverrideQuery::query(self, bodhi, skipped)
     }
 }
 
@@ -240,14 +299,14 @@ struct OverrideListPage {
 
 #[derive(Debug, Serialize)]
 struct OverridePageQuery<'a> {
-    builds: Option<&'a Vec<&'a str>>,
+    builds: Option<&'a Vec<Nvr>>,
     expired: Option<bool>,
     like: Option<&'a str>,
-    packages: Option<&'a Vec<&'a str>>,
+    packages: Option<&'a Vec<String>>,
     releases: Option<&'a Vec<FedoraRelease>>,
-    search: Option<&'a &'a str>,
+    search: Option<&'a str>,
     #[serde(rename = "user")]
-    users: Option<&'a Vec<&'a str>>,
+    users: Option<&'a Vec<String>>,
 
     page: u32,
     rows_per_page: u32,
@@ -259,7 +318,7 @@ impl<'a> SinglePageQuery<OverrideListPage> for OverridePageQuery<'a> {
     }
 
     fn parse(string: &str) -> Result<OverrideListPage, QueryError> {
-        let override_page: OverrideListPage = serde_json::from_str(string)?;
+        let override_page: OverrideListPage = from_json(string)?;
         Ok(override_page)
     }
 
@@ -268,4 +327,8 @@ impl<'a> SinglePageQuery<OverrideListPage> for OverridePageQuery<'a> {
             error: ServiceError::EmptyResponseError,
         })
     }
+
+    fn page(&self) -> Option<u32> {
+        Some(self.page)
+    }
 }
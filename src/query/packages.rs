@@ -6,10 +6,12 @@
 use std::fmt::{Debug, Formatter};
 
 use serde::{Deserialize, Serialize};
+use url::Url;
 
-use crate::error::{QueryError, ServiceError};
+use crate::error::{QueryError, ServiceError, SkippedItem};
+use crate::query::json::from_json;
 use crate::service::DEFAULT_ROWS;
-use crate::{BodhiService, Package, Query, SinglePageQuery};
+use crate::{BodhiService, Callback, Package, Query, SinglePageQuery};
 
 /// Use this for querying bodhi about a set of packages with the given properties, which can be
 /// specified with the builder pattern. Note that some options can be specified multiple times, and
@@ -24,15 +26,19 @@ use crate::{BodhiService, Package, Query, SinglePageQuery};
 /// let packages = bodhi.query(PackageQuery::new().search("rust*")).unwrap();
 /// ```
 ///
+/// Cloning a query does not clone its [`callback`](#method.callback): the clone sends the same
+/// requests, but it does not report any progress unless a new callback is set.
+///
 /// API documentation: <https://bodhi.fedoraproject.org/docs/server_api/rest/packages.html#service-0>
-#[derive(Default)]
+#[derive(Clone, Default, Serialize)]
 pub struct PackageQuery<'a> {
-    like: Option<&'a str>,
-    name: Option<&'a str>,
-    search: Option<&'a str>,
+    like: Option<String>,
+    name: Option<String>,
+    search: Option<String>,
 
     /// optional callback function for reporting progress
-    callback: Option<Box<dyn FnMut(u32, u32) + 'a>>,
+    #[serde(skip)]
+    callback: Callback<'a>,
 }
 
 impl<'a> Debug for PackageQuery<'a> {
@@ -52,53 +58,63 @@ impl<'a> PackageQuery<'a> {
             like: None,
             name: None,
             search: None,
-            callback: None,
+            callback: Callback::default(),
         }
     }
 
     /// Add a callback function for reporting back query progress for long-running queries.
     /// The function will be called with the current page and the total number of pages for
-    /// paginated queries.
-    pub fn callback(mut self, fun: impl Fn(u32, u32) + 'a) -> Self {
-        self.callback = Some(Box::new(fun));
+    /// paginated queries. The callback is the only part of a query that can borrow data, and it
+    /// must be `Send`, so the query can be sent to other threads.
+    ///
+    /// **Note**: The callback is *not* cloned together with the query, so clones of this query do
+    /// not report any progress.
+    pub fn callback(mut self, fun: impl FnMut(u32, u32) + Send + 'a) -> Self {
+        self.callback = Callback::new(fun);
         self
     }
 
     /// Restrict search to packages *like* the given argument (in the SQL sense).
-    pub fn like(mut self, like: &'a str) -> Self {
-        self.like = Some(like);
+    pub fn like(mut self, like: impl Into<String>) -> Self {
+        self.like = Some(like.into());
         self
     }
 
     /// Restrict the returned results to packages matching the given name.
-    pub fn name(mut self, name: &'a str) -> Self {
-        self.name = Some(name);
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
         self
     }
 
     /// Restrict search to packages containing the given argument.
-    pub fn search(mut self, search: &'a str) -> Self {
-        self.search = Some(search);
+    pub fn search(mut self, search: impl Into<String>) -> Self {
+        self.search = Some(search.into());
         self
     }
 
+    /// This method constructs the URL for viewing the results of this query on the given bodhi
+    /// instance (for example, in the web interface).
+    pub fn web_url(&self, bodhi: &BodhiService) -> Result<Url, QueryError> {
+        Ok(bodhi.web_url(&format!("/packages/?{}", serde_url_params::to_string(self)?)))
+    }
+
     /// Query the remote bodhi instance with the given parameters.
-    fn query(mut self, bodhi: &BodhiService) -> Result<Vec<Package>, QueryError> {
+    fn query(
+        mut self,
+        bodhi: &BodhiService,
+        mut skipped: Option<&mut Vec<SkippedItem>>,
+    ) -> Result<Vec<Package>, QueryError> {
         let mut packages: Vec<Package> = Vec::new();
         let mut page = 1;
 
         // initial progress: 0 out of some
-        if let Some(ref mut fun) = self.callback {
-            fun(0, 1);
-        }
+        self.callback.call(0, 1);
 
         loop {
             let query = self.page_query(page, DEFAULT_ROWS);
-            let result = query.query(bodhi)?;
+            let result = query.query(bodhi, skipped.as_deref_mut())?;
 
-            if let Some(ref mut fun) = self.callback {
-                fun(page, result.pages)
-            }
+            self.callback.call(page, result.pages);
 
             packages.extend(result.packages);
             page += 1;
@@ -113,9 +129,9 @@ impl<'a> PackageQuery<'a> {
 
     fn page_query(&self, page: u32, rows_per_page: u32) -> PackagePageQuery {
         PackagePageQuery {
-            like: self.like,
-            name: self.name,
-            search: self.search,
+            like: self.like.as_deref(),
+            name: self.name.as_deref(),
+            search: self.search.as_deref(),
             page,
             rows_per_page,
         }
@@ -123,8 +139,8 @@ impl<'a> PackageQuery<'a> {
 }
 
 impl<'a> Query<Vec<Package>> for PackageQuery<'a> {
-    fn query(self, bodhi: &BodhiService) -> Result<Vec<Package>, QueryError> {
-        PackageQuery::query(self, bodhi)
+    fn query(self, bodhi: &BodhiService, skipped: Option<&mut Vec<SkippedItem>>) -> Result<Vec<Package>, QueryError> {
+        PackageQuery::query(self, bodhi, skipped)
     }
 }
 
@@ -153,7 +169,7 @@ impl<'a> SinglePageQuery<PackageListPage> for PackagePageQuery<'a> {
     }
 
     fn parse(string: &str) -> Result<PackageListPage, QueryError> {
-        let package_page: PackageListPage = serde_json::from_str(string)?;
+        let package_page: PackageListPage = from_json(string)?;
         Ok(package_page)
     }
 
@@ -162,4 +178,8 @@ impl<'a> SinglePageQuery<PackageListPage> for PackagePageQuery<'a> {
             error: ServiceError::EmptyResponseError,
         })
     }
+
+    fn page(&self) -> Option<u32> {
+        Some(self.page)
+    }
 }
@@ -9,10 +9,12 @@
 //! updates or packages.
 
 use serde::{Deserialize, Serialize};
+use url::Url;
 
-use crate::error::{QueryError, ServiceError};
+use crate::error::{QueryError, ServiceError, SkippedItem};
+use crate::query::json::from_json;
 use crate::service::DEFAULT_ROWS;
-use crate::{BodhiService, Query, Release, SinglePageQuery};
+use crate::{BodhiService, FedoraRelease, Query, Release, SinglePageQuery, UpdateAlias};
 
 /// Use this for querying bodhi for a specific release by its name. It will either return an
 /// `Ok(Some(Release))` matching the specified name, return `Ok(None)` if it doesn't exist, or
@@ -27,26 +29,33 @@ use crate::{BodhiService, Query, Release, SinglePageQuery};
 /// ```
 ///
 /// API documentation: <https://bodhi.fedoraproject.org/docs/server_api/rest/releases.html#service-0>
-#[derive(Debug)]
-pub struct ReleaseNameQuery<'a> {
-    name: &'a str,
+#[derive(Clone, Debug)]
+pub struct ReleaseNameQuery {
+    name: String,
 }
 
-impl<'a> ReleaseNameQuery<'a> {
+impl ReleaseNameQuery {
     /// This method is the only way to create a new
     /// [`ReleaseNameQuery`](struct.ReleaseNameQuery.html) instance.
-    pub fn new(name: &'a str) -> Self {
-        ReleaseNameQuery { name }
+    pub fn new(name: impl AsRef<str>) -> Self {
+        ReleaseNameQuery {
+            name: name.as_ref().to_owned(),
+        }
+    }
+
+    /// This method constructs the URL of the requested release on the given bodhi instance.
+    pub fn web_url(&self, bodhi: &BodhiService) -> Url {
+        bodhi.web_url(&format!("/releases/{}", self.name))
     }
 }
 
-impl<'a> SinglePageQuery<Option<Release>> for ReleaseNameQuery<'a> {
+impl SinglePageQuery<Option<Release>> for ReleaseNameQuery {
     fn path(&self) -> Result<String, QueryError> {
         Ok(format!("/releases/{}", self.name))
     }
 
     fn parse(string: &str) -> Result<Option<Release>, QueryError> {
-        let release: Release = serde_json::from_str(string)?;
+        let release: Release = from_json(string)?;
         Ok(Some(release))
     }
 
@@ -55,9 +64,36 @@ impl<'a> SinglePageQuery<Option<Release>> for ReleaseNameQuery<'a> {
     }
 }
 
-impl<'a> Query<Option<Release>> for ReleaseNameQuery<'a> {
-    fn query(self, bodhi: &BodhiService) -> Result<Option<Release>, QueryError> {
-        <Self as SinglePageQuery<Option<Release>>>::query(self, bodhi)
+impl Query<Option<Release>> for ReleaseNameQuery {
+    fn query(
+        self,
+        bodhi: &BodhiService,
+        skipped: Option<&mut Vec<SkippedItem>>,
+    ) -> Result<Option<Release>, QueryError> {
+        <Self as SinglePageQuery<Option<Release>>>::query(self, bodhi, skipped)
+    }
+}
+
+impl FedoraRelease {
+    /// This method checks whether a release with this name exists on the given bodhi instance.
+    /// Since any name that matches bodhi's naming scheme can be parsed into a `FedoraRelease`, this
+    /// can be used to validate user input before using it as a query filter. Pseudo-releases like
+    /// `FedoraRelease::Current` are always considered to exist.
+    ///
+    /// ```
+    /// # use bodhi::{BodhiServiceBuilder, FedoraRelease};
+    /// let bodhi = BodhiServiceBuilder::default().build().unwrap();
+    ///
+    /// # #[cfg(feature = "online-tests")]
+    /// assert!(FedoraRelease::F30.exists(&bodhi).unwrap());
+    /// ```
+    pub fn exists(&self, bodhi: &BodhiService) -> Result<bool, QueryError> {
+        if self.is_pseudo() {
+            return Ok(true);
+        }
+
+        let name = self.to_string();
+        Ok(bodhi.query(ReleaseNameQuery::new(&name))?.is_some())
     }
 }
 
@@ -75,17 +111,16 @@ impl<'a> Query<Option<Release>> for ReleaseNameQuery<'a> {
 /// ```
 ///
 /// API documentation: <https://bodhi.fedoraproject.org/docs/server_api/rest/releases.html#service-1>
-#[derive(Debug, Default)]
-
-pub struct ReleaseQuery<'a> {
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ReleaseQuery {
     exclude_archived: Option<bool>,
-    ids: Option<Vec<&'a str>>,
-    name: Option<&'a str>,
-    packages: Option<Vec<&'a str>>,
-    updates: Option<Vec<&'a str>>,
+    ids: Option<Vec<String>>,
+    name: Option<String>,
+    packages: Option<Vec<String>>,
+    updates: Option<Vec<UpdateAlias>>,
 }
 
-impl<'a> ReleaseQuery<'a> {
+impl ReleaseQuery {
     /// This method returns a new [`ReleaseQuery`](struct.ReleaseQuery.html) with *no* filters set.
     pub fn new() -> Self {
         ReleaseQuery {
@@ -104,38 +139,48 @@ impl<'a> ReleaseQuery<'a> {
     }
 
     /// Restrict results to releases with the given ID.
-    pub fn ids(mut self, ids: Vec<&'a str>) -> Self {
-        self.ids = Some(ids);
+    pub fn ids(mut self, ids: Vec<impl Into<String>>) -> Self {
+        self.ids = Some(ids.into_iter().map(Into::into).collect());
         self
     }
 
     /// Restrict results to a release with the given name. If this is the only required filter,
     /// consider using a [`ReleaseNameQuery`](struct.ReleaseNameQuery.html) instead.
-    pub fn name(mut self, name: &'a str) -> Self {
-        self.name = Some(name);
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
         self
     }
 
     /// Restrict the returned results to releases containing the given package(s).
-    pub fn packages(mut self, packages: Vec<&'a str>) -> Self {
-        self.packages = Some(packages);
+    pub fn packages(mut self, packages: Vec<impl Into<String>>) -> Self {
+        self.packages = Some(packages.into_iter().map(Into::into).collect());
         self
     }
 
     /// Restrict the returned results to releases matching the given updates(s).
-    pub fn updates(mut self, updates: Vec<&'a str>) -> Self {
+    pub fn updates(mut self, updates: Vec<UpdateAlias>) -> Self {
         self.updates = Some(updates);
         self
     }
 
+    /// This method constructs the URL for viewing the results of this query on the given bodhi
+    /// instance (for example, in the web interface).
+    pub fn web_url(&self, bodhi: &BodhiService) -> Result<Url, QueryError> {
+        Ok(bodhi.web_url(&format!("/releases/?{}", serde_url_params::to_string(self)?)))
+    }
+
     /// Query the remote bodhi instance with the given parameters.
-    fn query(self, bodhi: &BodhiService) -> Result<Vec<Release>, QueryError> {
+    fn query(
+        self,
+        bodhi: &BodhiService,
+        mut skipped: Option<&mut Vec<SkippedItem>>,
+    ) -> Result<Vec<Release>, QueryError> {
         let mut overrides: Vec<Release> = Vec::new();
         let mut page = 1;
 
         loop {
             let query = self.page_query(page, DEFAULT_ROWS);
-            let result = query.query(bodhi)?;
+            let result = query.query(bodhi, skipped.as_deref_mut())?;
 
             overrides.extend(result.releases);
             page += 1;
@@ -152,7 +197,7 @@ impl<'a> ReleaseQuery<'a> {
         ReleasePageQuery {
             exclude_archived: self.exclude_archived,
             ids: self.ids.as_ref(),
-            name: self.name.as_ref(),
+            name: self.name.as_deref(),
             packages: self.packages.as_ref(),
             updates: self.updates.as_ref(),
             page,
@@ -161,9 +206,9 @@ impl<'a> ReleaseQuery<'a> {
     }
 }
 
-impl<'a> Query<Vec<Release>> for ReleaseQuery<'a> {
-    fn query(self, bodhi: &BodhiService) -> Result<Vec<Release>, QueryError> {
-        ReleaseQuery::query(self, bodhi)
+impl Query<Vec<Release>> for ReleaseQuery {
+    fn query(self, bodhi: &BodhiService, skipped: Option<&mut Vec<SkippedItem>>) -> Result<Vec<Release>, QueryError> {
+        ReleaseQuery::query(self, bodhi, skipped)
     }
 }
 
@@ -179,10 +224,10 @@ struct ReleaseListPage {
 #[derive(Debug, Serialize)]
 struct ReleasePageQuery<'a> {
     exclude_archived: Option<bool>,
-    ids: Option<&'a Vec<&'a str>>,
-    name: Option<&'a &'a str>,
-    packages: Option<&'a Vec<&'a str>>,
-    updates: Option<&'a Vec<&'a str>>,
+    ids: Option<&'a Vec<String>>,
+    name: Option<&'a str>,
+    packages: Option<&'a Vec<String>>,
+    updates: Option<&'a Vec<UpdateAlias>>,
 
     page: u32,
     rows_per_page: u32,
@@ -194,7 +239,7 @@ impl<'a> SinglePageQuery<ReleaseListPage> for ReleasePageQuery<'a> {
     }
 
     fn parse(string: &str) -> Result<ReleaseListPage, QueryError> {
-        let release_page: ReleaseListPage = serde_json::from_str(string)?;
+        let release_page: ReleaseListPage = from_json(string)?;
         Ok(release_page)
     }
 
@@ -203,4 +248,8 @@ impl<'a> SinglePageQuery<ReleaseListPage> for ReleasePageQuery<'a> {
             error: ServiceError::EmptyResponseError,
         })
     }
+
+    fn page(&self) -> Option<u32> {
+        Some(self.page)
+    }
 }
@@ -1,8 +1,12 @@
-use crate::error::{BodhiError, QueryError};
+use crate::error::{QueryError, SkippedItem};
+use crate::query::json::parse_response;
 use crate::BodhiService;
 
 pub trait Query<T> {
-    fn query(self, bodhi: &BodhiService) -> Result<T, QueryError>;
+    /// This method executes the query. If a list for skipped items is given, items of paginated
+    /// results that cannot be deserialized are skipped and added to that list ("lenient" mode)
+    /// instead of failing the whole query.
+    fn query(self, bodhi: &BodhiService, skipped: Option<&mut Vec<SkippedItem>>) -> Result<T, QueryError>;
 }
 
 pub trait SinglePageQuery<T> {
@@ -21,10 +25,16 @@ pub trait SinglePageQuery<T> {
     /// will return an error.
     fn missing() -> Result<T, QueryError>;
 
+    /// This method returns the page number for queries that fetch one page of paginated results,
+    /// which is included in error messages.
+    fn page(&self) -> Option<u32> {
+        None
+    }
+
     /// This method executes a single-page query, but delegates execution of some things to the
     /// individual trait implementations (such as deserializing JSON, handling 404 errors, or
     /// getting API paths and arguments).
-    fn query(self, bodhi: &BodhiService) -> Result<T, QueryError>
+    fn query(self, bodhi: &BodhiService, skipped: Option<&mut Vec<SkippedItem>>) -> Result<T, QueryError>
     where
         Self: Sized,
     {
@@ -34,14 +44,46 @@ pub trait SinglePageQuery<T> {
 
         if status.is_success() {
             let string = response.text()?;
-            <Self as SinglePageQuery<T>>::parse(&string)
+            parse_response(
+                bodhi,
+                &path,
+                self.page(),
+                string,
+                skipped,
+                <Self as SinglePageQuery<T>>::parse,
+            )
         } else if status == 404 {
             <Self as SinglePageQuery<T>>::missing()
         } else {
-            let result = response.text()?;
-            let error: BodhiError = serde_json::from_str(&result)?;
+            Err(QueryError::from_response(response))
+        }
+    }
+}
+
+/// This struct wraps the optional callback function of paginated queries, which is called with the
+/// current page and the total number of pages for reporting progress. Callbacks are not cloned
+/// together with the query they belong to, so the clone of a query does not report any progress.
+#[derive(Default)]
+pub(crate) struct Callback<'a> {
+    fun: Option<Box<dyn FnMut(u32, u32) + Send + 'a>>,
+}
 
-            Err(QueryError::BodhiError { error })
+impl<'a> Callback<'a> {
+    pub(crate) fn new(fun: impl FnMut(u32, u32) + Send + 'a) -> Self {
+        Callback {
+            fun: Some(Box::new(fun)),
         }
     }
+
+    pub(crate) fn call(&mut self, page: u32, pages: u32) {
+        if let Some(fun) = &mut self.fun {
+            fun(page, pages);
+        }
+    }
+}
+
+impl<'a> Clone for Callback<'a> {
+    fn clone(&self) -> Self {
+        Callback::default()
+    }
 }
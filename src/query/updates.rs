@@ -11,28 +11,33 @@
 use std::fmt::{Debug, Formatter};
 
 use serde::{Deserialize, Serialize};
+use url::Url;
 
 use crate::data::*;
-use crate::error::{QueryError, ServiceError};
+use crate::error::{QueryError, ServiceError, SkippedItem, UrlError};
+use crate::query::json::from_json;
+use crate::query::urls::{collection_url, extend_list, parse_enum, parse_value, query_params, unknown_parameter};
 use crate::service::DEFAULT_ROWS;
-use crate::{BodhiService, Query, SinglePageQuery};
+use crate::{BodhiService, Callback, Query, SinglePageQuery};
 
 /// Use this for querying bodhi for a specific update by its ID or alias. It will either return an
 /// `Ok(Some(Update))` matching the specified ID or alias, return `Ok(None)` if it doesn't exist, or
 /// return an `Err(QueryError)` if another error occurred.
 ///
 /// ```
-/// # use bodhi::{BodhiServiceBuilder, UpdateIDQuery};
+/// # use bodhi::{BodhiServiceBuilder, UpdateAlias, UpdateIDQuery};
 /// let bodhi = BodhiServiceBuilder::default().build().unwrap();
 ///
+/// let alias: UpdateAlias = "FEDORA-2019-3dd0cf468e".parse().unwrap();
+///
 /// # #[cfg(feature = "online-tests")]
-/// let update = bodhi.query(UpdateIDQuery::new("FEDORA-2019-3dd0cf468e")).unwrap();
+/// let update = bodhi.query(UpdateIDQuery::new(alias)).unwrap();
 /// ```
 ///
 /// API documentation: <https://bodhi.fedoraproject.org/docs/server_api/rest/updates.html#service-0>
-#[derive(Debug)]
-pub struct UpdateIDQuery<'a> {
-    id: &'a str,
+#[derive(Clone, Debug)]
+pub struct UpdateIDQuery {
+    id: UpdateID,
 }
 
 #[derive(Debug, Deserialize)]
@@ -41,20 +46,26 @@ struct UpdatePage {
     can_edit: bool,
 }
 
-impl<'a> UpdateIDQuery<'a> {
-    /// This method is the only way to create a new `UpdateIDQuery` instance.
-    pub fn new(id: &'a str) -> Self {
-        UpdateIDQuery { id }
+impl UpdateIDQuery {
+    /// This method is the only way to create a new `UpdateIDQuery` instance. The argument can be
+    /// either an [`UpdateAlias`](../../data/struct.UpdateAlias.html), or a numerical update ID.
+    pub fn new(id: impl Into<UpdateID>) -> Self {
+        UpdateIDQuery { id: id.into() }
+    }
+
+    /// This method constructs the URL of the requested update on the given bodhi instance.
+    pub fn web_url(&self, bodhi: &BodhiService) -> Url {
+        bodhi.web_url(&format!("/updates/{}", self.id))
     }
 }
 
-impl<'a> SinglePageQuery<Option<Update>> for UpdateIDQuery<'a> {
+impl SinglePageQuery<Option<Update>> for UpdateIDQuery {
     fn path(&self) -> Result<String, QueryError> {
         Ok(format!("/updates/{}", self.id))
     }
 
     fn parse(string: &str) -> Result<Option<Update>, QueryError> {
-        let update_page: UpdatePage = serde_json::from_str(string)?;
+        let update_page: UpdatePage = from_json(string)?;
         Ok(Some(update_page.update))
     }
 
@@ -63,9 +74,9 @@ impl<'a> SinglePageQuery<Option<Update>> for UpdateIDQuery<'a> {
     }
 }
 
-impl<'a> Query<Option<Update>> for UpdateIDQuery<'a> {
-    fn query(self, bodhi: &BodhiService) -> Result<Option<Update>, QueryError> {
-        <Self as SinglePageQuery<Option<Update>>>::query(self, bodhi)
+impl Query<Option<Update>> for UpdateIDQuery {
+    fn query(self, bodhi: &BodhiService, skipped: Option<&mut Vec<SkippedItem>>) -> Result<Option<Update>, QueryError> {
+        <Self as SinglePageQuery<Option<Update>>>::query(self, bodhi, skipped)
     }
 }
 
@@ -74,6 +85,9 @@ impl<'a> Query<Option<Update>> for UpdateIDQuery<'a> {
 /// updates will be returned if any criteria match. This is consistent with both the web interface
 /// and REST API behavior.
 ///
+/// The query parameters (but not the progress callback) can be serialized and deserialized, using
+/// the same parameter names as the REST API.
+///
 /// ```
 /// # use bodhi::{BodhiServiceBuilder, FedoraRelease, UpdateRequest, UpdateQuery};
 /// let bodhi = BodhiServiceBuilder::default().build().unwrap();
@@ -89,40 +103,58 @@ impl<'a> Query<Option<Update>> for UpdateIDQuery<'a> {
 ///     .unwrap();
 /// ```
 ///
+/// Cloning a query does not clone its [`callback`](#method.callback): the clone sends the same
+/// requests, but it does not report any progress unless a new callback is set.
+///
 /// API documentation: <https://bodhi.fedoraproject.org/docs/server_api/rest/updates.html#service-2>
-#[derive(Default)]
+#[derive(Clone, Default, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
 pub struct UpdateQuery<'a> {
     active_releases: Option<bool>,
-    aliases: Option<Vec<&'a str>>,
-    approved_before: Option<&'a BodhiDate>,
-    approved_since: Option<&'a BodhiDate>,
-    bugs: Option<Vec<u32>>,
-    builds: Option<Vec<&'a str>>,
+    #[serde(rename = "alias")]
+    aliases: Option<Vec<UpdateAlias>>,
+    #[serde(with = "crate::option_bodhi_date_format")]
+    approved_before: Option<BodhiDate>,
+    #[serde(with = "crate::option_bodhi_date_format")]
+    approved_since: Option<BodhiDate>,
+    bugs: Option<Vec<BugId>>,
+    builds: Option<Vec<Nvr>>,
     content_type: Option<ContentType>,
     critpath: Option<bool>,
-    cves: Option<Vec<&'a str>>,
-    like: Option<&'a str>,
+    cves: Option<Vec<String>>,
+    from_side_tag: Option<String>,
+    like: Option<String>,
     locked: Option<bool>,
-    modified_before: Option<&'a BodhiDate>,
-    modified_since: Option<&'a BodhiDate>,
-    packages: Option<Vec<&'a str>>,
+    #[serde(with = "crate::option_bodhi_date_format")]
+    modified_before: Option<BodhiDate>,
+    #[serde(with = "crate::option_bodhi_date_format")]
+    modified_since: Option<BodhiDate>,
+    packages: Option<Vec<String>>,
     pushed: Option<bool>,
-    pushed_before: Option<&'a BodhiDate>,
-    pushed_since: Option<&'a BodhiDate>,
+    #[serde(with = "crate::option_bodhi_date_format")]
+    pushed_before: Option<BodhiDate>,
+    #[serde(with = "crate::option_bodhi_date_format")]
+    pushed_since: Option<BodhiDate>,
     releases: Option<Vec<FedoraRelease>>,
     request: Option<UpdateRequest>,
-    search: Option<&'a str>,
+    search: Option<String>,
     severity: Option<UpdateSeverity>,
     status: Option<UpdateStatus>,
-    submitted_before: Option<&'a BodhiDate>,
-    submitted_since: Option<&'a BodhiDate>,
+    #[serde(with = "crate::option_bodhi_date_format")]
+    submitted_before: Option<BodhiDate>,
+    #[serde(with = "crate::option_bodhi_date_format")]
+    submitted_since: Option<BodhiDate>,
     suggest: Option<UpdateSuggestion>,
-    update_ids: Option<Vec<&'a str>>,
+    #[serde(rename = "updateid")]
+    update_ids: Option<Vec<UpdateID>>,
+    #[serde(rename = "type")]
     update_type: Option<UpdateType>,
-    users: Option<Vec<&'a str>>,
+    #[serde(rename = "user")]
+    users: Option<Vec<String>>,
 
     /// optional callback function for reporting progress
-    callback: Option<Box<dyn FnMut(u32, u32) + 'a>>,
+    #[serde(skip)]
+    callback: Callback<'a>,
 }
 
 impl<'a> Debug for UpdateQuery<'a> {
@@ -134,9 +166,10 @@ impl<'a> Debug for UpdateQuery<'a> {
         )?;
         write!(
             f,
-            "bugs: {:?}, builds: {:?}, content_type: {:?}, critpath: {:?}, cves: {:?}, like: {:?}, locked: {:?}, ",
-            &self.bugs, &self.builds, &self.content_type, &self.critpath, &self.cves, &self.like, &self.locked,
+            "bugs: {:?}, builds: {:?}, content_type: {:?}, critpath: {:?}, cves: {:?}, from_side_tag: {:?}, ",
+            &self.bugs, &self.builds, &self.content_type, &self.critpath, &self.cves, &self.from_side_tag,
         )?;
+        write!(f, "like: {:?}, locked: {:?}, ", &self.like, &self.locked)?;
         write!(
             f,
             "modified_before: {:?}, modified_since: {:?}, packages: {:?}, pushed: {:?}, pushed_before: {:?}, ",
@@ -169,6 +202,7 @@ impl<'a> UpdateQuery<'a> {
             content_type: None,
             critpath: None,
             cves: None,
+            from_side_tag: None,
             like: None,
             locked: None,
             modified_before: None,
@@ -188,15 +222,19 @@ impl<'a> UpdateQuery<'a> {
             update_ids: None,
             update_type: None,
             users: None,
-            callback: None,
+            callback: Callback::default(),
         }
     }
 
     /// Add a callback function for reporting back query progress for long-running queries.
     /// The function will be called with the current page and the total number of pages for
-    /// paginated queries.
-    pub fn callback(mut self, fun: impl Fn(u32, u32) + 'a) -> Self {
-        self.callback = Some(Box::new(fun));
+    /// paginated queries. The callback is the only part of a query that can borrow data, and it
+    /// must be `Send`, so the query can be sent to other threads.
+    ///
+    /// **Note**: The callback is *not* cloned together with the query, so clones of this query do
+    /// not report any progress.
+    pub fn callback(mut self, fun: impl FnMut(u32, u32) + Send + 'a) -> Self {
+        self.callback = Callback::new(fun);
         self
     }
 
@@ -207,33 +245,33 @@ impl<'a> UpdateQuery<'a> {
     }
 
     /// Restrict results to updates matching the given alias(es).
-    pub fn aliases(mut self, aliases: Vec<&'a str>) -> Self {
+    pub fn aliases(mut self, aliases: Vec<UpdateAlias>) -> Self {
         self.aliases = Some(aliases);
         self
     }
 
     /// Restrict the returned results to updates which were approved
     /// before the given date and time.
-    pub fn approved_before(mut self, approved_before: &'a BodhiDate) -> Self {
-        self.approved_before = Some(approved_before);
+    pub fn approved_before(mut self, approved_before: &BodhiDate) -> Self {
+        self.approved_before = Some(approved_before.clone());
         self
     }
 
     /// Restrict the returned results to updates which were approved
     /// since the given date and time.
-    pub fn approved_since(mut self, approved_since: &'a BodhiDate) -> Self {
-        self.approved_since = Some(approved_since);
+    pub fn approved_since(mut self, approved_since: &BodhiDate) -> Self {
+        self.approved_since = Some(approved_since.clone());
         self
     }
 
     /// Restrict results to updates associated with the given bug(s).
-    pub fn bugs(mut self, bugs: Vec<u32>) -> Self {
-        self.bugs = Some(bugs);
+    pub fn bugs(mut self, bugs: Vec<impl Into<BugId>>) -> Self {
+        self.bugs = Some(bugs.into_iter().map(Into::into).collect());
         self
     }
 
     /// Restrict results to updates associated with the given build(s).
-    pub fn builds(mut self, builds: Vec<&'a str>) -> Self {
+    pub fn builds(mut self, builds: Vec<Nvr>) -> Self {
         self.builds = Some(builds);
         self
     }
@@ -251,14 +289,20 @@ impl<'a> UpdateQuery<'a> {
     }
 
     /// Restrict results to updates associated with the given CVE(s).
-    pub fn cves(mut self, cves: Vec<&'a str>) -> Self {
-        self.cves = Some(cves);
+    pub fn cves(mut self, cves: Vec<impl Into<String>>) -> Self {
+        self.cves = Some(cves.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Restrict the returned results to updates that were created from the given side tag.
+    pub fn from_side_tag(mut self, from_side_tag: impl Into<String>) -> Self {
+        self.from_side_tag = Some(from_side_tag.into());
         self
     }
 
     /// Restrict search to updates *like* the given argument (in the SQL sense).
-    pub fn like(mut self, like: &'a str) -> Self {
-        self.like = Some(like);
+    pub fn like(mut self, like: impl Into<String>) -> Self {
+        self.like = Some(like.into());
         self
     }
 
@@ -270,21 +314,21 @@ impl<'a> UpdateQuery<'a> {
 
     /// Restrict the returned results to updates which were modified
     /// before the given date and time.
-    pub fn modified_before(mut self, modified_before: &'a BodhiDate) -> Self {
-        self.modified_before = Some(modified_before);
+    pub fn modified_before(mut self, modified_before: &BodhiDate) -> Self {
+        self.modified_before = Some(modified_before.clone());
         self
     }
 
     /// Restrict the returned results to updates which were modified
     /// since the given date and time.
-    pub fn modified_since(mut self, modified_since: &'a BodhiDate) -> Self {
-        self.modified_since = Some(modified_since);
+    pub fn modified_since(mut self, modified_since: &BodhiDate) -> Self {
+        self.modified_since = Some(modified_since.clone());
         self
     }
 
     /// Restrict results to updates associated for the given package(s).
-    pub fn packages(mut self, packages: Vec<&'a str>) -> Self {
-        self.packages = Some(packages);
+    pub fn packages(mut self, packages: Vec<impl Into<String>>) -> Self {
+        self.packages = Some(packages.into_iter().map(Into::into).collect());
         self
     }
 
@@ -296,15 +340,15 @@ impl<'a> UpdateQuery<'a> {
 
     /// Restrict the returned results to updates which were pushed
     /// before the given date and time.
-    pub fn pushed_before(mut self, pushed_before: &'a BodhiDate) -> Self {
-        self.pushed_before = Some(pushed_before);
+    pub fn pushed_before(mut self, pushed_before: &BodhiDate) -> Self {
+        self.pushed_before = Some(pushed_before.clone());
         self
     }
 
     /// Restrict the returned results to updates which were pushed
     /// since the given date and time.
-    pub fn pushed_since(mut self, pushed_since: &'a BodhiDate) -> Self {
-        self.pushed_since = Some(pushed_since);
+    pub fn pushed_since(mut self, pushed_since: &BodhiDate) -> Self {
+        self.pushed_since = Some(pushed_since.clone());
         self
     }
 
@@ -321,8 +365,8 @@ impl<'a> UpdateQuery<'a> {
     }
 
     /// Restrict search to updates containing the given argument.
-    pub fn search(mut self, search: &'a str) -> Self {
-        self.search = Some(search);
+    pub fn search(mut self, search: impl Into<String>) -> Self {
+        self.search = Some(search.into());
         self
     }
 
@@ -340,15 +384,15 @@ impl<'a> UpdateQuery<'a> {
 
     /// Restrict the returned results to updates which were submitted
     /// before the given date and time.
-    pub fn submitted_before(mut self, submitted_before: &'a BodhiDate) -> Self {
-        self.submitted_before = Some(submitted_before);
+    pub fn submitted_before(mut self, submitted_before: &BodhiDate) -> Self {
+        self.submitted_before = Some(submitted_before.clone());
         self
     }
 
     /// Restrict the returned results to updates which were submitted
     /// since the given date and time.
-    pub fn submitted_since(mut self, submitted_since: &'a BodhiDate) -> Self {
-        self.submitted_since = Some(submitted_since);
+    pub fn submitted_since(mut self, submitted_since: &BodhiDate) -> Self {
+        self.submitted_since = Some(submitted_since.clone());
         self
     }
 
@@ -359,8 +403,8 @@ impl<'a> UpdateQuery<'a> {
     }
 
     /// Restrict results to updates matching the given update ID(s).
-    pub fn update_ids(mut self, update_ids: Vec<&'a str>) -> Self {
-        self.update_ids = Some(update_ids);
+    pub fn update_ids(mut self, update_ids: Vec<impl Into<UpdateID>>) -> Self {
+        self.update_ids = Some(update_ids.into_iter().map(Into::into).collect());
         self
     }
 
@@ -371,28 +415,83 @@ impl<'a> UpdateQuery<'a> {
     }
 
     /// Restrict results to updates associated with the given user(s).
-    pub fn users(mut self, users: Vec<&'a str>) -> Self {
-        self.users = Some(users);
+    pub fn users(mut self, users: Vec<impl Into<String>>) -> Self {
+        self.users = Some(users.into_iter().map(Into::into).collect());
         self
     }
 
+    /// This method parses a URL of the bodhi web interface or REST API for listing updates (for
+    /// example, `https://bodhi.fedoraproject.org/updates/?packages=rust&status=testing`) into the
+    /// equivalent query. Unknown query parameters and invalid values are rejected.
+    pub fn from_url(url: &str) -> Result<Self, UrlError> {
+        let url = collection_url(url, "updates")?;
+        Self::from_params(&url)
+    }
+
+    pub(crate) fn from_params(url: &Url) -> Result<Self, UrlError> {
+        let mut query = Self::new();
+
+        for (key, value) in query_params(url) {
+            match key.as_str() {
+                "active_releases" => query.active_releases = Some(parse_value(&key, &value)?),
+                "alias" => extend_list(&mut query.aliases, &key, &value)?,
+                "approved_before" => query.approved_before = Some(parse_value(&key, &value)?),
+                "approved_since" => query.approved_since = Some(parse_value(&key, &value)?),
+                "bugs" => extend_list(&mut query.bugs, &key, &value)?,
+                "builds" => extend_list(&mut query.builds, &key, &value)?,
+                "content_type" => query.content_type = Some(parse_enum(&key, &value)?),
+                "critpath" => query.critpath = Some(parse_value(&key, &value)?),
+                "cves" => extend_list(&mut query.cves, &key, &value)?,
+                "from_side_tag" => query.from_side_tag = Some(value),
+                "like" => query.like = Some(value),
+                "locked" => query.locked = Some(parse_value(&key, &value)?),
+                "modified_before" => query.modified_before = Some(parse_value(&key, &value)?),
+                "modified_since" => query.modified_since = Some(parse_value(&key, &value)?),
+                "packages" => extend_list(&mut query.packages, &key, &value)?,
+                "pushed" => query.pushed = Some(parse_value(&key, &value)?),
+                "pushed_before" => query.pushed_before = Some(parse_value(&key, &value)?),
+                "pushed_since" => query.pushed_since = Some(parse_value(&key, &value)?),
+                "releases" => extend_list(&mut query.releases, &key, &value)?,
+                "request" => query.request = Some(parse_enum(&key, &value)?),
+                "search" => query.search = Some(value),
+                "severity" => query.severity = Some(parse_enum(&key, &value)?),
+                "status" => query.status = Some(parse_enum(&key, &value)?),
+                "submitted_before" => query.submitted_before = Some(parse_value(&key, &value)?),
+                "submitted_since" => query.submitted_since = Some(parse_value(&key, &value)?),
+                "suggest" => query.suggest = Some(parse_enum(&key, &value)?),
+                "updateid" => extend_list(&mut query.update_ids, &key, &value)?,
+                "type" => query.update_type = Some(parse_enum(&key, &value)?),
+                "user" => extend_list(&mut query.users, &key, &value)?,
+                _ => return Err(unknown_parameter("UpdateQuery", key)),
+            }
+        }
+
+        Ok(query)
+    }
+
+    /// This method constructs the URL for viewing the results of this query on the given bodhi
+    /// instance (for example, in the web interface).
+    pub fn web_url(&self, bodhi: &BodhiService) -> Result<Url, QueryError> {
+        Ok(bodhi.web_url(&format!("/updates/?{}", serde_url_params::to_string(self)?)))
+    }
+
     /// Query the remote bodhi instance with the given parameters.
-    fn query(mut self, bodhi: &BodhiService) -> Result<Vec<Update>, QueryError> {
+    fn query(
+        mut self,
+        bodhi: &BodhiService,
+        mut skipped: Option<&mut Vec<SkippedItem>>,
+    ) -> Result<Vec<Update>, QueryError> {
         let mut updates: Vec<Update> = Vec::new();
         let mut page = 1;
 
         // initial progress: 0 out of some
-        if let Some(ref mut fun) = self.callback {
-            fun(0, 1);
-        }
+        self.callback.call(0, 1);
 
         loop {
             let query = self.page_query(page, DEFAULT_ROWS);
-            let result = query.query(bodhi)?;
+            let result = query.query(bodhi, skipped.as_deref_mut())?;
 
-            if let Some(ref mut fun) = self.callback {
-                fun(page, result.pages)
-            }
+            self.callback.call(page, result.pages);
 
             updates.extend(result.updates);
             page += 1;
@@ -409,28 +508,29 @@ impl<'a> UpdateQuery<'a> {
         UpdatePageQuery {
             active_releases: self.active_releases,
             aliases: self.aliases.as_ref(),
-            approved_before: self.approved_before,
-            approved_since: self.approved_since,
+            approved_before: self.approved_before.as_ref(),
+            approved_since: self.approved_since.as_ref(),
             bugs: self.bugs.as_ref(),
             builds: self.builds.as_ref(),
             content_type: self.content_type.as_ref(),
             critpath: self.critpath,
             cves: self.cves.as_ref(),
-            like: self.like,
+            from_side_tag: self.from_side_tag.as_deref(),
+            like: self.like.as_deref(),
             locked: self.locked,
-            modified_before: self.modified_before,
-            modified_since: self.modified_since,
+            modified_before: self.modified_before.as_ref(),
+            modified_since: self.modified_since.as_ref(),
             packages: self.packages.as_ref(),
             pushed: self.pushed,
-            pushed_before: self.pushed_before,
-            pushed_since: self.pushed_since,
+            pushed_before: self.pushed_before.as_ref(),
+            pushed_since: self.pushed_since.as_ref(),
             releases: self.releases.as_ref(),
             request: self.request.as_ref(),
-            search: self.search,
+            search: self.search.as_deref(),
             severity: self.severity.as_ref(),
             status: self.status.as_ref(),
-            submitted_before: self.submitted_before,
-            submitted_since: self.submitted_since,
+            submitted_before: self.submitted_before.as_ref(),
+            submitted_since: self.submitted_since.as_ref(),
             suggest: self.suggest.as_ref(),
             update_ids: self.update_ids.as_ref(),
             update_type: self.update_type.as_ref(),
@@ -442,8 +542,8 @@ impl<'a> UpdateQuery<'a> {
 }
 
 impl<'a> Query<Vec<Update>> for UpdateQuery<'a> {
-    fn query(self, bodhi: &BodhiService) -> Result<Vec<Update>, QueryError> {
-        UpdateQuery::query(self, bodhi)
+    fn query(self, bodhi: &BodhiService, skipped: Option<&mut Vec<SkippedItem>>) -> Result<Vec<Update>, QueryError> {
+        UpdateQuery::query(self, bodhi, skipped)
     }
 }
 
@@ -460,23 +560,24 @@ struct UpdateListPage {
 struct UpdatePageQuery<'a> {
     active_releases: Option<bool>,
     #[serde(rename = "alias")]
-    aliases: Option<&'a Vec<&'a str>>,
+    aliases: Option<&'a Vec<UpdateAlias>>,
     #[serde(with = "crate::option_bodhi_date_format_ref")]
     approved_before: Option<&'a BodhiDate>,
     #[serde(with = "crate::option_bodhi_date_format_ref")]
     approved_since: Option<&'a BodhiDate>,
-    bugs: Option<&'a Vec<u32>>,
-    builds: Option<&'a Vec<&'a str>>,
+    bugs: Option<&'a Vec<BugId>>,
+    builds: Option<&'a Vec<Nvr>>,
     content_type: Option<&'a ContentType>,
     critpath: Option<bool>,
-    cves: Option<&'a Vec<&'a str>>,
+    cves: Option<&'a Vec<String>>,
+    from_side_tag: Option<&'a str>,
     like: Option<&'a str>,
     locked: Option<bool>,
     #[serde(with = "crate::option_bodhi_date_format_ref")]
     modified_before: Option<&'a BodhiDate>,
     #[serde(with = "crate::option_bodhi_date_format_ref")]
     modified_since: Option<&'a BodhiDate>,
-    packages: Option<&'a Vec<&'a str>>,
+    packages: Option<&'a Vec<String>>,
     pushed: Option<bool>,
     #[serde(with = "crate::option_bodhi_date_format_ref")]
     pushed_before: Option<&'a BodhiDate>,
@@ -493,11 +594,11 @@ struct UpdatePageQuery<'a> {
     submitted_since: Option<&'a BodhiDate>,
     suggest: Option<&'a UpdateSuggestion>,
     #[serde(rename = "updateid")]
-    update_ids: Option<&'a Vec<&'a str>>,
+    update_ids: Option<&'a Vec<UpdateID>>,
     #[serde(rename = "type")]
     update_type: Option<&'a UpdateType>,
     #[serde(rename = "user")]
-    users: Option<&'a Vec<&'a str>>,
+    users: Option<&'a Vec<String>>,
 
     page: u32,
     rows_per_page: u32,
@@ -509,7 +610,7 @@ impl<'a> SinglePageQuery<UpdateListPage> for UpdatePageQuery<'a> {
     }
 
     fn parse(string: &str) -> Result<UpdateListPage, QueryError> {
-        let update_page: UpdateListPage = serde_json::from_str(string)?;
+        let update_page: UpdateListPage = from_json(string)?;
         Ok(update_page)
     }
 
@@ -518,4 +619,8 @@ impl<'a> SinglePageQuery<UpdateListPage> for UpdatePageQuery<'a> {
             error: ServiceError::EmptyResponseError,
         })
     }
+
+    fn page(&self) -> Option<u32> {
+        Some(self.page)
+    }
 }
@@ -0,0 +1,206 @@
+//! This module contains helpers for parsing URLs of the bodhi web interface and REST API back into
+//! the equivalent queries.
+//!
+//! ```
+//! # use bodhi::{BodhiResource, UpdateQuery};
+//! let query = UpdateQuery::from_url("https://bodhi.fedoraproject.org/updates/?packages=rust&status=testing");
+//! assert!(query.is_ok());
+//!
+//! match BodhiResource::parse("/updates/FEDORA-2019-cf87377f5f").unwrap() {
+//!     BodhiResource::Update(query) => println!("{:?}", query),
+//!     _ => unreachable!(),
+//! }
+//! ```
+
+use std::convert::TryFrom;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use url::Url;
+
+use crate::data::{StringEnum, UpdateID, FEDORA_BODHI_URL};
+use crate::error::UrlError;
+use crate::{
+    BuildNVRQuery,
+    BuildQuery,
+    CommentIDQuery,
+    CommentQuery,
+    OverrideNVRQuery,
+    OverrideQuery,
+    UpdateIDQuery,
+    UpdateQuery,
+    UserNameQuery,
+    UserQuery,
+};
+
+// These parameters are accepted in URLs, but have no effect, since all pages of results are always
+// returned, and the rendering of results is not relevant for queries.
+const IGNORED_PARAMETERS: [&str; 3] = ["chrome", "page", "rows_per_page"];
+
+/// This enum represents the bodhi resources that can be addressed by URLs, with the equivalent
+/// queries for fetching them.
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum BodhiResource {
+    /// single build, addressed by its NVR (`/builds/{nvr}`)
+    Build(BuildNVRQuery),
+    /// list of builds (`/builds/`)
+    Builds(BuildQuery<'static>),
+    /// single comment, addressed by its ID (`/comments/{id}`)
+    Comment(CommentIDQuery),
+    /// list of comments (`/comments/`)
+    Comments(CommentQuery<'static>),
+    /// single override, addressed by the NVR of its build (`/overrides/{nvr}`)
+    Override(OverrideNVRQuery),
+    /// list of overrides (`/overrides/`)
+    Overrides(OverrideQuery<'static>),
+    /// single update, addressed by its alias or ID (`/updates/{alias}`)
+    Update(UpdateIDQuery),
+    /// list of updates (`/updates/`)
+    Updates(UpdateQuery<'static>),
+    /// single user, addressed by their name (`/users/{name}`)
+    User(UserNameQuery),
+    /// list of users (`/users/`)
+    Users(UserQuery<'static>),
+}
+
+impl BodhiResource {
+    /// This method parses a URL of the bodhi web interface or REST API into the query for the
+    /// resource it points to. Both absolute URLs (on any bodhi instance) and absolute paths (like
+    /// `/updates/FEDORA-2019-cf87377f5f`) are accepted.
+    pub fn parse(url: &str) -> Result<Self, UrlError> {
+        let url = parse_url(url)?;
+        let segments = path_segments(&url);
+
+        match segments.as_slice() {
+            ["builds"] => Ok(BodhiResource::Builds(BuildQuery::from_params(&url)?)),
+            ["builds", nvr] => {
+                no_params(&url, "BuildNVRQuery")?;
+                Ok(BodhiResource::Build(BuildNVRQuery::new(&parse_value("nvr", nvr)?)))
+            },
+            ["comments"] => Ok(BodhiResource::Comments(CommentQuery::from_params(&url)?)),
+            ["comments", id] => {
+                no_params(&url, "CommentIDQuery")?;
+                Ok(BodhiResource::Comment(CommentIDQuery::new(parse_value("id", id)?)))
+            },
+            ["overrides"] => Ok(BodhiResource::Overrides(OverrideQuery::from_params(&url)?)),
+            ["overrides", nvr] => {
+                no_params(&url, "OverrideNVRQuery")?;
+                Ok(BodhiResource::Override(OverrideNVRQuery::new(&parse_value(
+                    "nvr", nvr,
+                )?)))
+            },
+            ["updates"] => Ok(BodhiResource::Updates(UpdateQuery::from_params(&url)?)),
+            ["updates", id] => {
+                no_params(&url, "UpdateIDQuery")?;
+                let id = UpdateID::try_from(*id).map_err(|error| invalid_value("id", error))?;
+                Ok(BodhiResource::Update(UpdateIDQuery::new(id)))
+            },
+            ["users"] => Ok(BodhiResource::Users(UserQuery::from_params(&url)?)),
+            ["users", name] => {
+                no_params(&url, "UserNameQuery")?;
+                Ok(BodhiResource::User(UserNameQuery::new(name)))
+            },
+            _ => Err(UrlError::UnsupportedPath {
+                path: url.path().to_owned(),
+            }),
+        }
+    }
+}
+
+pub(crate) fn parse_url(url: &str) -> Result<Url, UrlError> {
+    match Url::parse(url) {
+        Ok(url) => Ok(url),
+        Err(url::ParseError::RelativeUrlWithoutBase) => Ok(Url::parse(FEDORA_BODHI_URL)?.join(url)?),
+        Err(error) => Err(error.into()),
+    }
+}
+
+fn path_segments(url: &Url) -> Vec<&str> {
+    match url.path_segments() {
+        Some(segments) => segments.filter(|segment| !segment.is_empty()).collect(),
+        None => Vec::new(),
+    }
+}
+
+/// This function parses the given URL, and checks that it points to the given list of resources
+/// (for example, `/updates/`).
+pub(crate) fn collection_url(url: &str, collection: &str) -> Result<Url, UrlError> {
+    let url = parse_url(url)?;
+
+    if path_segments(&url) != [collection] {
+        return Err(UrlError::UnsupportedPath {
+            path: url.path().to_owned(),
+        });
+    }
+
+    Ok(url)
+}
+
+/// This function returns the relevant query parameters of the given URL. Parameters with empty
+/// values (which are sent by HTML forms for fields that were left empty) are skipped.
+pub(crate) fn query_params(url: &Url) -> impl Iterator<Item = (String, String)> + '_ {
+    url.query_pairs()
+        .filter(|(key, value)| !value.is_empty() && !IGNORED_PARAMETERS.contains(&key.as_ref()))
+        .map(|(key, value)| (key.into_owned(), value.into_owned()))
+}
+
+fn no_params(url: &Url, resource: &'static str) -> Result<(), UrlError> {
+    match query_params(url).next() {
+        Some((parameter, _)) => Err(unknown_parameter(resource, parameter)),
+        None => Ok(()),
+    }
+}
+
+pub(crate) fn unknown_parameter(resource: &'static str, parameter: String) -> UrlError {
+    UrlError::UnknownParameter { resource, parameter }
+}
+
+fn invalid_value(parameter: &str, error: impl Display) -> UrlError {
+    UrlError::InvalidValue {
+        parameter: parameter.to_owned(),
+        error: error.to_string(),
+    }
+}
+
+pub(crate) fn parse_value<T>(parameter: &str, value: &str) -> Result<T, UrlError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    value.parse().map_err(|error| invalid_value(parameter, error))
+}
+
+/// This function parses the value of a parameter that is represented by an enum. Unknown values
+/// are always rejected (regardless of the `strict-enums` feature), since they are most likely
+/// typos.
+pub(crate) fn parse_enum<T>(parameter: &str, value: &str) -> Result<T, UrlError>
+where
+    T: FromStr + StringEnum,
+    T::Err: Display,
+{
+    let parsed: T = parse_value(parameter, value)?;
+
+    if parsed.is_unknown() {
+        return Err(invalid_value(parameter, format!("Unknown value: {}", value)));
+    }
+
+    Ok(parsed)
+}
+
+/// This function parses a comma-separated list of values, and appends them to the given list.
+/// Parameters that can have multiple values can also be repeated in URLs.
+pub(crate) fn extend_list<T>(list: &mut Option<Vec<T>>, parameter: &str, value: &str) -> Result<(), UrlError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let list = list.get_or_insert_with(Vec::new);
+
+    for item in value.split(',').map(str::trim).filter(|item| !item.is_empty()) {
+        list.push(parse_value(parameter, item)?);
+    }
+
+    Ok(())
+}
@@ -12,10 +12,13 @@
 use std::fmt::{Debug, Formatter};
 
 use serde::{Deserialize, Serialize};
+use url::Url;
 
-use crate::error::{QueryError, ServiceError};
+use crate::error::{QueryError, ServiceError, SkippedItem, UrlError};
+use crate::query::json::from_json;
+use crate::query::urls::{collection_url, extend_list, query_params, unknown_parameter};
 use crate::service::DEFAULT_ROWS;
-use crate::{BodhiService, Query, SinglePageQuery, User};
+use crate::{BodhiService, Callback, Query, SinglePageQuery, UpdateAlias, User};
 
 /// Use this for querying bodhi for a specific user by their name. It will either return an
 /// `Ok(User)` matching the specified name, return `Ok(None)` if it doesn't exist, or return an
@@ -30,9 +33,9 @@ use crate::{BodhiService, Query, SinglePageQuery, User};
 /// ```
 ///
 /// API documentation: <https://bodhi.fedoraproject.org/docs/server_api/rest/users.html#service-0>
-#[derive(Debug)]
-pub struct UserNameQuery<'a> {
-    name: &'a str,
+#[derive(Clone, Debug)]
+pub struct UserNameQuery {
+    name: String,
 }
 
 #[derive(Debug, Deserialize)]
@@ -40,20 +43,27 @@ struct UserPage {
     user: User,
 }
 
-impl<'a> UserNameQuery<'a> {
+impl UserNameQuery {
     /// This method is the only way to create a new `UserNameQuery` instance.
-    pub fn new(name: &'a str) -> Self {
-        UserNameQuery { name }
+    pub fn new(name: impl AsRef<str>) -> Self {
+        UserNameQuery {
+            name: name.as_ref().to_owned(),
+        }
+    }
+
+    /// This method constructs the URL of the requested user on the given bodhi instance.
+    pub fn web_url(&self, bodhi: &BodhiService) -> Url {
+        bodhi.web_url(&format!("/users/{}", self.name))
     }
 }
 
-impl<'a> SinglePageQuery<Option<User>> for UserNameQuery<'a> {
+impl SinglePageQuery<Option<User>> for UserNameQuery {
     fn path(&self) -> Result<String, QueryError> {
         Ok(format!("/users/{}", self.name))
     }
 
     fn parse(string: &str) -> Result<Option<User>, QueryError> {
-        let user_page: UserPage = serde_json::from_str(string)?;
+        let user_page: UserPage = from_json(string)?;
         Ok(Some(user_page.user))
     }
 
@@ -62,9 +72,9 @@ impl<'a> SinglePageQuery<Option<User>> for UserNameQuery<'a> {
     }
 }
 
-impl<'a> Query<Option<User>> for UserNameQuery<'a> {
-    fn query(self, bodhi: &BodhiService) -> Result<Option<User>, QueryError> {
-        <Self as SinglePageQuery<Option<User>>>::query(self, bodhi)
+impl Query<Option<User>> for UserNameQuery {
+    fn query(self, bodhi: &BodhiService, skipped: Option<&mut Vec<SkippedItem>>) -> Result<Option<User>, QueryError> {
+        <Self as SinglePageQuery<Option<User>>>::query(self, bodhi, skipped)
     }
 }
 
@@ -73,6 +83,9 @@ impl<'a> Query<Option<User>> for UserNameQuery<'a> {
 /// users will be returned if any criteria match. This is consistent with both the web interface and
 /// REST API behavior.
 ///
+/// The query parameters (but not the progress callback) can be serialized and deserialized, using
+/// the same parameter names as the REST API.
+///
 /// ```
 /// # use bodhi::{BodhiServiceBuilder, UserQuery};
 /// let bodhi = BodhiServiceBuilder::default().build().unwrap();
@@ -81,17 +94,22 @@ impl<'a> Query<Option<User>> for UserNameQuery<'a> {
 /// let users = bodhi.query(UserQuery::new().groups(vec!["provenpackager"])).unwrap();
 /// ```
 ///
+/// Cloning a query does not clone its [`callback`](#method.callback): the clone sends the same
+/// requests, but it does not report any progress unless a new callback is set.
+///
 /// API documentation: <https://bodhi.fedoraproject.org/docs/server_api/rest/users.html#service-1>
-#[derive(Default)]
+#[derive(Clone, Default, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
 pub struct UserQuery<'a> {
-    groups: Option<Vec<&'a str>>,
-    like: Option<&'a str>,
-    name: Option<&'a str>,
-    search: Option<&'a str>,
-    updates: Option<Vec<&'a str>>,
+    groups: Option<Vec<String>>,
+    like: Option<String>,
+    name: Option<String>,
+    search: Option<String>,
+    updates: Option<Vec<UpdateAlias>>,
 
     /// optional callback function for reporting progress
-    callback: Option<Box<dyn FnMut(u32, u32) + 'a>>,
+    #[serde(skip)]
+    callback: Callback<'a>,
 }
 
 impl<'a> Debug for UserQuery<'a> {
@@ -113,27 +131,31 @@ impl<'a> UserQuery<'a> {
             name: None,
             search: None,
             updates: None,
-            callback: None,
+            callback: Callback::default(),
         }
     }
 
     /// Add a callback function for reporting back query progress for long-running queries.
     /// The function will be called with the current page and the total number of pages for
-    /// paginated queries.
-    pub fn callback(mut self, fun: impl Fn(u32, u32) + 'a) -> Self {
-        self.callback = Some(Box::new(fun));
+    /// paginated queries. The callback is the only part of a query that can borrow data, and it
+    /// must be `Send`, so the query can be sent to other threads.
+    ///
+    /// **Note**: The callback is *not* cloned together with the query, so clones of this query do
+    /// not report any progress.
+    pub fn callback(mut self, fun: impl FnMut(u32, u32) + Send + 'a) -> Self {
+        self.callback = Callback::new(fun);
         self
     }
 
     /// Restrict the returned results to members of the given group(s).
-    pub fn groups(mut self, groups: Vec<&'a str>) -> Self {
-        self.groups = Some(groups);
+    pub fn groups(mut self, groups: Vec<impl Into<String>>) -> Self {
+        self.groups = Some(groups.into_iter().map(Into::into).collect());
         self
     }
 
     /// Restrict search to users *like* the given argument (in the SQL sense).
-    pub fn like(mut self, like: &'a str) -> Self {
-        self.like = Some(like);
+    pub fn like(mut self, like: impl Into<String>) -> Self {
+        self.like = Some(like.into());
         self
     }
 
@@ -141,40 +163,71 @@ impl<'a> UserQuery<'a> {
     ///
     /// If this is the only required filter, consider using a
     /// [`UserNameQuery`](struct.UserNameQuery.html) instead.
-    pub fn name(mut self, name: &'a str) -> Self {
-        self.name = Some(name);
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
         self
     }
 
     /// Restrict search to users containing the given argument.
-    pub fn search(mut self, search: &'a str) -> Self {
-        self.search = Some(search);
+    pub fn search(mut self, search: impl Into<String>) -> Self {
+        self.search = Some(search.into());
         self
     }
 
     /// Restrict the returned results to users associated with the given update(s).
-    pub fn updates(mut self, updates: Vec<&'a str>) -> Self {
+    pub fn updates(mut self, updates: Vec<UpdateAlias>) -> Self {
         self.updates = Some(updates);
         self
     }
 
+    /// This method parses a URL of the bodhi web interface or REST API for listing users (for
+    /// example, `https://bodhi.fedoraproject.org/users/?groups=packager`) into the equivalent
+    /// query. Unknown query parameters and invalid values are rejected.
+    pub fn from_url(url: &str) -> Result<Self, UrlError> {
+        let url = collection_url(url, "users")?;
+        Self::from_params(&url)
+    }
+
+    pub(crate) fn from_params(url: &Url) -> Result<Self, UrlError> {
+        let mut query = Self::new();
+
+        for (key, value) in query_params(url) {
+            match key.as_str() {
+                "groups" => extend_list(&mut query.groups, &key, &value)?,
+                "like" => query.like = Some(value),
+                "name" => query.name = Some(value),
+                "search" => query.search = Some(value),
+                "updates" => extend_list(&mut query.updates, &key, &value)?,
+                _ => return Err(unknown_parameter("UserQuery", key)),
+            }
+        }
+
+        Ok(query)
+    }
+
+    /// This method constructs the URL for viewing the results of this query on the given bodhi
+    /// instance (for example, in the web interface).
+    pub fn web_url(&self, bodhi: &BodhiService) -> Result<Url, QueryError> {
+        Ok(bodhi.web_url(&format!("/users/?{}", serde_url_params::to_string(self)?)))
+    }
+
     /// Query the remote bodhi instance with the given parameters.
-    fn query(mut self, bodhi: &BodhiService) -> Result<Vec<User>, QueryError> {
+    fn query(
+        mut self,
+        bodhi: &BodhiService,
+        mut skipped: Option<&mut Vec<SkippedItem>>,
+    ) -> Result<Vec<User>, QueryError> {
         let mut users: Vec<User> = Vec::new();
         let mut page = 1;
 
         // initial progress: 0 out of some
-        if let Some(ref mut fun) = self.callback {
-            fun(0, 1);
-        }
+        self.callback.call(0, 1);
 
         loop {
             let query = self.page_query(page, DEFAULT_ROWS);
-            let result = query.query(bodhi)?;
+            let result = query.query(bodhi, skipped.as_deref_mut())?;
 
-            if let Some(ref mut fun) = self.callback {
-                fun(page, result.pages)
-            }
+            self.callback.call(page, result.pages);
 
             users.extend(result.users);
             page += 1;
@@ -190,9 +243,9 @@ impl<'a> UserQuery<'a> {
     fn page_query(&self, page: u32, rows_per_page: u32) -> UserPageQuery {
         UserPageQuery {
             groups: self.groups.as_ref(),
-            like: self.like.as_ref(),
-            name: self.name.as_ref(),
-            search: self.search.as_ref(),
+            like: self.like.as_deref(),
+            name: self.name.as_deref(),
+            search: self.search.as_deref(),
             updates: self.updates.as_ref(),
             page,
             rows_per_page,
@@ -201,8 +254,8 @@ impl<'a> UserQuery<'a> {
 }
 
 impl<'a> Query<Vec<User>> for UserQuery<'a> {
-    fn query(self, bodhi: &BodhiService) -> Result<Vec<User>, QueryError> {
-        UserQuery::query(self, bodhi)
+    fn query(self, bodhi: &BodhiService, skipped: Option<&mut Vec<SkippedItem>>) -> Result<Vec<User>, QueryError> {
+        UserQuery::query(self, bodhi, skipped)
     }
 }
 
@@ -217,11 +270,11 @@ struct UserListPage {
 
 #[derive(Debug, Serialize)]
 struct UserPageQuery<'a> {
-    groups: Option<&'a Vec<&'a str>>,
-    like: Option<&'a &'a str>,
-    name: Option<&'a &'a str>,
-    search: Option<&'a &'a str>,
-    updates: Option<&'a Vec<&'a str>>,
+    groups: Option<&'a Vec<String>>,
+    like: Option<&'a str>,
+    name: Option<&'a str>,
+    search: Option<&'a str>,
+    updates: Option<&'a Vec<UpdateAlias>>,
 
     page: u32,
     rows_per_page: u32,
@@ -233,7 +286,7 @@ impl<'a> SinglePageQuery<UserListPage> for UserPageQuery<'a> {
     }
 
     fn parse(string: &str) -> Result<UserListPage, QueryError> {
-        let user_page: UserListPage = serde_json::from_str(string)?;
+        let user_page: UserListPage = from_json(string)?;
         Ok(user_page)
     }
 
@@ -242,4 +295,8 @@ impl<'a> SinglePageQuery<UserListPage> for UserPageQuery<'a> {
             error: ServiceError::EmptyResponseError,
         })
     }
+
+    fn page(&self) -> Option<u32> {
+        Some(self.page)
+    }
 }
@@ -0,0 +1,128 @@
+//! The contents of this module can be used to query a bodhi instance for its server version and
+//! other information about the server.
+
+use serde::Deserialize;
+
+use crate::error::{QueryError, ServiceError, SkippedItem};
+use crate::query::json::from_json;
+use crate::{BodhiService, Query, ServerInfo, ServerVersion, SinglePageQuery};
+
+/// Use this for querying bodhi for the version of the server software. It will return either an
+/// `Ok(ServerVersion)`, or an `Err(QueryError)` if an error occurred.
+///
+/// Note that the result of this query is also cached by
+/// [`BodhiService::server_version`](../../service/struct.BodhiService.html#method.server_version),
+/// which should be preferred for repeated checks.
+///
+/// ```
+/// # use bodhi::{BodhiServiceBuilder, ServerVersionQuery};
+/// let bodhi = BodhiServiceBuilder::default().build().unwrap();
+///
+/// # #[cfg(feature = "online-tests")]
+/// let version = bodhi.query(ServerVersionQuery::new()).unwrap();
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ServerVersionQuery {}
+
+#[derive(Debug, Deserialize)]
+struct ServerVersionPage {
+    version: String,
+}
+
+impl ServerVersionQuery {
+    /// This method creates a new [`ServerVersionQuery`](struct.ServerVersionQuery.html).
+    pub fn new() -> Self {
+        ServerVersionQuery {}
+    }
+}
+
+impl SinglePageQuery<ServerVersion> for ServerVersionQuery {
+    fn path(&self) -> Result<String, QueryError> {
+        Ok(String::from("/api_version"))
+    }
+
+    fn parse(string: &str) -> Result<ServerVersion, QueryError> {
+        let page: ServerVersionPage = from_json(string)?;
+
+        match page.version.parse() {
+            Ok(version) => Ok(version),
+            Err(error) => Err(QueryError::InvalidDataError {
+                error: format!("{}", error),
+            }),
+        }
+    }
+
+    fn missing() -> Result<ServerVersion, QueryError> {
+        Err(QueryError::ServiceError {
+            error: ServiceError::EmptyResponseError,
+        })
+    }
+}
+
+impl Query<ServerVersion> for ServerVersionQuery {
+    fn query(self, bodhi: &BodhiService, skipped: Option<&mut Vec<SkippedItem>>) -> Result<ServerVersion, QueryError> {
+        <Self as SinglePageQuery<ServerVersion>>::query(self, bodhi, skipped)
+    }
+}
+
+/// Use this for querying bodhi for information about the server: the root URL of its REST API, its
+/// version, and the notice on its front page. It will return either an `Ok(ServerInfo)`, or an
+/// `Err(QueryError)` if an error occurred.
+///
+/// Note that the result of this query is also cached by
+/// [`BodhiService::server_info`](../../service/struct.BodhiService.html#method.server_info), which
+/// should be preferred for repeated checks.
+///
+/// ```
+/// # use bodhi::{BodhiServiceBuilder, ServerInfoQuery};
+/// let bodhi = BodhiServiceBuilder::default().build().unwrap();
+///
+/// # #[cfg(feature = "online-tests")]
+/// let info = bodhi.query(ServerInfoQuery::new()).unwrap();
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ServerInfoQuery {}
+
+impl ServerInfoQuery {
+    /// This method creates a new [`ServerInfoQuery`](struct.ServerInfoQuery.html).
+    pub fn new() -> Self {
+        ServerInfoQuery {}
+    }
+}
+
+impl Query<ServerInfo> for ServerInfoQuery {
+    fn query(self, bodhi: &BodhiService, skipped: Option<&mut Vec<SkippedItem>>) -> Result<ServerInfo, QueryError> {
+        let version = bodhi.server_version()?;
+        let frontpage = <FrontpageQuery as SinglePageQuery<FrontpagePage>>::query(FrontpageQuery {}, bodhi, skipped)?;
+
+        Ok(ServerInfo {
+            api_root: bodhi.url().clone(),
+            version,
+            notice: frontpage.frontpage_notice.filter(|notice| !notice.trim().is_empty()),
+        })
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct FrontpagePage {
+    #[serde(default)]
+    frontpage_notice: Option<String>,
+}
+
+#[derive(Debug)]
+struct FrontpageQuery {}
+
+impl SinglePageQuery<FrontpagePage> for FrontpageQuery {
+    fn path(&self) -> Result<String, QueryError> {
+        Ok(String::from("/"))
+    }
+
+    fn parse(string: &str) -> Result<FrontpagePage, QueryError> {
+        // older versions of bodhi only render the front page as HTML, which has no notice
+        Ok(from_json(string).unwrap_or_default())
+    }
+
+    fn missing() -> Result<FrontpagePage, QueryError> {
+        Ok(FrontpagePage::default())
+    }
+}
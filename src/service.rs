@@ -1,16 +1,20 @@
 //! This module contains the structures and methods to interact with a (remote) bodhi server
 //! instance.
 
-use std::fmt::{Debug, Formatter};
+use std::fmt::{Debug, Display, Formatter};
+use std::path::PathBuf;
+use std::sync::Mutex;
 use std::time::Duration;
 
+use chrono::Utc;
 use fedora::{AnonymousSessionBuilder, OpenIDSessionBuilder, Session};
 use reqwest::blocking::Response;
+use reqwest::header::ACCEPT;
 use url::Url;
 
 use crate::data::{FEDORA_BODHI_STG_URL, FEDORA_BODHI_URL};
-use crate::error::{QueryError, ServiceError};
-use crate::{Create, Edit, Query};
+use crate::error::{Lenient, QueryError, ServiceError};
+use crate::{Create, Edit, Query, ServerInfo, ServerInfoQuery, ServerVersion, ServerVersionQuery};
 
 /// This constant defines how many items are queried every time for multi-page queries. The
 /// server-side maximum is usually 100, the default is 20, and 50 seems a good compromise for speed.
@@ -26,6 +30,9 @@ const REQUEST_RETRIES: usize = 3;
 /// Specify a sane default user agent for bodhi-rs.
 const USER_AGENT: &str = "bodhi-rs";
 
+/// placeholder for the CSRF token in requests that are returned by dry runs
+const REDACTED_CSRF_TOKEN: &str = "<redacted>";
+
 #[derive(Debug)]
 #[allow(clippy::upper_case_acronyms)]
 enum BodhiServiceType {
@@ -65,6 +72,7 @@ pub struct BodhiServiceBuilder<'a> {
     url: String,
     timeout: Option<Duration>,
     retries: Option<usize>,
+    dump_directory: Option<PathBuf>,
 }
 
 #[derive(Debug)]
@@ -127,6 +135,7 @@ impl<'a> BodhiServiceBuilder<'a> {
             url: FEDORA_BODHI_URL.to_string(),
             timeout: None,
             retries: None,
+            dump_directory: None,
         }
     }
 
@@ -138,6 +147,7 @@ impl<'a> BodhiServiceBuilder<'a> {
             url: FEDORA_BODHI_STG_URL.to_string(),
             timeout: None,
             retries: None,
+            dump_directory: None,
         }
     }
 
@@ -150,6 +160,7 @@ impl<'a> BodhiServiceBuilder<'a> {
             url,
             timeout: None,
             retries: None,
+            dump_directory: None,
         }
     }
 
@@ -165,6 +176,15 @@ impl<'a> BodhiServiceBuilder<'a> {
         self
     }
 
+    /// This method can be used to set a directory where the JSON payloads of responses that could
+    /// not be deserialized are saved (one file per response, or per skipped item of lenient
+    /// queries), which is useful for investigating changes in the JSON format of the server.
+    /// The directory is created if it does not exist. Failures to write the files are ignored.
+    pub fn dump_directory(mut self, directory: impl Into<PathBuf>) -> Self {
+        self.dump_directory = Some(directory.into());
+        self
+    }
+
     /// This method can be used to set credentials for authenticating with the fedora OpenID
     /// endpoint, so the resulting [`BodhiService`](struct.BodhiService.html) can be used to
     /// send authenticated requests for creating and editing things on the server.
@@ -193,7 +213,7 @@ impl<'a> BodhiServiceBuilder<'a> {
         let login_url = url.join("/login")?;
         let user_agent = USER_AGENT.to_string();
 
-        let session: Box<dyn Session> = if let Some(auth) = self.authentication {
+        let session: Box<dyn Session + Send + Sync> = if let Some(auth) = self.authentication {
             match self.service_type {
                 BodhiServiceType::DEFAULT => Box::new(
                     OpenIDSessionBuilder::default(login_url, auth.username, auth.password)
@@ -227,7 +247,53 @@ impl<'a> BodhiServiceBuilder<'a> {
             )
         };
 
-        Ok(BodhiService { url, session, retries })
+        Ok(BodhiService {
+            url,
+            session,
+            retries,
+            server_version: Mutex::new(None),
+            server_info: Mutex::new(None),
+            dump_directory: self.dump_directory,
+        })
+    }
+}
+
+/// This struct represents a POST request for creating or editing things on bodhi. It is returned by
+/// the "dry-run" methods of [`BodhiService`](struct.BodhiService.html) instead of sending the
+/// request to the server.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PostRequest {
+    /// HTTP method of the request (always `POST`)
+    pub method: &'static str,
+    /// path of the API endpoint, relative to the base URL of the bodhi instance
+    pub path: String,
+    /// JSON-encoded request body
+    pub body: String,
+}
+
+impl PostRequest {
+    pub(crate) fn new(path: String, body: String) -> Self {
+        PostRequest {
+            method: "POST",
+            path,
+            body,
+        }
+    }
+
+    /// This method returns the request body as a JSON value.
+    pub fn json(&self) -> Result<serde_json::Value, serde_json::Error> {
+        serde_json::from_str(&self.body)
+    }
+}
+
+impl Display for PostRequest {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        writeln!(f, "{} {}", self.method, self.path)?;
+
+        match self.json().and_then(|json| serde_json::to_string_pretty(&json)) {
+            Ok(body) => write!(f, "{}", body),
+            Err(_) => write!(f, "{}", self.body),
+        }
     }
 }
 
@@ -236,8 +302,13 @@ impl<'a> BodhiServiceBuilder<'a> {
 /// submit to, and receive from - the service.
 pub struct BodhiService {
     url: Url,
-    session: Box<dyn Session>,
+    session: Box<dyn Session + Send + Sync>,
     retries: usize,
+    /// cached version of the remote server, populated on first use
+    server_version: Mutex<Option<ServerVersion>>,
+    /// cached information about the remote server, populated on first use
+    server_info: Mutex<Option<ServerInfo>>,
+    dump_directory: Option<PathBuf>,
 }
 
 impl Debug for BodhiService {
@@ -251,7 +322,14 @@ impl BodhiService {
         let url = self.url.join(path)?;
 
         let qf = || {
-            match self.session.session().get(url.clone()).send() {
+            // bodhi serves both HTML and JSON from the same endpoints, so JSON has to be requested
+            match self
+                .session
+                .session()
+                .get(url.clone())
+                .header(ACCEPT, "application/json")
+                .send()
+            {
                 Ok(response) => {
                     match response.content_length() {
                         Some(_len) => {
@@ -304,9 +382,133 @@ impl BodhiService {
         Ok(response)
     }
 
+    /// This method returns the base URL of this bodhi instance.
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    // The web interface uses the same paths as the REST API, since bodhi serves both HTML and JSON
+    // from the same endpoints (depending on the "Accept" header of the request).
+    pub(crate) fn web_url(&self, path: &str) -> Url {
+        self.url
+            .join(path)
+            .expect("Failed to join the base URL with an absolute path, this should not happen.")
+    }
+
+    /// This method saves the given payload to a new file in the dump directory (if it was
+    /// configured), and returns the path of the file. The file name is derived from the endpoint,
+    /// page number, item index, and the current time.
+    pub(crate) fn dump_payload(
+        &self,
+        endpoint: &str,
+        page: Option<u32>,
+        item: Option<usize>,
+        payload: &str,
+    ) -> Option<PathBuf> {
+        let directory = self.dump_directory.as_ref()?;
+
+        let resource: String = endpoint
+            .split('?')
+            .next()
+            .unwrap_or_default()
+            .split('/')
+            .filter(|segment| !segment.is_empty())
+            .collect::<Vec<&str>>()
+            .join("-");
+
+        let mut name = if resource.is_empty() {
+            String::from("response")
+        } else {
+            resource
+        };
+        if let Some(page) = page {
+            name.push_str(&format!("-page{}", page));
+        }
+        if let Some(item) = item {
+            name.push_str(&format!("-item{}", item));
+        }
+        name.push_str(&format!("-{}.json", Utc::now().format("%Y%m%dT%H%M%S%.6f")));
+
+        let path = directory.join(name);
+
+        std::fs::create_dir_all(directory).ok()?;
+        std::fs::write(&path, payload).ok()?;
+
+        Some(path)
+    }
+
+    /// This method returns the version of the remote bodhi server. The version is only queried
+    /// once, and the result is cached for the lifetime of this `BodhiService` instance.
+    pub fn server_version(&self) -> Result<ServerVersion, QueryError> {
+        let mut cached = self.server_version.lock().unwrap_or_else(|error| error.into_inner());
+
+        match *cached {
+            Some(version) => Ok(version),
+            None => {
+                let version = self.query(ServerVersionQuery::new())?;
+                *cached = Some(version);
+                Ok(version)
+            },
+        }
+    }
+
+    /// This method returns information about the remote bodhi server (the root URL of its REST API,
+    /// its version, and the notice on its front page). The information is only queried once, and
+    /// the result is cached for the lifetime of this `BodhiService` instance.
+    pub fn server_info(&self) -> Result<ServerInfo, QueryError> {
+        let mut cached = self.server_info.lock().unwrap_or_else(|error| error.into_inner());
+
+        match &*cached {
+            Some(info) => Ok(info.clone()),
+            None => {
+                let info = self.query(ServerInfoQuery::new())?;
+                *cached = Some(info.clone());
+                Ok(info)
+            },
+        }
+    }
+
+    /// This method checks whether the remote bodhi server is running at least the given version.
+    /// If it is older, an `UnsupportedServerVersion` error is returned.
+    ///
+    /// ```
+    /// # use bodhi::{BodhiServiceBuilder, ServerVersion};
+    /// let bodhi = BodhiServiceBuilder::default().build().unwrap();
+    ///
+    /// # #[cfg(feature = "online-tests")]
+    /// bodhi.require_version(ServerVersion::new(5, 0, 0)).unwrap();
+    /// ```
+    pub fn require_version(&self, required: ServerVersion) -> Result<(), QueryError> {
+        check_version(self.server_version()?, required)
+    }
+
     /// This method is used for GET methods to query things on the bodhi instance.
     pub fn query<T>(&self, query: impl Query<T>) -> Result<T, QueryError> {
-        Query::query(query, self)
+        Query::query(query, self, None)
+    }
+
+    /// This method is used for GET methods to query things on the bodhi instance, but items of
+    /// paginated results that cannot be deserialized are skipped instead of failing the whole
+    /// query. The skipped items are returned together with the result.
+    ///
+    /// ```
+    /// # use bodhi::{BodhiServiceBuilder, BuildQuery};
+    /// let bodhi = BodhiServiceBuilder::default().build().unwrap();
+    ///
+    /// # #[cfg(feature = "online-tests")]
+    /// # {
+    /// let builds = bodhi.query_lenient(BuildQuery::new().nvr("rust-1.40.0-1.fc31")).unwrap();
+    ///
+    /// for item in &builds.skipped {
+    ///     eprintln!("{}", item);
+    /// }
+    /// # }
+    /// ```
+    pub fn query_lenient<T>(&self, query: impl Query<T>) -> Result<Lenient<T>, QueryError> {
+        let mut skipped = Vec::new();
+        let result = Query::query(query, self, Some(&mut skipped))?;
+
+        Ok(Lenient { result, skipped })
     }
 
     /// This method is used for POST methods to create new things on the bodhi instance.
@@ -318,4 +520,41 @@ impl BodhiService {
     pub fn edit<T>(&self, editor: &dyn Edit<T>) -> Result<T, QueryError> {
         Edit::edit(editor, self)
     }
+
+    /// This method validates the data for creating new things on the bodhi instance, and returns
+    /// the POST request that [`create`](#method.create) would send, without sending it. The CSRF
+    /// token is not queried from the server, and is redacted from the request body.
+    ///
+    /// ```
+    /// # use bodhi::{BodhiServiceBuilder, CommentBuilder, Karma};
+    /// let bodhi = BodhiServiceBuilder::default().build().unwrap();
+    ///
+    /// let comment = CommentBuilder::new("FEDORA-2019-cf87377f5f").karma(Karma::Positive);
+    /// let request = bodhi.dry_run_create(&comment).unwrap();
+    ///
+    /// assert_eq!(request.path, "/comments/");
+    /// assert_eq!(request.json().unwrap()["csrf_token"], "<redacted>");
+    /// ```
+    pub fn dry_run_create<T>(&self, creator: &dyn Create<T>) -> Result<PostRequest, QueryError> {
+        creator.validate()?;
+        creator.request(REDACTED_CSRF_TOKEN)
+    }
+
+    /// This method validates the data for editing existing things on the bodhi instance, and
+    /// returns the POST request that [`edit`](#method.edit) would send, without sending it. The
+    /// CSRF token is not queried from the server, and is redacted from the request body.
+    pub fn dry_run_edit<T>(&self, editor: &dyn Edit<T>) -> Result<PostRequest, QueryError> {
+        editor.validate()?;
+        editor.request(REDACTED_CSRF_TOKEN)
+    }
+}
+
+/// This function returns an `UnsupportedServerVersion` error if the given server version is older
+/// than the required version.
+pub(crate) fn check_version(version: ServerVersion, required: ServerVersion) -> Result<(), QueryError> {
+    if version >= required {
+        Ok(())
+    } else {
+        Err(QueryError::UnsupportedServerVersion { version, required })
+    }
 }
@@ -0,0 +1,139 @@
+use std::convert::TryFrom;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use crate::batch::run;
+use crate::error::QueryError;
+use crate::{BatchOptions, BatchResult, BodhiDate, BodhiServiceBuilder, CommentBuilder, OverrideBuilder};
+
+fn process(item: &i32) -> Result<i32, QueryError> {
+    if *item < 0 {
+        Err(QueryError::InvalidDataError {
+            error: format!("negative item: {}", item),
+        })
+    } else {
+        Ok(item * 2)
+    }
+}
+
+fn outcome(results: &[BatchResult<i32>]) -> Vec<Option<i32>> {
+    results
+        .iter()
+        .map(|result| match result {
+            BatchResult::Succeeded(value) => Some(*value),
+            BatchResult::Failed(_) => Some(-1),
+            BatchResult::Skipped => None,
+        })
+        .collect()
+}
+
+#[test]
+fn batch_run_ordered() {
+    let items: Vec<i32> = (0..20).collect();
+    let refs: Vec<&i32> = items.iter().collect();
+
+    let results = run(&refs, &BatchOptions::new().concurrency(4), process);
+    let expected: Vec<Option<i32>> = (0..20).map(|item| Some(item * 2)).collect();
+
+    assert_eq!(outcome(&results), expected);
+}
+
+#[test]
+fn batch_run_stop_on_error() {
+    let items = [1, -2, 3, 4];
+    let refs: Vec<&i32> = items.iter().collect();
+
+    let results = run(&refs, &BatchOptions::new(), process);
+    assert_eq!(outcome(&results), vec![Some(2), Some(-1), None, None]);
+}
+
+#[test]
+fn batch_run_continue_on_error() {
+    let items = [1, -2, 3, 4];
+    let refs: Vec<&i32> = items.iter().collect();
+
+    let results = run(&refs, &BatchOptions::new().continue_on_error(true), process);
+    assert_eq!(outcome(&results), vec![Some(2), Some(-1), Some(6), Some(8)]);
+}
+
+#[test]
+fn batch_run_concurrency() {
+    let items = [0; 8];
+    let refs: Vec<&i32> = items.iter().collect();
+
+    let running = AtomicUsize::new(0);
+    let maximum = AtomicUsize::new(0);
+
+    let results = run(&refs, &BatchOptions::new().concurrency(3), |item| {
+        let current = running.fetch_add(1, Ordering::SeqCst) + 1;
+        maximum.fetch_max(current, Ordering::SeqCst);
+        std::thread::sleep(Duration::from_millis(20));
+        running.fetch_sub(1, Ordering::SeqCst);
+        process(item)
+    });
+
+    assert!(results.iter().all(BatchResult::is_success));
+    assert!(maximum.load(Ordering::SeqCst) <= 3);
+    assert!(maximum.load(Ordering::SeqCst) > 1);
+}
+
+#[test]
+fn batch_run_rate_limit() {
+    let items = [0; 4];
+    let refs: Vec<&i32> = items.iter().collect();
+
+    let start = Instant::now();
+    let options = BatchOptions::new().concurrency(4).rate_limit(Duration::from_millis(50));
+    run(&refs, &options, process);
+
+    // the last request can only start after three intervals
+    assert!(start.elapsed() >= Duration::from_millis(150));
+}
+
+#[test]
+fn batch_create_invalid_stops() {
+    let bodhi = BodhiServiceBuilder::default().build().unwrap();
+
+    let date = BodhiDate::try_from("2999-01-01").unwrap();
+    let overrides = vec![
+        OverrideBuilder::new("rust-1.40.0-1.fc31", "notes", &date),
+        OverrideBuilder::new("rust-1.40.0-1.fc32", "", &date),
+    ];
+
+    // no requests are sent if any item is invalid, and the batch does not continue on errors
+    let report = bodhi.create_many(&overrides, &BatchOptions::new()).unwrap();
+
+    assert!(!report.is_success());
+    assert_eq!(report.skipped().collect::<Vec<usize>>(), vec![0]);
+
+    let failed: Vec<usize> = report.failed().map(|(index, _)| index).collect();
+    assert_eq!(failed, vec![1]);
+    assert!(matches!(
+        report.results[1],
+        BatchResult::Failed(QueryError::ValidationError { .. })
+    ));
+}
+
+#[test]
+fn batch_create_all_invalid() {
+    let bodhi = BodhiServiceBuilder::default().build().unwrap();
+
+    let comments = vec![CommentBuilder::new("FEDORA-2019-cf87377f5f").text("")];
+    let options = BatchOptions::new().continue_on_error(true);
+
+    let report = bodhi.create_many(&comments, &options).unwrap();
+
+    assert_eq!(report.failed().count(), 1);
+    assert_eq!(report.succeeded().count(), 0);
+}
+
+#[test]
+fn batch_create_empty() {
+    let bodhi = BodhiServiceBuilder::default().build().unwrap();
+
+    let overrides: Vec<OverrideBuilder> = Vec::new();
+    let report = bodhi.create_many(&overrides, &BatchOptions::new()).unwrap();
+
+    assert!(report.is_success());
+    assert!(report.results.is_empty());
+}
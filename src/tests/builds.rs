@@ -1,4 +1,4 @@
-use super::bodhi_init;
+use super::{alias, bodhi_init, nvr};
 
 use crate::{Build, BuildNVRQuery, BuildQuery, FedoraRelease};
 
@@ -37,14 +37,14 @@ fn query_sanity_updates() {
     let bodhi = bodhi_init();
 
     let builds_one: Vec<Build> = bodhi
-        .query(BuildQuery::new().updates(vec!["FEDORA-2019-cf87377f5f"]))
+        .query(BuildQuery::new().updates(vec![alias("FEDORA-2019-cf87377f5f")]))
         .unwrap();
     let builds_two: Vec<Build> = bodhi
-        .query(BuildQuery::new().updates(vec!["FEDORA-2019-24c9d17287"]))
+        .query(BuildQuery::new().updates(vec![alias("FEDORA-2019-24c9d17287")]))
         .unwrap();
 
     let both_builds: Vec<Build> = bodhi
-        .query(BuildQuery::new().updates(vec!["FEDORA-2019-cf87377f5f", "FEDORA-2019-24c9d17287"]))
+        .query(BuildQuery::new().updates(vec![alias("FEDORA-2019-cf87377f5f"), alias("FEDORA-2019-24c9d17287")]))
         .unwrap();
 
     assert_eq!(both_builds.len(), builds_one.len() + builds_two.len())
@@ -54,7 +54,7 @@ fn query_sanity_updates() {
 fn nvr_query_some() {
     let bodhi = bodhi_init();
 
-    let build: Option<Build> = bodhi.query(BuildNVRQuery::new("rust-1.34.2-1.fc30")).unwrap();
+    let build: Option<Build> = bodhi.query(BuildNVRQuery::new(&nvr("rust-1.34.2-1.fc30"))).unwrap();
 
     assert!(build.is_some());
 }
@@ -63,7 +63,9 @@ fn nvr_query_some() {
 fn nvr_query_none() {
     let bodhi = bodhi_init();
 
-    let build: Option<Build> = bodhi.query(BuildNVRQuery::new("this-doesnt-exist-1-1.fc30")).unwrap();
+    let build: Option<Build> = bodhi
+        .query(BuildNVRQuery::new(&nvr("this-doesnt-exist-1-1.fc30")))
+        .unwrap();
 
     assert!(build.is_none());
 }
@@ -1,4 +1,4 @@
-use super::bodhi_init;
+use super::{alias, bodhi_init};
 
 use crate::{Comment, CommentIDQuery, CommentQuery};
 
@@ -21,14 +21,14 @@ fn query_sanity_updates() {
     let bodhi = bodhi_init();
 
     let commis_one: Vec<Comment> = bodhi
-        .query(CommentQuery::new().updates(vec!["FEDORA-2019-cf87377f5f"]))
+        .query(CommentQuery::new().updates(vec![alias("FEDORA-2019-cf87377f5f")]))
         .unwrap();
     let commis_two: Vec<Comment> = bodhi
-        .query(CommentQuery::new().updates(vec!["FEDORA-2019-24c9d17287"]))
+        .query(CommentQuery::new().updates(vec![alias("FEDORA-2019-24c9d17287")]))
         .unwrap();
 
     let both_commis: Vec<Comment> = bodhi
-        .query(CommentQuery::new().updates(vec!["FEDORA-2019-cf87377f5f", "FEDORA-2019-24c9d17287"]))
+        .query(CommentQuery::new().updates(vec![alias("FEDORA-2019-cf87377f5f"), alias("FEDORA-2019-24c9d17287")]))
         .unwrap();
 
     assert_eq!(both_commis.len(), commis_one.len() + commis_two.len())
@@ -1,6 +1,6 @@
 use super::bodhi_init;
 
-use crate::ComposeQuery;
+use crate::{ComposeQuery, ComposeRequest};
 
 #[test]
 fn deserialize() {
@@ -9,3 +9,15 @@ fn deserialize() {
     // query and deserialize currently active composes
     bodhi.query(ComposeQuery::new()).unwrap();
 }
+
+#[test]
+fn deserialize_filtered() {
+    let bodhi = bodhi_init();
+
+    // query and deserialize currently active composes, and check that filters are applied
+    let composes = bodhi
+        .query(ComposeQuery::new().request(ComposeRequest::Stable))
+        .unwrap();
+
+    assert!(composes.iter().all(|c| c.request == ComposeRequest::Stable));
+}
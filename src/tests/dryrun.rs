@@ -0,0 +1,66 @@
+use std::convert::TryFrom;
+
+use super::test_override;
+use crate::error::{QueryError, ValidationProblem};
+use crate::{BodhiDate, BodhiServiceBuilder, OverrideBuilder, UpdateBuilder, UpdateSeverity, UpdateType};
+
+#[test]
+fn dry_run_create_update() {
+    let bodhi = BodhiServiceBuilder::default().build().unwrap();
+
+    let builds = ["rust-1.40.0-1.fc31"];
+    let builder = UpdateBuilder::from_builds(&builds, "Update to rust 1.40.0.")
+        .bugs(1234567)
+        .update_type(UpdateType::Security)
+        .severity(UpdateSeverity::High)
+        .stable_karma(3);
+
+    let request = bodhi.dry_run_create(&builder).unwrap();
+    assert_eq!(request.method, "POST");
+    assert_eq!(request.path, "/updates/");
+
+    let json = request.json().unwrap();
+    assert_eq!(json["builds"], serde_json::json!(["rust-1.40.0-1.fc31"]));
+    assert_eq!(json["bugs"], serde_json::json!(["1234567"]));
+    assert_eq!(json["type"], "security");
+    assert_eq!(json["stable_karma"], 3);
+    assert_eq!(json["csrf_token"], "<redacted>");
+
+    assert!(request.to_string().starts_with("POST /updates/\n{\n"));
+}
+
+#[test]
+fn dry_run_create_invalid() {
+    let bodhi = BodhiServiceBuilder::default().build().unwrap();
+
+    let date = BodhiDate::try_from("2000-01-01").unwrap();
+    let builder = OverrideBuilder::new("rust-1.40.0-1.fc31", "notes", &date);
+
+    match bodhi.dry_run_create(&builder) {
+        Err(QueryError::ValidationError { error }) => assert_eq!(
+            error.problems,
+            vec![ValidationProblem::ExpirationInPast { expiration_date: date }]
+        ),
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+#[test]
+fn dry_run_edit_override() {
+    let bodhi = BodhiServiceBuilder::default().build().unwrap();
+
+    let over_ride = test_override("2000-01-01 00:00:00");
+    let date = BodhiDate::try_from("2999-01-01").unwrap();
+
+    let request = bodhi
+        .dry_run_edit(&over_ride.edit().notes("new notes").expiration_date(&date))
+        .unwrap();
+    assert_eq!(request.path, "/overrides/");
+
+    let json = request.json().unwrap();
+    assert_eq!(json["nvr"], "rust-1.40.0-1.fc31");
+    assert_eq!(json["edited"], "rust-1.40.0-1.fc31");
+    assert_eq!(json["notes"], "new notes");
+    assert_eq!(json["expiration_date"], "2999-01-01 00:00:00");
+    assert_eq!(json["csrf_token"], "<redacted>");
+}
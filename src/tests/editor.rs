@@ -0,0 +1,111 @@
+use std::convert::TryFrom;
+
+use super::test_update;
+use crate::edit::updates::check_unmodified;
+use crate::error::{QueryError, ValidationProblem};
+use crate::{BodhiDate, BodhiServiceBuilder, FieldChange, UpdateType, Validate};
+
+#[test]
+fn editor_diff() {
+    let update = test_update(&["rust-1.40.0-1.fc31"], None);
+
+    let editor = update
+        .edit()
+        .notes("Update to rust 1.40.0, with fixes.")
+        .add_build("cargo-c-0.5.0-1.fc31")
+        .add_bug(7654321)
+        .stable_karma(3)
+        .update_type(UpdateType::BugFix);
+
+    let diff = editor.diff();
+    let fields: Vec<&str> = diff.iter().map(|change| change.field).collect();
+
+    // stable karma was set to its current value
+    assert_eq!(fields, vec!["bugs", "builds", "notes", "type"]);
+
+    assert_eq!(
+        diff[3],
+        FieldChange {
+            field: "type",
+            old: serde_json::json!("enhancement"),
+            new: serde_json::json!("bugfix"),
+        }
+    );
+    assert_eq!(diff[3].to_string(), r#"type: "enhancement" → "bugfix""#);
+    assert_eq!(
+        diff[1].new,
+        serde_json::json!(["rust-1.40.0-1.fc31", "cargo-c-0.5.0-1.fc31"])
+    );
+
+    assert!(update.edit().diff().is_empty());
+}
+
+#[test]
+fn editor_unchanged_fields() {
+    let bodhi = BodhiServiceBuilder::default().build().unwrap();
+    let update = test_update(&["rust-1.40.0-1.fc31"], None);
+
+    let request = bodhi.dry_run_edit(&update.edit().notes("new notes")).unwrap();
+    let json = request.json().unwrap();
+
+    // unchanged fields are submitted with their current values
+    assert_eq!(json["edited"], "FEDORA-2019-cf87377f5f");
+    assert_eq!(json["notes"], "new notes");
+    assert_eq!(json["type"], "enhancement");
+    assert_eq!(json["stable_days"], 7);
+    assert_eq!(json["bugs"], serde_json::json!(["1234567"]));
+    assert_eq!(json["builds"], serde_json::json!(["rust-1.40.0-1.fc31"]));
+}
+
+#[test]
+fn editor_side_tag() {
+    let update = test_update(&["rust-1.40.0-1.fc31"], Some("f31-build-side-1234"));
+
+    assert!(update.edit().notes("new notes").validate().is_ok());
+    assert_eq!(
+        update
+            .edit()
+            .remove_build("rust-1.40.0-1.fc31")
+            .validate()
+            .unwrap_err()
+            .problems,
+        vec![ValidationProblem::SideTagBuildsEdited]
+    );
+}
+
+#[test]
+fn editor_unmodified() {
+    let fetched = test_update(&["rust-1.40.0-1.fc31"], None);
+    let current = test_update(&["rust-1.40.0-1.fc31"], None);
+    assert!(check_unmodified(&fetched, Some(current)).is_ok());
+}
+
+#[test]
+fn editor_modified() {
+    let fetched = test_update(&["rust-1.40.0-1.fc31"], None);
+
+    let mut current = test_update(&["rust-1.40.0-1.fc31"], None);
+    current.date_modified = Some(BodhiDate::try_from("2019-12-21 12:00:00").unwrap());
+    assert!(matches!(
+        check_unmodified(&fetched, Some(current)),
+        Err(QueryError::ConcurrentModification { alias, date_modified: Some(_) }) if alias == fetched.alias
+    ));
+
+    // the version hash changes when builds are added or removed
+    let mut current = test_update(&["rust-1.40.0-1.fc31"], None);
+    current.version_hash = String::from("0123456789abcdef0123456789abcdef01234567");
+    assert!(matches!(
+        check_unmodified(&fetched, Some(current)),
+        Err(QueryError::ConcurrentModification { .. })
+    ));
+}
+
+#[test]
+fn editor_missing_update() {
+    let fetched = test_update(&["rust-1.40.0-1.fc31"], None);
+
+    assert!(matches!(
+        check_unmodified(&fetched, None),
+        Err(QueryError::UpdateNotFound { alias }) if alias == fetched.alias
+    ));
+}
@@ -0,0 +1,93 @@
+use std::convert::TryFrom;
+
+use super::{test_override, test_update};
+use crate::ensure::{find_update, override_action, override_expiration};
+use crate::error::QueryError;
+use crate::{BodhiDate, BodhiServiceBuilder, OverrideAction, UpdateBuilder};
+
+#[test]
+fn ensure_override_unchanged() {
+    let over_ride = test_override("2030-01-01 00:00:00");
+    let date = BodhiDate::try_from("2029-12-31").unwrap();
+
+    assert_eq!(override_action(&over_ride, &date), OverrideAction::Unchanged);
+}
+
+#[test]
+fn ensure_override_extended() {
+    let over_ride = test_override("2030-01-01 00:00:00");
+    let date = BodhiDate::try_from("2030-02-01").unwrap();
+
+    assert_eq!(override_action(&over_ride, &date), OverrideAction::Extended);
+}
+
+#[test]
+fn ensure_override_unexpired() {
+    let mut over_ride = test_override("2020-01-01 00:00:00");
+    over_ride.expired_date = Some(BodhiDate::try_from("2020-01-01 00:00:00").unwrap());
+    let date = BodhiDate::try_from("2019-12-01").unwrap();
+
+    // expired overrides are re-activated, even if the expiration date is late enough
+    assert_eq!(override_action(&over_ride, &date), OverrideAction::Unexpired);
+}
+
+#[test]
+fn ensure_override_unexpired_keeps_later_date() {
+    let mut over_ride = test_override("2030-01-01 00:00:00");
+    over_ride.expired_date = Some(BodhiDate::try_from("2020-01-01 00:00:00").unwrap());
+    let date = BodhiDate::try_from("2029-12-01").unwrap();
+
+    // re-activating an override must not shorten its stored expiration date
+    assert_eq!(override_action(&over_ride, &date), OverrideAction::Unexpired);
+    assert_eq!(override_expiration(&over_ride, &date), &over_ride.expiration_date);
+}
+
+#[test]
+fn ensure_override_unexpired_uses_requested_date() {
+    let mut over_ride = test_override("2020-01-01 00:00:00");
+    over_ride.expired_date = Some(BodhiDate::try_from("2020-01-01 00:00:00").unwrap());
+    let date = BodhiDate::try_from("2030-01-01").unwrap();
+
+    assert_eq!(override_expiration(&over_ride, &date), &date);
+}
+
+#[test]
+fn ensure_update_existing() {
+    let updates = vec![
+        test_update(&["cargo-c-0.5.0-1.fc31"], None),
+        test_update(&["rust-1.40.0-1.fc31", "cargo-c-0.5.0-1.fc31"], None),
+    ];
+
+    let update = find_update(updates, &["rust-1.40.0-1.fc31"]).unwrap().unwrap();
+    assert_eq!(update.builds.len(), 2);
+}
+
+#[test]
+fn ensure_update_missing() {
+    let updates = vec![test_update(&["cargo-c-0.5.0-1.fc31"], None)];
+    assert!(find_update(updates, &["rust-1.40.0-1.fc31"]).unwrap().is_none());
+}
+
+#[test]
+fn ensure_update_conflict() {
+    let updates = vec![test_update(&["rust-1.40.0-1.fc31"], None)];
+
+    match find_update(updates, &["rust-1.40.0-1.fc31", "cargo-c-0.5.0-1.fc31"]) {
+        Err(QueryError::ConflictingBuilds { builds, aliases }) => {
+            assert_eq!(builds, vec!["rust-1.40.0-1.fc31"]);
+            assert_eq!(aliases, vec!["FEDORA-2019-cf87377f5f"]);
+        },
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+#[test]
+fn ensure_update_side_tag() {
+    let bodhi = BodhiServiceBuilder::default().build().unwrap();
+    let builder = UpdateBuilder::from_tag("f32-build-side-1234", "notes");
+
+    assert!(matches!(
+        bodhi.ensure_update(&builder),
+        Err(QueryError::InvalidDataError { .. })
+    ));
+}
@@ -14,7 +14,101 @@ fn release_format() {
 #[test]
 #[should_panic]
 fn release_try_from_err() {
-    FedoraRelease::try_from("X12").unwrap();
+    FedoraRelease::try_from("X 12").unwrap();
+}
+
+#[test]
+fn release_parse_unknown() {
+    let release = FedoraRelease::try_from("F99C").unwrap();
+    assert_eq!(release.product(), Some(ReleaseProduct::Fedora));
+    assert_eq!(release.version(), Some(99));
+    assert_eq!(release.variant(), Some(ReleaseVariant::Container));
+    assert_eq!(release.to_string().as_str(), "F99C");
+
+    assert_eq!(
+        FedoraRelease::try_from("EPEL-9N").unwrap(),
+        FedoraRelease::epel(9).with_variant(ReleaseVariant::Next)
+    );
+    assert_eq!(FedoraRelease::try_from("ELN").unwrap().version(), None);
+    assert!(FedoraRelease::try_from("__current__").unwrap().is_pseudo());
+}
+
+#[test]
+fn release_parse_invalid() {
+    for value in &["", "F 36", "not a release", "F36/", "EPEL-8\n"] {
+        assert!(FedoraRelease::try_from(*value).is_err(), "{} should be invalid", value);
+    }
+}
+
+#[test]
+fn release_parse_malformed() {
+    for value in &[
+        "F",
+        "F036",
+        "F36c",
+        "EPEL8",
+        "EPEL-",
+        "EPEL-10.",
+        "EPEL-10.01",
+        "ELN1",
+        "f36",
+    ] {
+        let release = FedoraRelease::try_from(*value).unwrap();
+        assert!(release.is_unknown(), "{} should be kept as-is", value);
+        assert_eq!(release.to_string().as_str(), *value);
+    }
+}
+
+#[test]
+fn release_parse_minor() {
+    let release = FedoraRelease::try_from("EPEL-10.1").unwrap();
+    assert_eq!(release.product(), Some(ReleaseProduct::EPEL));
+    assert_eq!(release.version(), Some(10));
+    assert_eq!(release.minor_version(), Some(1));
+    assert_eq!(release, FedoraRelease::epel(10).with_minor(1));
+    assert_eq!(release.to_string().as_str(), "EPEL-10.1");
+
+    let release = FedoraRelease::try_from("EPEL-10.0").unwrap();
+    assert_eq!(release.minor_version(), Some(0));
+    assert_eq!(release.to_string().as_str(), "EPEL-10.0");
+}
+
+#[test]
+fn release_parse_unknown_suffix() {
+    for value in &["F36X", "F36CM", "EPEL-10.1Z"] {
+        let release = FedoraRelease::try_from(*value).unwrap();
+        assert!(release.is_unknown());
+        assert!(!release.is_pseudo());
+        assert_eq!(release.product(), None);
+        assert_eq!(release.to_string().as_str(), *value);
+    }
+}
+
+#[test]
+fn release_deserialize_raw() {
+    let release: FedoraRelease = serde_json::from_str(r#""OSTREE-2024""#).unwrap();
+    assert!(release.is_unknown());
+    assert_eq!(serde_json::to_string(&release).unwrap(), r#""OSTREE-2024""#);
+
+    assert!(serde_json::from_str::<FedoraRelease>(r#""not a release""#).is_err());
+}
+
+#[test]
+fn release_roundtrip_raw() {
+    for value in &[
+        "FOO",
+        "OSTREE-2024",
+        "F36X",
+        "A-VERY-LONG-RELEASE-NAME-WITH-MORE-THAN-32-CHARACTERS",
+    ] {
+        let parsed: FedoraRelease = value.parse().unwrap();
+        let deserialized: FedoraRelease = serde_json::from_value(serde_json::json!(value)).unwrap();
+
+        assert!(parsed.is_unknown());
+        assert_eq!(parsed, deserialized);
+        assert_eq!(parsed.to_string().parse::<FedoraRelease>().unwrap(), parsed);
+        assert_eq!(serde_json::to_value(&parsed).unwrap(), serde_json::json!(value));
+    }
 }
 
 #[test]
@@ -94,8 +188,6 @@ fn idem_content_type() {
 
 #[test]
 fn idem_fedora_release() {
-    use FedoraRelease::*;
-
     let strings = vec![
         "F36", "F36C", "F36F", "F36M", "F35", "F35C", "F35F", "F35M", "F34", "F34C", "F34F", "F34M", "F33", "F33C",
         "F33F", "F33M", "F32", "F32C", "F32F", "F32M", "F31", "F31C", "F31F", "F31M", "F30", "F30C", "F30F", "F30M",
@@ -104,9 +196,56 @@ fn idem_fedora_release() {
     ];
 
     let values = vec![
-        F36, F36C, F36F, F36M, F35, F35C, F35F, F35M, F34, F34C, F34F, F34M, F33, F33C, F33F, F33M, F32, F32C, F32F,
-        F32M, F31, F31C, F31F, F31M, F30, F30C, F30F, F30M, F29, F29C, F29F, F29M, F28, F28C, F28M, F27, F27M, F26,
-        F25, F24, F23, F22, F21, EPEL8, EPEL8M, EPEL8N, EPEL7, EL6, EL5, ELN,
+        FedoraRelease::F36,
+        FedoraRelease::F36C,
+        FedoraRelease::F36F,
+        FedoraRelease::F36M,
+        FedoraRelease::F35,
+        FedoraRelease::F35C,
+        FedoraRelease::F35F,
+        FedoraRelease::F35M,
+        FedoraRelease::F34,
+        FedoraRelease::F34C,
+        FedoraRelease::F34F,
+        FedoraRelease::F34M,
+        FedoraRelease::F33,
+        FedoraRelease::F33C,
+        FedoraRelease::F33F,
+        FedoraRelease::F33M,
+        FedoraRelease::F32,
+        FedoraRelease::F32C,
+        FedoraRelease::F32F,
+        FedoraRelease::F32M,
+        FedoraRelease::F31,
+        FedoraRelease::F31C,
+        FedoraRelease::F31F,
+        FedoraRelease::F31M,
+        FedoraRelease::F30,
+        FedoraRelease::F30C,
+        FedoraRelease::F30F,
+        FedoraRelease::F30M,
+        FedoraRelease::F29,
+        FedoraRelease::F29C,
+        FedoraRelease::F29F,
+        FedoraRelease::F29M,
+        FedoraRelease::F28,
+        FedoraRelease::F28C,
+        FedoraRelease::F28M,
+        FedoraRelease::F27,
+        FedoraRelease::F27M,
+        FedoraRelease::F26,
+        FedoraRelease::F25,
+        FedoraRelease::F24,
+        FedoraRelease::F23,
+        FedoraRelease::F22,
+        FedoraRelease::F21,
+        FedoraRelease::EPEL8,
+        FedoraRelease::EPEL8M,
+        FedoraRelease::EPEL8N,
+        FedoraRelease::EPEL7,
+        FedoraRelease::EL6,
+        FedoraRelease::EL5,
+        FedoraRelease::ELN,
     ];
 
     assert_eq!(strings.len(), values.len());
@@ -301,3 +440,29 @@ fn idem_update_type() {
         assert_eq!(value.to_string().parse::<UpdateType>().unwrap(), value);
     }
 }
+
+#[test]
+#[cfg(not(feature = "strict-enums"))]
+fn unknown_roundtrip() {
+    let status: TestGatingStatus = "new_status".parse().unwrap();
+    assert_eq!(status, TestGatingStatus::Unknown(String::from("new_status")));
+    assert_eq!(status.to_string(), "new_status");
+
+    let status: UpdateStatus = serde_json::from_str("\"new_status\"").unwrap();
+    assert_eq!(status, UpdateStatus::Unknown(String::from("new_status")));
+    assert_eq!(serde_json::to_string(&status).unwrap(), "\"new_status\"");
+
+    // parsing ignores case, but deserializing only accepts the values that bodhi returns
+    assert_eq!("STABLE".parse::<UpdateStatus>().unwrap(), UpdateStatus::Stable);
+    let status: UpdateStatus = serde_json::from_str("\"STABLE\"").unwrap();
+    assert_eq!(status, UpdateStatus::Unknown(String::from("STABLE")));
+    assert_eq!(serde_json::to_string(&status).unwrap(), "\"STABLE\"");
+}
+
+#[test]
+#[cfg(feature = "strict-enums")]
+fn unknown_strict() {
+    assert!("new_status".parse::<TestGatingStatus>().is_err());
+    assert!(serde_json::from_str::<UpdateStatus>("\"new_status\"").is_err());
+    assert!(serde_json::from_str::<UpdateStatus>("\"STABLE\"").is_err());
+}
@@ -0,0 +1,126 @@
+use crate::error::{BodhiError, BodhiErrorCategory, BodhiErrorEntry, QueryError};
+
+fn error(http_status: u16, name: &str, description: &str) -> BodhiError {
+    let json = serde_json::json!({
+        "status": "error",
+        "errors": [{ "location": "body", "name": name, "description": description }]
+    });
+
+    BodhiError::from_response(http_status, &json.to_string()).unwrap()
+}
+
+#[test]
+fn error_entries() {
+    let json = r#"{
+        "status": "error",
+        "errors": [
+            { "location": "body", "name": "builds", "description": "Build does not exist: foo-1-1.fc31" },
+            { "location": "body", "description": "Something else went wrong" }
+        ]
+    }"#;
+
+    let error = BodhiError::from_response(400, json).unwrap();
+
+    assert_eq!(error.http_status, Some(400));
+    assert_eq!(
+        error.errors[1],
+        BodhiErrorEntry {
+            location: String::from("body"),
+            name: String::new(),
+            description: String::from("Something else went wrong"),
+        }
+    );
+    assert_eq!(error.entries_for("builds").count(), 1);
+    assert_eq!(
+        error.to_string(),
+        "error (HTTP 400): body.builds: Build does not exist: foo-1-1.fc31; body: Something else went wrong"
+    );
+}
+
+#[test]
+fn error_categories() {
+    let cases = [
+        (
+            error(400, "csrf_token", "CSRF tokens do not match"),
+            BodhiErrorCategory::CsrfFailure,
+        ),
+        (
+            error(400, "request", "Can't change request on a locked update"),
+            BodhiErrorCategory::LockedUpdate,
+        ),
+        (
+            error(400, "request", "This update has not yet met the minimum testing requirements defined in the <a href=\"https://fedoraproject.org/wiki/Package_update_acceptance_criteria\">Package Update Acceptance Criteria</a> (karma)"),
+            BodhiErrorCategory::KarmaThreshold,
+        ),
+        (
+            error(400, "builds", "Invalid tag: foo-1-1.fc31 not tagged with any of the following tags"),
+            BodhiErrorCategory::InvalidBuild,
+        ),
+        (
+            error(400, "nvr", "Invalid build"),
+            BodhiErrorCategory::InvalidBuild,
+        ),
+        (
+            error(403, "builds", "decathorpe does not have commit access to foo"),
+            BodhiErrorCategory::PermissionDenied,
+        ),
+        (
+            error(400, "builds", "decathorpe does not have commit access to foo"),
+            BodhiErrorCategory::PermissionDenied,
+        ),
+        (
+            error(400, "", "decathorpe does not have commit access to foo"),
+            BodhiErrorCategory::PermissionDenied,
+        ),
+        (
+            error(403, "", "Access denied"),
+            BodhiErrorCategory::PermissionDenied,
+        ),
+        (error(500, "", "Something went wrong"), BodhiErrorCategory::Other),
+    ];
+
+    for (error, category) in cases.iter() {
+        assert_eq!(&error.category(), category, "{}", error);
+    }
+}
+
+#[test]
+fn unexpected_response() {
+    let html = format!("<html><body>{}</body></html>", "Bad Gateway ".repeat(200));
+    let error = QueryError::from_parts(502, Some(String::from("text/html")), html);
+
+    match &error {
+        QueryError::UnexpectedResponse {
+            status,
+            content_type,
+            body,
+        } => {
+            assert_eq!(*status, 502);
+            assert_eq!(content_type.as_deref(), Some("text/html"));
+            assert_eq!(body.len(), 1024 + 3);
+            assert!(body.ends_with("..."));
+        },
+        _ => panic!("Unexpected error: {}", error),
+    }
+
+    assert!(error.is_retryable());
+
+    let error = QueryError::from_parts(500, None, String::new());
+    assert!(matches!(error, QueryError::UnexpectedResponse { status: 500, .. }));
+    assert!(error.is_retryable());
+
+    let error = QueryError::from_parts(404, None, String::from("Not Found"));
+    assert!(!error.is_retryable());
+}
+
+#[test]
+fn bodhi_error_response() {
+    let json = r#"{"status": "error", "errors": [{"location": "body", "name": "csrf_token", "description": "CSRF tokens do not match"}]}"#;
+
+    let error = QueryError::from_parts(400, Some(String::from("application/json")), json.to_owned());
+    assert!(matches!(error, QueryError::BodhiError { .. }));
+    assert!(!error.is_retryable());
+
+    let error = QueryError::from_parts(503, None, json.to_owned());
+    assert!(error.is_retryable());
+}
@@ -0,0 +1,116 @@
+use super::test_update;
+
+use crate::group::rollback_request;
+use crate::{Update, UpdateGroupStatus, UpdateRequest, UpdateStatus};
+
+// group member with the given status, karma, test gating status, and testing requirements
+fn member(status: &str, karma: i32, gating: Option<&str>, meets_testing_requirements: bool) -> Update {
+    let mut update = test_update(&["rust-1.40.0-1.fc31"], None);
+    update.status = status.parse().unwrap();
+    update.karma = Some(karma);
+    update.test_gating_status = gating.map(|gating| gating.parse().unwrap());
+    update.meets_testing_requirements = meets_testing_requirements;
+
+    update
+}
+
+#[test]
+fn group_status() {
+    let status = UpdateGroupStatus {
+        updates: vec![member("testing", 0, None, false), member("testing", 1, None, false)],
+    };
+    assert_eq!(status.status(), Some(UpdateStatus::Testing));
+
+    let status = UpdateGroupStatus {
+        updates: vec![member("testing", 0, None, false), member("pending", 0, None, false)],
+    };
+    assert_eq!(status.status(), None);
+
+    let status = UpdateGroupStatus { updates: vec![] };
+    assert_eq!(status.status(), None);
+}
+
+#[test]
+fn group_gating() {
+    let status = UpdateGroupStatus {
+        updates: vec![
+            member("testing", 0, Some("passed"), false),
+            member("testing", 0, Some("ignored"), false),
+            member("testing", 0, None, false),
+        ],
+    };
+    assert!(status.is_gating_passed());
+
+    let status = UpdateGroupStatus {
+        updates: vec![
+            member("testing", 0, Some("passed"), false),
+            member("testing", 0, Some("failed"), false),
+        ],
+    };
+    assert!(!status.is_gating_passed());
+}
+
+#[test]
+fn group_karma() {
+    let status = UpdateGroupStatus {
+        updates: vec![member("testing", 3, None, true), member("testing", 5, None, true)],
+    };
+    assert_eq!(status.min_karma(), Some(3));
+    assert!(status.is_karma_reached());
+
+    let status = UpdateGroupStatus {
+        updates: vec![member("testing", 3, None, true), member("testing", -1, None, true)],
+    };
+    assert_eq!(status.min_karma(), Some(-1));
+    assert!(!status.is_karma_reached());
+
+    let status = UpdateGroupStatus { updates: vec![] };
+    assert_eq!(status.min_karma(), None);
+}
+
+#[test]
+fn group_ready_for_stable() {
+    let status = UpdateGroupStatus {
+        updates: vec![
+            member("testing", 0, Some("passed"), true),
+            member("testing", 0, None, true),
+        ],
+    };
+    assert!(status.is_ready_for_stable());
+
+    // one member does not meet the testing requirements yet
+    let status = UpdateGroupStatus {
+        updates: vec![member("testing", 0, None, true), member("testing", 0, None, false)],
+    };
+    assert!(!status.is_ready_for_stable());
+
+    // all members meet the testing requirements, but one is blocked by test gating
+    let status = UpdateGroupStatus {
+        updates: vec![
+            member("testing", 0, None, true),
+            member("testing", 0, Some("waiting"), true),
+        ],
+    };
+    assert!(!status.is_ready_for_stable());
+}
+
+#[test]
+fn group_rollback() {
+    // updates without a pending request have the new request revoked
+    assert_eq!(
+        rollback_request(None, &UpdateRequest::Stable),
+        Some(UpdateRequest::Revoke)
+    );
+
+    // updates with a pending request get their previous request back
+    assert_eq!(
+        rollback_request(Some(&UpdateRequest::Testing), &UpdateRequest::Stable),
+        Some(UpdateRequest::Testing)
+    );
+
+    // updates that already had the same request were not changed
+    assert_eq!(
+        rollback_request(Some(&UpdateRequest::Stable), &UpdateRequest::Stable),
+        None
+    );
+}
@@ -0,0 +1,106 @@
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::convert::TryFrom;
+
+use crate::{BugId, Nvr, UpdateAlias, UpdateID};
+
+#[test]
+fn alias_parse() {
+    for value in &[
+        "FEDORA-2019-cf87377f5f",
+        "FEDORA-EPEL-2020-0a1b2c3d4e",
+        "FEDORA-CONTAINER-2019-1a2b3c4d5e",
+        "FEDORA-EPEL-NEXT-2021-0a1b2c3d4e",
+    ] {
+        let alias = UpdateAlias::try_from(*value).unwrap();
+        assert_eq!(alias.to_string().as_str(), *value);
+    }
+
+    let alias = UpdateAlias::try_from("FEDORA-MODULAR-2019-cf87377f5f").unwrap();
+    assert_eq!(alias.prefix(), "FEDORA-MODULAR");
+    assert_eq!(alias.year(), 2019);
+    assert_eq!(alias.hash(), "cf87377f5f");
+}
+
+#[test]
+fn alias_parse_invalid() {
+    for value in &[
+        "",
+        "FEDORA",
+        "FEDORA-2019",
+        "FEDORA-19-cf87377f5f",
+        "FEDORA-2019-",
+        "fedora-2019-cf87377f5f",
+        "RHEL-2019-cf87377f5f",
+        "FEDORA--2019-cf87377f5f",
+        "FEDORA-2019-cf87377f5f ",
+    ] {
+        assert!(UpdateAlias::try_from(*value).is_err(), "{} should be invalid", value);
+    }
+}
+
+#[test]
+fn update_id_parse() {
+    assert_eq!(UpdateID::try_from("12345").unwrap(), UpdateID::ID(12345));
+    assert!(matches!(
+        UpdateID::try_from("FEDORA-2019-cf87377f5f").unwrap(),
+        UpdateID::Alias(_)
+    ));
+    assert!(UpdateID::try_from("rust-1.40.0-1.fc31").is_err());
+}
+
+#[test]
+fn nvr_parse() {
+    let nvr = Nvr::try_from("python-requests-2:2.22.0-7.fc31").unwrap();
+    assert_eq!(nvr.name(), "python-requests");
+    assert_eq!(nvr.epoch(), Some(2));
+    assert_eq!(nvr.version(), "2.22.0");
+    assert_eq!(nvr.release(), "7.fc31");
+    assert_eq!(nvr.to_string().as_str(), "python-requests-2:2.22.0-7.fc31");
+
+    for value in &[
+        "",
+        "rust",
+        "rust-1.40.0",
+        "-1.40.0-1.fc31",
+        "rust-x:1.40.0-1.fc31",
+        "rust-1.40.0-",
+    ] {
+        assert!(Nvr::try_from(*value).is_err(), "{} should be invalid", value);
+    }
+}
+
+#[test]
+fn nvr_ordering() {
+    let mut nvrs: Vec<Nvr> = vec!["rust-1.40.0-1.fc31", "rust-1.9.0-1.fc31", "rust-1.40.0~rc1-1.fc31"]
+        .into_iter()
+        .map(|s| s.parse().unwrap())
+        .collect();
+    nvrs.sort_by(Nvr::nvr_cmp);
+
+    let sorted: Vec<String> = nvrs.iter().map(|nvr| nvr.to_string()).collect();
+    assert_eq!(
+        sorted,
+        vec!["rust-1.9.0-1.fc31", "rust-1.40.0~rc1-1.fc31", "rust-1.40.0-1.fc31"]
+    );
+
+    // equivalent versions are compared as equal, but the values are still different
+    let one = Nvr::try_from("rust-1.40.0-1.fc31").unwrap();
+    let two = Nvr::try_from("rust-0:1.40.0-01.fc31").unwrap();
+    assert_eq!(one.nvr_cmp(&two), Ordering::Equal);
+    assert_ne!(one, two);
+
+    let set: HashSet<Nvr> = vec![one.clone(), two, one].into_iter().collect();
+    assert_eq!(set.len(), 2);
+}
+
+#[test]
+fn bug_id_parse() {
+    assert_eq!(BugId::try_from("1234567").unwrap(), BugId::new(1234567));
+    assert_eq!(BugId::try_from("#1234567").unwrap(), BugId::new(1234567));
+    assert_eq!(BugId::try_from("RHBZ#1234567").unwrap(), BugId::new(1234567));
+
+    for value in &["", "0", "#", "rhbz#", "12a", "-1", "bz#1234"] {
+        assert!(BugId::try_from(*value).is_err(), "{} should be invalid", value);
+    }
+}
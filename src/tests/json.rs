@@ -0,0 +1,152 @@
+use serde::Deserialize;
+
+use crate::error::QueryError;
+use crate::query::json::{from_json, parse_response};
+use crate::{BodhiServiceBuilder, Build};
+
+#[derive(Debug, Deserialize)]
+struct BuildListPage {
+    builds: Vec<Build>,
+}
+
+fn build(nvr: &str, epoch: serde_json::Value) -> serde_json::Value {
+    serde_json::json!({ "epoch": epoch, "nvr": nvr, "release_id": 1, "signed": true, "type": "rpm" })
+}
+
+fn page(epochs: &[serde_json::Value]) -> String {
+    let builds: Vec<serde_json::Value> = epochs
+        .iter()
+        .enumerate()
+        .map(|(i, epoch)| build(&format!("foo-{}-1.fc32", i), epoch.clone()))
+        .collect();
+
+    serde_json::json!({ "builds": builds, "extra": { "unused": true } }).to_string()
+}
+
+#[test]
+fn json_path_nested() {
+    let body = page(&[0.into(), 1.into(), "two".into()]);
+
+    match from_json::<BuildListPage>(&body) {
+        Err(QueryError::DeserializationError { path, .. }) => assert_eq!(path.as_deref(), Some("builds[2].epoch")),
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+#[test]
+fn json_path_missing_field() {
+    let body = r#"{"builds": [{"nvr": "foo-1-1.fc32", "signed": true, "type": "rpm"}, {"nvr": "bar-1-1.fc32"}]}"#;
+
+    match from_json::<BuildListPage>(body) {
+        Err(QueryError::DeserializationError { path, error, .. }) => {
+            assert_eq!(path.as_deref(), Some("builds[1]"));
+            assert!(error.to_string().contains("missing field `signed`"));
+        },
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+#[test]
+fn json_path_syntax_error() {
+    match from_json::<BuildListPage>(r#"{"builds": ["#) {
+        Err(QueryError::DeserializationError { path, .. }) => assert!(path.is_none()),
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+#[test]
+fn parse_response_context() {
+    let bodhi = BodhiServiceBuilder::default().build().unwrap();
+    let body = page(&[0.into(), "one".into()]);
+
+    let error = parse_response(
+        &bodhi,
+        "/builds/?page=3",
+        Some(3),
+        body,
+        None,
+        from_json::<BuildListPage>,
+    )
+    .unwrap_err();
+
+    match &error {
+        QueryError::DeserializationError {
+            path,
+            endpoint,
+            page,
+            dump,
+            ..
+        } => {
+            assert_eq!(path.as_deref(), Some("builds[1].epoch"));
+            assert_eq!(endpoint.as_deref(), Some("/builds/?page=3"));
+            assert_eq!(*page, Some(3));
+            assert!(dump.is_none());
+        },
+        other => panic!("unexpected error: {:?}", other),
+    }
+
+    let message = error.to_string();
+    assert!(
+        message.starts_with("Failed to deserialize JSON response from /builds/?page=3 (page 3) at builds[1].epoch: ")
+    );
+}
+
+#[test]
+fn parse_response_lenient() {
+    let directory = std::env::temp_dir().join(format!("bodhi-rs-dump-{}", std::process::id()));
+
+    let bodhi = BodhiServiceBuilder::default()
+        .dump_directory(&directory)
+        .build()
+        .unwrap();
+
+    let mut skipped = Vec::new();
+    let body = page(&["zero".into(), 1.into(), "two".into(), 3.into()]);
+    let result = parse_response(
+        &bodhi,
+        "/builds/?page=1",
+        Some(1),
+        body,
+        Some(&mut skipped),
+        from_json::<BuildListPage>,
+    )
+    .unwrap();
+
+    let nvrs: Vec<&str> = result.builds.iter().map(|build| build.nvr.as_str()).collect();
+    assert_eq!(nvrs, vec!["foo-1-1.fc32", "foo-3-1.fc32"]);
+
+    assert_eq!(skipped.len(), 2);
+    assert_eq!(skipped[0].path, "builds[0].epoch");
+    assert_eq!(skipped[1].path, "builds[2].epoch");
+    assert_eq!(skipped[1].page, Some(1));
+
+    let dump = skipped[0].dump.as_ref().unwrap();
+    let item: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(dump).unwrap()).unwrap();
+    assert_eq!(item["nvr"], "foo-0-1.fc32");
+
+    std::fs::remove_dir_all(&directory).unwrap();
+}
+
+#[test]
+fn parse_response_lenient_single() {
+    let bodhi = BodhiServiceBuilder::default().build().unwrap();
+    let body = build("foo-1-1.fc32", "one".into()).to_string();
+
+    // lenient queries only skip items of paginated results
+    let mut skipped = Vec::new();
+    let error = parse_response(
+        &bodhi,
+        "/builds/foo-1-1.fc32",
+        None,
+        body,
+        Some(&mut skipped),
+        from_json::<Build>,
+    )
+    .unwrap_err();
+
+    match error {
+        QueryError::DeserializationError { path, .. } => assert_eq!(path.as_deref(), Some("epoch")),
+        other => panic!("unexpected error: {:?}", other),
+    }
+    assert!(skipped.is_empty());
+}
@@ -0,0 +1,140 @@
+use serde_json::{json, Value};
+
+use super::test_update;
+use crate::{Comment, FeedbackTally, Karma};
+
+fn comment(id: u32, user: &str, karma: i8, timestamp: &str, text: &str) -> Value {
+    json!({
+        "bug_feedback": [],
+        "id": id,
+        "karma": karma,
+        "karma_critpath": 0,
+        "testcase_feedback": [],
+        "text": text,
+        "timestamp": timestamp,
+        "update": null,
+        "update_id": 1,
+        "user": { "avatar": null, "email": null, "groups": [], "id": id, "name": user, "openid": null },
+        "user_id": id
+    })
+}
+
+fn comments(values: Vec<Value>) -> Option<Vec<Comment>> {
+    Some(serde_json::from_value(Value::Array(values)).unwrap())
+}
+
+#[test]
+fn karma_latest_comment_per_user() {
+    let mut update = test_update(&["rust-1.40.0-1.fc31"], None);
+    update.comments = comments(vec![
+        comment(1, "alice", 1, "2019-12-20 10:00:00", "works"),
+        comment(2, "bob", 1, "2019-12-20 11:00:00", "works"),
+        comment(3, "alice", -1, "2019-12-21 10:00:00", "broken after all"),
+        comment(4, "carol", 1, "2019-12-21 11:00:00", "works"),
+    ]);
+
+    let summary = update.karma_summary();
+
+    assert_eq!(summary.users.get("alice"), Some(&Karma::Negative));
+    assert_eq!(summary.positive, 2);
+    assert_eq!(summary.negative, -1);
+    assert_eq!(summary.total(), 1);
+    assert!(!summary.is_stable_karma_reached());
+}
+
+#[test]
+fn karma_plain_comment_keeps_vote() {
+    let mut update = test_update(&["rust-1.40.0-1.fc31"], None);
+    update.comments = comments(vec![
+        comment(1, "alice", 1, "2019-12-20 10:00:00", "works"),
+        comment(2, "alice", 0, "2019-12-21 10:00:00", "still works after a reboot"),
+    ]);
+
+    let summary = update.karma_summary();
+
+    assert_eq!(summary.users.get("alice"), Some(&Karma::Positive));
+    assert_eq!(summary.total(), 1);
+}
+
+#[test]
+fn karma_excludes_submitter_and_bots() {
+    let mut update = test_update(&["rust-1.40.0-1.fc31"], None);
+    update.comments = comments(vec![
+        comment(1, "decathorpe", 1, "2019-12-20 10:00:00", "my own update"),
+        comment(
+            2,
+            "bodhi",
+            0,
+            "2019-12-20 11:00:00",
+            "This update has been submitted for testing.",
+        ),
+        comment(3, "alice", 1, "2019-12-20 12:00:00", "works"),
+        comment(4, "bob", 1, "2019-12-20 13:00:00", "works"),
+        comment(5, "carol", 1, "2019-12-20 14:00:00", "works"),
+    ]);
+
+    let summary = update.karma_summary();
+
+    assert_eq!(summary.users.len(), 3);
+    assert_eq!(summary.total(), 3);
+    assert!(summary.is_stable_karma_reached());
+    assert!(summary.is_autokarma_met());
+}
+
+#[test]
+fn karma_reset() {
+    let mut update = test_update(&["rust-1.40.0-1.fc31"], None);
+    update.comments = comments(vec![
+        comment(1, "alice", -1, "2019-12-20 10:00:00", "broken"),
+        comment(2, "bob", -1, "2019-12-20 11:00:00", "broken"),
+        comment(3, "bodhi", 0, "2019-12-21 10:00:00", "Karma has been reset."),
+        comment(4, "carol", 1, "2019-12-21 11:00:00", "fixed"),
+    ]);
+
+    let summary = update.karma_summary();
+
+    assert_eq!(summary.users.len(), 1);
+    assert_eq!(summary.total(), 1);
+    assert!(!summary.is_unstable_karma_reached());
+}
+
+#[test]
+fn karma_feedback() {
+    let mut first = comment(1, "alice", 1, "2019-12-20 10:00:00", "works");
+    first["bug_feedback"] = json!([{ "bug": null, "bug_id": 1234567, "comment_id": 1, "karma": -1 }]);
+    first["testcase_feedback"] = json!([
+        { "comment_id": 1, "karma": 1, "testcase": { "name": "QA:Testcase rust", "package": null }, "testcase_id": 1 }
+    ]);
+
+    let mut second = comment(2, "alice", 1, "2019-12-21 10:00:00", "fixed now");
+    second["bug_feedback"] = json!([{ "bug": null, "bug_id": 1234567, "comment_id": 2, "karma": 1 }]);
+
+    let mut third = comment(3, "bob", 1, "2019-12-21 11:00:00", "works");
+    third["bug_feedback"] = json!([{ "bug": null, "bug_id": 1234567, "comment_id": 3, "karma": 0 }]);
+
+    let mut update = test_update(&["rust-1.40.0-1.fc31"], None);
+    update.comments = comments(vec![first, second, third]);
+
+    let summary = update.karma_summary();
+
+    let bug = FeedbackTally {
+        positive: 1,
+        neutral: 1,
+        negative: 0,
+    };
+    assert_eq!(summary.bugs.get(&1234567), Some(&bug));
+    assert_eq!(summary.bugs[&1234567].total(), 1);
+
+    // test case feedback from an older comment is still counted
+    assert_eq!(summary.testcases["QA:Testcase rust"].positive, 1);
+}
+
+#[test]
+fn karma_without_comments() {
+    let update = test_update(&["rust-1.40.0-1.fc31"], None);
+    let summary = update.karma_summary();
+
+    assert!(summary.users.is_empty());
+    assert_eq!(summary.total(), 0);
+    assert_eq!(summary.stable_karma, Some(3));
+}
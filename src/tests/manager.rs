@@ -0,0 +1,65 @@
+use std::convert::TryFrom;
+
+use super::{test_override, test_update};
+use crate::error::QueryError;
+use crate::manager::{expiring_before, stable_nvrs};
+use crate::{BatchReport, BatchResult, BodhiDate, EditedOverride, OverrideReport, UpdateStatus};
+
+#[test]
+fn manager_expiring_before() {
+    let overrides = vec![
+        test_override("2030-01-01 00:00:00"),
+        test_override("2030-03-01 00:00:00"),
+    ];
+
+    let deadline = BodhiDate::try_from("2030-02-01").unwrap();
+    let expiring = expiring_before(overrides, &deadline);
+
+    assert_eq!(expiring.len(), 1);
+    assert_eq!(
+        expiring[0].expiration_date,
+        BodhiDate::try_from("2030-01-01 00:00:00").unwrap()
+    );
+}
+
+#[test]
+fn manager_stable_nvrs() {
+    let mut stable = test_update(&["rust-1.40.0-1.fc31", "cargo-c-0.5.0-1.fc31"], None);
+    stable.status = UpdateStatus::Stable;
+    let mut testing = test_update(&["rust-1.41.0-1.fc31"], None);
+    testing.status = UpdateStatus::Testing;
+
+    let updates = vec![stable, testing];
+    let nvrs: Vec<&str> = stable_nvrs(&updates).into_iter().collect();
+
+    assert_eq!(nvrs, vec!["cargo-c-0.5.0-1.fc31", "rust-1.40.0-1.fc31"]);
+}
+
+#[test]
+fn manager_report() {
+    let first = test_override("2030-01-01 00:00:00");
+    let mut second = test_override("2030-01-01 00:00:00");
+    second.nvr = String::from("rust-1.41.0-1.fc31");
+    let mut third = test_override("2030-01-01 00:00:00");
+    third.nvr = String::from("rust-1.42.0-1.fc31");
+
+    let batch: BatchReport<EditedOverride> = BatchReport {
+        results: vec![
+            BatchResult::Succeeded(EditedOverride {
+                over_ride: test_override("2030-06-01 00:00:00"),
+                caveats: Vec::new(),
+            }),
+            BatchResult::Failed(QueryError::InvalidDataError {
+                error: String::from("failed"),
+            }),
+        ],
+    };
+
+    let report = OverrideReport::new(&[&first, &second], &[&third], batch);
+
+    assert_eq!(report.changed.len(), 1);
+    assert_eq!(report.failed.len(), 1);
+    assert_eq!(report.failed[0].0, "rust-1.41.0-1.fc31");
+    assert_eq!(report.unchanged, vec!["rust-1.42.0-1.fc31"]);
+    assert!(!report.is_success());
+}
@@ -23,13 +23,222 @@ fn bodhi_init() -> BodhiService {
         .expect("Failed to initialize bodhi service for tests.")
 }
 
+#[cfg(any(feature = "online-tests", feature = "offline-tests"))]
+fn alias(alias: &str) -> crate::UpdateAlias {
+    alias.parse().expect("Failed to parse update alias.")
+}
+
+#[cfg(feature = "online-tests")]
+fn nvr(nvr: &str) -> crate::Nvr {
+    nvr.parse().expect("Failed to parse NVR.")
+}
+
+// JSON representation of a buildroot override, for testing builders and data types offline
+#[cfg(feature = "offline-tests")]
+const TEST_OVERRIDE: &str = r#"{
+    "build": { "epoch": null, "nvr": "rust-1.40.0-1.fc31", "release_id": 28, "signed": true, "type": "rpm" },
+    "build_id": 1375394,
+    "expiration_date": "2020-01-01 12:00:00",
+    "expired_date": null,
+    "notes": "rust 1.40.0",
+    "nvr": "rust-1.40.0-1.fc31",
+    "submission_date": "2019-12-19 18:45:00",
+    "submitter": {
+        "avatar": null,
+        "email": null,
+        "groups": [{ "name": "packager" }],
+        "id": 2855,
+        "name": "decathorpe",
+        "openid": "decathorpe.id.fedoraproject.org"
+    },
+    "submitter_id": 2855
+}"#;
+
+// JSON value of the buildroot override, for tests that need to modify it before deserializing
+#[cfg(feature = "offline-tests")]
+fn test_override_json() -> serde_json::Value {
+    serde_json::from_str(TEST_OVERRIDE).expect("Invalid buildroot override JSON for tests.")
+}
+
+// buildroot override with the given expiration date, for testing builders offline
+#[cfg(feature = "offline-tests")]
+fn test_override(expiration_date: &str) -> crate::Override {
+    let mut over_ride = test_override_json();
+    over_ride["expiration_date"] = expiration_date.into();
+
+    serde_json::from_value(over_ride).expect("Failed to deserialize buildroot override for tests.")
+}
+
+// JSON representation of an update, for testing builders offline
+#[cfg(feature = "offline-tests")]
+const TEST_UPDATE: &str = r#"{
+    "alias": "FEDORA-2019-cf87377f5f",
+    "autokarma": true,
+    "autotime": true,
+    "bugs": [{ "bug_id": 1234567, "parent": false, "security": false, "title": null }],
+    "builds": [],
+    "close_bugs": true,
+    "comments": null,
+    "compose": null,
+    "content_type": "rpm",
+    "critpath": false,
+    "date_approved": null,
+    "date_modified": "2019-12-20 12:00:00",
+    "date_pushed": null,
+    "date_stable": null,
+    "date_submitted": "2019-12-19 18:45:00",
+    "date_testing": null,
+    "display_name": "",
+    "from_tag": null,
+    "karma": 0,
+    "locked": false,
+    "meets_testing_requirements": false,
+    "notes": "Update to rust 1.40.0.",
+    "pushed": false,
+    "release": {
+        "branch": "f31",
+        "candidate_tag": "f31-updates-candidate",
+        "composed_by_bodhi": true,
+        "composes": null,
+        "create_automatic_updates": false,
+        "dist_tag": "f31",
+        "id_prefix": "FEDORA",
+        "long_name": "Fedora 31",
+        "mail_template": "fedora_errata_template",
+        "mandatory_days_in_testing": 7,
+        "name": "F31",
+        "package_manager": "dnf",
+        "override_tag": "f31-override",
+        "pending_signing_tag": "f31-signing-pending",
+        "pending_stable_tag": "f31-updates-pending",
+        "pending_testing_tag": "f31-updates-testing-pending",
+        "stable_tag": "f31-updates",
+        "state": "current",
+        "testing_repository": "updates-testing",
+        "testing_tag": "f31-updates-testing",
+        "version": "31"
+    },
+    "request": "testing",
+    "require_bugs": true,
+    "require_testcases": true,
+    "requirements": "",
+    "severity": "unspecified",
+    "stable_days": 7,
+    "stable_karma": 3,
+    "status": "pending",
+    "suggest": "unspecified",
+    "test_cases": [],
+    "test_gating_status": null,
+    "title": "rust-1.40.0-1.fc31",
+    "unstable_karma": -3,
+    "updateid": "FEDORA-2019-cf87377f5f",
+    "type": "enhancement",
+    "url": "https://bodhi.fedoraproject.org/updates/FEDORA-2019-cf87377f5f",
+    "user": { "avatar": null, "email": null, "groups": [], "id": 2855, "name": "decathorpe", "openid": null },
+    "version_hash": "3a5e1c5b0f2ac1ab16b6e1d6f7e1b6d0a1e5f7c2"
+}"#;
+
+// update with the given builds and side tag, for testing builders offline
+#[cfg(feature = "offline-tests")]
+fn test_update(builds: &[&str], from_tag: Option<&str>) -> crate::Update {
+    let builds: Vec<serde_json::Value> = builds
+        .iter()
+        .map(|nvr| serde_json::json!({ "epoch": 0, "nvr": nvr, "release_id": 28, "signed": true, "type": "rpm" }))
+        .collect();
+
+    let mut update: serde_json::Value = serde_json::from_str(TEST_UPDATE).expect("Invalid update JSON for tests.");
+    update["builds"] = builds.into();
+    update["from_tag"] = from_tag.into();
+
+    serde_json::from_value(update).expect("Failed to deserialize update for tests.")
+}
+
+// bodhi service for a local server that returns the given JSON responses (matched by the prefix of
+// the request path, other paths return 404 errors), for testing queries and builders offline
+#[cfg(feature = "offline-tests")]
+fn mock_bodhi(responses: Vec<(&'static str, String)>) -> crate::BodhiService {
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+
+    let listener = TcpListener::bind("127.0.0.1:0").expect("Failed to start mock server for tests.");
+    let url = format!("http://{}/", listener.local_addr().unwrap());
+
+    std::thread::spawn(move || {
+        for mut stream in listener.incoming().flatten() {
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+            let mut request = String::new();
+            reader.read_line(&mut request).unwrap();
+
+            // skip the request headers (GET requests have no body)
+            let mut header = String::new();
+            while reader.read_line(&mut header).unwrap() > 2 {
+                header.clear();
+            }
+
+            let path = request.split_whitespace().nth(1).unwrap_or_default();
+            let (status, body) = match responses.iter().find(|(prefix, _)| path.starts_with(prefix)) {
+                Some((_, body)) => ("200 OK", body.as_str()),
+                None => ("404 Not Found", "{}"),
+            };
+
+            let _ = write!(
+                stream,
+                "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            );
+        }
+    });
+
+    crate::BodhiServiceBuilder::custom(url, String::new())
+        .retries(0)
+        .build()
+        .expect("Failed to initialize bodhi service for tests.")
+}
+
 // offline tests
 #[cfg(feature = "offline-tests")]
+mod batch;
+#[cfg(feature = "offline-tests")]
 mod dates;
 #[cfg(feature = "offline-tests")]
+mod dryrun;
+#[cfg(feature = "offline-tests")]
+mod editor;
+#[cfg(feature = "offline-tests")]
+mod ensure;
+#[cfg(feature = "offline-tests")]
 mod enums;
 #[cfg(feature = "offline-tests")]
+mod errors;
+#[cfg(feature = "offline-tests")]
+mod group;
+#[cfg(feature = "offline-tests")]
+mod identifiers;
+#[cfg(feature = "offline-tests")]
+mod json;
+#[cfg(feature = "offline-tests")]
+mod karma;
+#[cfg(feature = "offline-tests")]
+mod manager;
+#[cfg(feature = "offline-tests")]
+mod queries;
+#[cfg(feature = "offline-tests")]
+mod report;
+#[cfg(feature = "offline-tests")]
+mod rpm;
+#[cfg(feature = "offline-tests")]
 mod types;
+#[cfg(feature = "offline-tests")]
+mod urls;
+#[cfg(feature = "offline-tests")]
+mod validate;
+#[cfg(feature = "offline-tests")]
+mod version;
+#[cfg(feature = "offline-tests")]
+mod watch;
 
 // tests requiring internet access
 #[cfg(feature = "online-tests")]
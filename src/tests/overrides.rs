@@ -1,4 +1,4 @@
-use super::bodhi_init;
+use super::{bodhi_init, nvr};
 
 use crate::{FedoraRelease, Override, OverrideNVRQuery, OverrideQuery};
 
@@ -50,7 +50,7 @@ fn query_sanity_users() {
 fn nvr_query_some() {
     let bodhi = bodhi_init();
 
-    let over_ride: Option<Override> = bodhi.query(OverrideNVRQuery::new("rust-1.34.2-1.fc30")).unwrap();
+    let over_ride: Option<Override> = bodhi.query(OverrideNVRQuery::new(&nvr("rust-1.34.2-1.fc30"))).unwrap();
 
     assert!(over_ride.is_some());
 }
@@ -59,7 +59,9 @@ fn nvr_query_some() {
 fn nvr_query_none() {
     let bodhi = bodhi_init();
 
-    let over_ride: Option<Override> = bodhi.query(OverrideNVRQuery::new("syncthing-1.1.3-1.fc30")).unwrap();
+    let over_ride: Option<Override> = bodhi
+        .query(OverrideNVRQuery::new(&nvr("syncthing-1.1.3-1.fc30")))
+        .unwrap();
 
     assert!(over_ride.is_none());
 }
@@ -69,7 +71,7 @@ fn nvr_query_invalid() {
     let bodhi = bodhi_init();
 
     let over_ride: Option<Override> = bodhi
-        .query(OverrideNVRQuery::new("this-doesnt-exist-1-1.fc30"))
+        .query(OverrideNVRQuery::new(&nvr("this-doesnt-exist-1-1.fc30")))
         .unwrap();
 
     assert!(over_ride.is_none());
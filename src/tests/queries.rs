@@ -0,0 +1,176 @@
+use std::convert::TryFrom;
+
+use super::test_update;
+use crate::*;
+
+fn assert_owned<T: Clone + Send + 'static>() {}
+
+#[test]
+fn queries_are_owned() {
+    assert_owned::<BuildNVRQuery>();
+    assert_owned::<BuildQuery<'static>>();
+    assert_owned::<CommentIDQuery>();
+    assert_owned::<CommentQuery<'static>>();
+    assert_owned::<ComposeQuery>();
+    assert_owned::<ComposeReleaseRequestQuery>();
+    assert_owned::<CSRFQuery>();
+    assert_owned::<OverrideNVRQuery>();
+    assert_owned::<OverrideQuery<'static>>();
+    assert_owned::<PackageQuery<'static>>();
+    assert_owned::<ReleaseNameQuery>();
+    assert_owned::<ReleaseQuery>();
+    assert_owned::<ServerVersionQuery>();
+    assert_owned::<UpdateIDQuery>();
+    assert_owned::<UpdateQuery<'static>>();
+    assert_owned::<UserNameQuery>();
+    assert_owned::<UserQuery<'static>>();
+}
+
+#[test]
+fn query_from_owned_strings() {
+    // the query must not borrow from its arguments
+    let query = {
+        let users: Vec<String> = vec![String::from("decathorpe")];
+        let date = BodhiDate::from(chrono::Utc::now());
+
+        UpdateQuery::new()
+            .users(users)
+            .like(String::from("rust-%"))
+            .submitted_since(&date)
+    };
+
+    let cloned = query.clone();
+    assert_eq!(format!("{:?}", query), format!("{:?}", cloned));
+}
+
+#[test]
+fn query_serde_roundtrip() {
+    let date = BodhiDate::try_from("2020-01-01 00:00:00").unwrap();
+
+    let query = UpdateQuery::new()
+        .users(vec!["decathorpe"])
+        .releases(vec![FedoraRelease::F31])
+        .update_type(UpdateType::Security)
+        .submitted_since(&date)
+        .callback(|_, _| {});
+
+    let json = serde_json::to_value(&query).unwrap();
+
+    assert_eq!(json["user"], serde_json::json!(["decathorpe"]));
+    assert_eq!(json["releases"], serde_json::json!(["F31"]));
+    assert_eq!(json["type"], serde_json::json!("security"));
+    assert_eq!(json["submitted_since"], serde_json::json!("2020-01-01 00:00:00"));
+
+    let parsed: UpdateQuery = serde_json::from_value(json.clone()).unwrap();
+    assert_eq!(serde_json::to_value(&parsed).unwrap(), json);
+}
+
+#[test]
+fn query_deserialize_partial() {
+    let query: OverrideQuery = serde_json::from_str(r#"{"user": ["decathorpe"], "expired": false}"#).unwrap();
+    let json = serde_json::to_value(&query).unwrap();
+
+    assert_eq!(json["user"], serde_json::json!(["decathorpe"]));
+    assert_eq!(json["expired"], serde_json::json!(false));
+    assert_eq!(json["packages"], serde_json::Value::Null);
+}
+
+#[test]
+fn query_deserialize_empty() {
+    let _: BuildQuery = serde_json::from_str("{}").unwrap();
+    let _: CommentQuery = serde_json::from_str("{}").unwrap();
+    let _: UpdateQuery = serde_json::from_str("{}").unwrap();
+    let _: UserQuery = serde_json::from_str("{}").unwrap();
+}
+
+#[test]
+fn query_deserialize_unknown_field() {
+    // misspelled filters are rejected instead of silently ignored
+    let result: Result<OverrideQuery, _> = serde_json::from_str(r#"{"users": ["decathorpe"]}"#);
+    let error = result.unwrap_err().to_string();
+    assert!(error.contains("unknown field `users`"), "{}", error);
+
+    assert!(serde_json::from_str::<UpdateQuery>(r#"{"aliases": ["FEDORA-2019-cf87377f5f"]}"#).is_err());
+}
+
+// compose of the release of the test update, with the given content type, request, and state
+fn test_compose(content_type: &str, request: &str, state: &str) -> Compose {
+    let mut compose: Compose = serde_json::from_value(serde_json::json!({
+        "checkpoints": "{}",
+        "content_type": content_type,
+        "date_created": "2019-12-20 12:00:00",
+        "error_message": null,
+        "release": null,
+        "release_id": 28,
+        "request": request,
+        "security": false,
+        "state": state,
+        "state_date": "2019-12-20 12:30:00",
+        "update_summary": []
+    }))
+    .unwrap();
+
+    compose.release = Some(test_update(&[], None).release);
+    compose
+}
+
+#[test]
+fn compose_filter_content_type() {
+    let compose = test_compose("rpm", "stable", "pending");
+
+    assert!(ComposeQuery::new().content_type(ContentType::RPM).matches(&compose));
+    assert!(!ComposeQuery::new().content_type(ContentType::Module).matches(&compose));
+
+    let mut compose = compose;
+    compose.content_type = None;
+    assert!(!ComposeQuery::new().content_type(ContentType::RPM).matches(&compose));
+}
+
+#[test]
+fn compose_filter_releases() {
+    let compose = test_compose("rpm", "stable", "pending");
+
+    assert!(ComposeQuery::new()
+        .releases(vec![FedoraRelease::F30, FedoraRelease::F31])
+        .matches(&compose));
+    assert!(!ComposeQuery::new().releases(vec![FedoraRelease::F30]).matches(&compose));
+
+    let mut compose = compose;
+    compose.release = None;
+    assert!(!ComposeQuery::new().releases(vec![FedoraRelease::F31]).matches(&compose));
+}
+
+#[test]
+fn compose_filter_request() {
+    let compose = test_compose("rpm", "stable", "pending");
+
+    assert!(ComposeQuery::new().request(ComposeRequest::Stable).matches(&compose));
+    assert!(!ComposeQuery::new().request(ComposeRequest::Testing).matches(&compose));
+}
+
+#[test]
+fn compose_filter_state() {
+    let compose = test_compose("rpm", "stable", "pending");
+
+    assert!(ComposeQuery::new().state(ComposeStatus::Pending).matches(&compose));
+    assert!(!ComposeQuery::new().state(ComposeStatus::Failed).matches(&compose));
+}
+
+#[test]
+fn compose_filter_combined() {
+    let compose = test_compose("module", "testing", "failed");
+
+    // no filters match all composes, and all filters must match
+    assert!(ComposeQuery::new().matches(&compose));
+    assert!(ComposeQuery::new()
+        .content_type(ContentType::Module)
+        .releases(vec![FedoraRelease::F31])
+        .request(ComposeRequest::Testing)
+        .state(ComposeStatus::Failed)
+        .matches(&compose));
+    assert!(!ComposeQuery::new()
+        .content_type(ContentType::Module)
+        .request(ComposeRequest::Testing)
+        .state(ComposeStatus::Success)
+        .matches(&compose));
+}
@@ -1,11 +1,11 @@
 use super::bodhi_init;
 
-use crate::{Release, ReleaseNameQuery, ReleaseQuery};
+use crate::{FedoraRelease, Release, ReleaseNameQuery, ReleaseQuery};
 
 #[test]
 fn query() {
-    // This test makes sure that the FedoraRelease enum contains valid values for all fedora releases.
-    // If this fails, then new enum variant(s) need to be added.
+    // This test makes sure that the names of all fedora releases can be parsed into FedoraRelease
+    // values. If this fails, then bodhi's naming scheme for releases has changed.
 
     let bodhi = bodhi_init();
     let _releases: Vec<Release> = bodhi.query(ReleaseQuery::new()).unwrap();
@@ -28,3 +28,11 @@ fn name_query_none() {
 
     assert!(release.is_none());
 }
+
+#[test]
+fn release_exists() {
+    let bodhi = bodhi_init();
+
+    assert!(FedoraRelease::F30.exists(&bodhi).unwrap());
+    assert!(!FedoraRelease::fedora(12).exists(&bodhi).unwrap());
+}
@@ -0,0 +1,58 @@
+use super::test_override_json;
+use crate::{Override, SchemaReport};
+
+fn override_json(build_extra: bool) -> serde_json::Value {
+    let mut json = test_override_json();
+    json["submitter"]["groups"] =
+        serde_json::json!([{ "name": "packager" }, { "name": "provenpackager", "sponsor": true }]);
+
+    if build_extra {
+        json["build"]["ci_url"] = serde_json::json!("https://example.com");
+    }
+
+    json
+}
+
+#[test]
+fn schema_report() {
+    let overrides: Vec<Override> = vec![
+        serde_json::from_value(override_json(true)).unwrap(),
+        serde_json::from_value(override_json(false)).unwrap(),
+    ];
+
+    let mut report = SchemaReport::new();
+    report.add(&overrides);
+
+    assert!(!report.is_empty());
+    assert_eq!(report.fields().count(), 2);
+
+    let ci_url = report.field("Override.build", "ci_url").unwrap();
+    assert_eq!(ci_url.count, 1);
+    assert_eq!(ci_url.total, 2);
+    assert!(ci_url.types.contains("string"));
+
+    let sponsor = report.field("Override.submitter.groups", "sponsor").unwrap();
+    assert_eq!(sponsor.count, 2);
+    assert_eq!(sponsor.total, 4);
+
+    assert_eq!(
+        report.to_string(),
+        "Override.build.ci_url: 1 of 2 (string)\nOverride.submitter.groups.sponsor: 2 of 4 (bool)\n"
+    );
+}
+
+#[test]
+fn schema_report_single() {
+    let value: Override = serde_json::from_value(override_json(false)).unwrap();
+
+    let mut report = SchemaReport::new();
+    report.add(&value);
+
+    // the "sponsor" field of the second group is still unknown
+    assert_eq!(report.fields().count(), 1);
+    assert!(report.field("Override.build", "ci_url").is_none());
+
+    let report = SchemaReport::new();
+    assert!(report.is_empty());
+    assert_eq!(report.to_string(), "No unknown fields.\n");
+}
@@ -0,0 +1,153 @@
+use std::cmp::Ordering;
+
+use crate::{rpmvercmp, Build, ContentType, Nvr};
+
+// test cases from RPM's test suite (`tests/rpmvercmp.at`)
+const RPMVERCMP_CASES: &[(&str, &str, Ordering)] = &[
+    ("1.0", "1.0", Ordering::Equal),
+    ("1.0", "2.0", Ordering::Less),
+    ("2.0", "1.0", Ordering::Greater),
+    ("2.0.1", "2.0.1", Ordering::Equal),
+    ("2.0", "2.0.1", Ordering::Less),
+    ("2.0.1", "2.0", Ordering::Greater),
+    ("2.0.1a", "2.0.1a", Ordering::Equal),
+    ("2.0.1a", "2.0.1", Ordering::Greater),
+    ("2.0.1", "2.0.1a", Ordering::Less),
+    ("5.5p1", "5.5p1", Ordering::Equal),
+    ("5.5p1", "5.5p2", Ordering::Less),
+    ("5.5p2", "5.5p1", Ordering::Greater),
+    ("5.5p10", "5.5p10", Ordering::Equal),
+    ("5.5p1", "5.5p10", Ordering::Less),
+    ("5.5p10", "5.5p1", Ordering::Greater),
+    ("10xyz", "10.1xyz", Ordering::Less),
+    ("10.1xyz", "10xyz", Ordering::Greater),
+    ("xyz10", "xyz10", Ordering::Equal),
+    ("xyz10", "xyz10.1", Ordering::Less),
+    ("xyz10.1", "xyz10", Ordering::Greater),
+    ("xyz.4", "xyz.4", Ordering::Equal),
+    ("xyz.4", "8", Ordering::Less),
+    ("8", "xyz.4", Ordering::Greater),
+    ("xyz.4", "2", Ordering::Less),
+    ("2", "xyz.4", Ordering::Greater),
+    ("5.5p2", "5.6p1", Ordering::Less),
+    ("5.6p1", "5.5p2", Ordering::Greater),
+    ("5.6p1", "6.5p1", Ordering::Less),
+    ("6.5p1", "5.6p1", Ordering::Greater),
+    ("6.0.rc1", "6.0", Ordering::Greater),
+    ("6.0", "6.0.rc1", Ordering::Less),
+    ("10b2", "10a1", Ordering::Greater),
+    ("10a2", "10b2", Ordering::Less),
+    ("1.0aa", "1.0aa", Ordering::Equal),
+    ("1.0a", "1.0aa", Ordering::Less),
+    ("1.0aa", "1.0a", Ordering::Greater),
+    ("10.0001", "10.0001", Ordering::Equal),
+    ("10.0001", "10.1", Ordering::Equal),
+    ("10.1", "10.0001", Ordering::Equal),
+    ("10.0001", "10.0039", Ordering::Less),
+    ("10.0039", "10.0001", Ordering::Greater),
+    ("4.999.9", "5.0", Ordering::Less),
+    ("5.0", "4.999.9", Ordering::Greater),
+    ("20101121", "20101121", Ordering::Equal),
+    ("20101121", "20101122", Ordering::Less),
+    ("20101122", "20101121", Ordering::Greater),
+    ("2_0", "2_0", Ordering::Equal),
+    ("2.0", "2_0", Ordering::Equal),
+    ("2_0", "2.0", Ordering::Equal),
+    ("a", "a", Ordering::Equal),
+    ("a+", "a+", Ordering::Equal),
+    ("a+", "a_", Ordering::Equal),
+    ("a_", "a+", Ordering::Equal),
+    ("+a", "+a", Ordering::Equal),
+    ("+a", "_a", Ordering::Equal),
+    ("_a", "+a", Ordering::Equal),
+    ("+_", "+_", Ordering::Equal),
+    ("_+", "+_", Ordering::Equal),
+    ("_+", "_+", Ordering::Equal),
+    ("+", "_", Ordering::Equal),
+    ("_", "+", Ordering::Equal),
+    ("1.0~rc1", "1.0~rc1", Ordering::Equal),
+    ("1.0~rc1", "1.0", Ordering::Less),
+    ("1.0", "1.0~rc1", Ordering::Greater),
+    ("1.0~rc1", "1.0~rc2", Ordering::Less),
+    ("1.0~rc2", "1.0~rc1", Ordering::Greater),
+    ("1.0~rc1~git123", "1.0~rc1~git123", Ordering::Equal),
+    ("1.0~rc1~git123", "1.0~rc1", Ordering::Less),
+    ("1.0~rc1", "1.0~rc1~git123", Ordering::Greater),
+    ("1.0^", "1.0^", Ordering::Equal),
+    ("1.0^", "1.0", Ordering::Greater),
+    ("1.0", "1.0^", Ordering::Less),
+    ("1.0^git1", "1.0^git1", Ordering::Equal),
+    ("1.0^git1", "1.0", Ordering::Greater),
+    ("1.0", "1.0^git1", Ordering::Less),
+    ("1.0^git1", "1.0^git2", Ordering::Less),
+    ("1.0^git2", "1.0^git1", Ordering::Greater),
+    ("1.0^git1", "1.01", Ordering::Less),
+    ("1.01", "1.0^git1", Ordering::Greater),
+    ("1.0^20160101", "1.0^20160101", Ordering::Equal),
+    ("1.0^20160101", "1.0.1", Ordering::Less),
+    ("1.0.1", "1.0^20160101", Ordering::Greater),
+    ("1.0^20160101^git1", "1.0^20160101^git1", Ordering::Equal),
+    ("1.0^20160102", "1.0^20160101^git1", Ordering::Greater),
+    ("1.0^20160101^git1", "1.0^20160102", Ordering::Less),
+    ("1.0~rc1^git1", "1.0~rc1^git1", Ordering::Equal),
+    ("1.0~rc1^git1", "1.0~rc1", Ordering::Greater),
+    ("1.0~rc1", "1.0~rc1^git1", Ordering::Less),
+    ("1.0^git1~pre", "1.0^git1~pre", Ordering::Equal),
+    ("1.0^git1", "1.0^git1~pre", Ordering::Greater),
+    ("1.0^git1~pre", "1.0^git1", Ordering::Less),
+];
+
+#[test]
+fn rpmvercmp_corpus() {
+    for (one, two, expected) in RPMVERCMP_CASES {
+        assert_eq!(
+            rpmvercmp(one, two),
+            *expected,
+            "rpmvercmp({}, {}) should be {:?}",
+            one,
+            two,
+            expected
+        );
+    }
+}
+
+fn build(nvr: &str, epoch: Option<u32>) -> Build {
+    Build {
+        epoch,
+        nvr: String::from(nvr),
+        release_id: None,
+        signed: true,
+        build_type: ContentType::RPM,
+        extra: Default::default(),
+    }
+}
+
+#[test]
+fn build_evr_cmp() {
+    let old = build("rust-1.40.0-1.fc31", Some(0));
+    let new = build("rust-1.40.0-2.fc31", None);
+    let epoch = build("rust-1.39.0-1.fc31", Some(1));
+
+    assert_eq!(old.evr_cmp(&new), Some(Ordering::Less));
+    assert_eq!(new.evr_cmp(&epoch), Some(Ordering::Less));
+    assert_eq!(old.evr_cmp(&old), Some(Ordering::Equal));
+
+    // builds with invalid NVRs can not be compared
+    let invalid = build("rust", None);
+    assert_eq!(old.evr_cmp(&invalid), None);
+    assert_eq!(invalid.evr_cmp(&invalid), None);
+}
+
+#[test]
+fn build_parse_nvr() {
+    // the epoch of the build is part of the string representation, so it survives a round-trip
+    let nvr = build("rust-1.39.0-1.fc31", Some(1)).parse_nvr().unwrap();
+    assert_eq!(nvr.epoch(), Some(1));
+    assert_eq!(nvr.to_string().as_str(), "rust-1:1.39.0-1.fc31");
+    assert_eq!(nvr.to_string().parse::<Nvr>().unwrap(), nvr);
+
+    // a zero epoch is omitted
+    let nvr = build("rust-1.40.0-1.fc31", Some(0)).parse_nvr().unwrap();
+    assert_eq!(nvr.epoch(), None);
+    assert_eq!(nvr.to_string().as_str(), "rust-1.40.0-1.fc31");
+}
@@ -1,4 +1,5 @@
-use crate::{Bug, TestCase};
+use super::test_override_json;
+use crate::{Archived, Bug, Override, TestCase};
 
 #[test]
 fn bug_url() {
@@ -29,3 +30,21 @@ fn testcase_url() {
         "https://fedoraproject.org/wiki/QA:Foo_Bar_Baz"
     );
 }
+
+#[test]
+fn override_roundtrip() {
+    let mut json = test_override_json();
+    json["build"]["ci_url"] = serde_json::Value::Null;
+    json["some_new_field"] = serde_json::json!([1, 2, 3]);
+
+    let value: Override = serde_json::from_value(json.clone()).unwrap();
+    assert!(value.extra.contains_key("some_new_field"));
+    assert!(value.build.extra.contains_key("ci_url"));
+
+    let archived = serde_json::to_value(Archived::new(value)).unwrap();
+    assert_eq!(archived["data"], json);
+
+    let loaded: Archived<Override> = serde_json::from_value(archived).unwrap();
+    assert!(loaded.is_current());
+    assert_eq!(serde_json::to_value(&loaded.data).unwrap(), json);
+}
@@ -1,4 +1,4 @@
-use super::bodhi_init;
+use super::{alias, bodhi_init, nvr};
 
 use crate::{BodhiDate, FedoraRelease, Update, UpdateIDQuery, UpdateQuery};
 
@@ -50,14 +50,14 @@ fn query_sanity_aliases() {
     let bodhi = bodhi_init();
 
     let updates_one: Vec<Update> = bodhi
-        .query(UpdateQuery::new().aliases(vec!["FEDORA-2019-cf87377f5f"]))
+        .query(UpdateQuery::new().aliases(vec![alias("FEDORA-2019-cf87377f5f")]))
         .unwrap();
     let updates_two: Vec<Update> = bodhi
-        .query(UpdateQuery::new().aliases(vec!["FEDORA-2019-24c9d17287"]))
+        .query(UpdateQuery::new().aliases(vec![alias("FEDORA-2019-24c9d17287")]))
         .unwrap();
 
     let updates_both: Vec<Update> = bodhi
-        .query(UpdateQuery::new().aliases(vec!["FEDORA-2019-cf87377f5f", "FEDORA-2019-24c9d17287"]))
+        .query(UpdateQuery::new().aliases(vec![alias("FEDORA-2019-cf87377f5f"), alias("FEDORA-2019-24c9d17287")]))
         .unwrap();
 
     assert_eq!(updates_both.len(), updates_one.len() + updates_two.len())
@@ -80,14 +80,14 @@ fn query_sanity_builds() {
     let bodhi = bodhi_init();
 
     let updates_one: Vec<Update> = bodhi
-        .query(UpdateQuery::new().builds(vec!["rust-1.39.0-1.fc31"]))
+        .query(UpdateQuery::new().builds(vec![nvr("rust-1.39.0-1.fc31")]))
         .unwrap();
     let updates_two: Vec<Update> = bodhi
-        .query(UpdateQuery::new().builds(vec!["rust-1.40.0-1.fc31"]))
+        .query(UpdateQuery::new().builds(vec![nvr("rust-1.40.0-1.fc31")]))
         .unwrap();
 
     let updates_both: Vec<Update> = bodhi
-        .query(UpdateQuery::new().builds(vec!["rust-1.39.0-1.fc31", "rust-1.40.0-1.fc31"]))
+        .query(UpdateQuery::new().builds(vec![nvr("rust-1.39.0-1.fc31"), nvr("rust-1.40.0-1.fc31")]))
         .unwrap();
 
     assert_eq!(updates_both.len(), updates_one.len() + updates_two.len())
@@ -143,7 +143,9 @@ fn query_sanity_users() {
 fn id_query_some() {
     let bodhi = bodhi_init();
 
-    let update: Option<Update> = bodhi.query(UpdateIDQuery::new("FEDORA-2019-227c137c3f")).unwrap();
+    let update: Option<Update> = bodhi
+        .query(UpdateIDQuery::new(alias("FEDORA-2019-227c137c3f")))
+        .unwrap();
 
     assert!(update.is_some());
 }
@@ -152,7 +154,22 @@ fn id_query_some() {
 fn id_query_none() {
     let bodhi = bodhi_init();
 
-    let update: Option<Update> = bodhi.query(UpdateIDQuery::new("NOPE")).unwrap();
+    let update: Option<Update> = bodhi
+        .query(UpdateIDQuery::new(alias("FEDORA-2019-0000000000")))
+        .unwrap();
 
     assert!(update.is_none());
 }
+
+#[test]
+fn query_from_side_tag() {
+    let bodhi = bodhi_init();
+
+    let updates: Vec<Update> = bodhi
+        .query(UpdateQuery::new().from_side_tag("f34-build-side-42428"))
+        .unwrap();
+
+    assert!(updates
+        .iter()
+        .all(|u| u.from_tag.as_deref() == Some("f34-build-side-42428")));
+}
@@ -0,0 +1,146 @@
+use super::alias;
+use crate::error::UrlError;
+use crate::*;
+
+#[test]
+fn update_query_from_url() {
+    let query =
+        UpdateQuery::from_url("https://bodhi.fedoraproject.org/updates/?packages=rust&status=testing&page=2").unwrap();
+    let expected = UpdateQuery::new().packages(vec!["rust"]).status(UpdateStatus::Testing);
+
+    assert_eq!(format!("{:?}", query), format!("{:?}", expected));
+}
+
+#[test]
+fn update_query_from_url_lists() {
+    let query = UpdateQuery::from_url("/updates/?releases=F31,F32&releases=F33&user=decathorpe&search=").unwrap();
+    let expected = UpdateQuery::new()
+        .releases(vec![FedoraRelease::F31, FedoraRelease::F32, FedoraRelease::F33])
+        .users(vec!["decathorpe"]);
+
+    assert_eq!(format!("{:?}", query), format!("{:?}", expected));
+}
+
+#[test]
+fn query_from_url_invalid() {
+    assert!(matches!(
+        UpdateQuery::from_url("/updates/?package=rust"),
+        Err(UrlError::UnknownParameter { .. })
+    ));
+    assert!(matches!(
+        UpdateQuery::from_url("/updates/?critpath=maybe"),
+        Err(UrlError::InvalidValue { .. })
+    ));
+    assert!(matches!(
+        UpdateQuery::from_url("/updates/?status=tesing"),
+        Err(UrlError::InvalidValue { .. })
+    ));
+    assert!(matches!(
+        UpdateQuery::from_url("/updates/?type=bugfix&severity=critical"),
+        Err(UrlError::InvalidValue { .. })
+    ));
+    assert!(matches!(
+        OverrideQuery::from_url("/updates/?packages=rust"),
+        Err(UrlError::UnsupportedPath { .. })
+    ));
+    assert!(matches!(
+        BuildQuery::from_url("https://bodhi.fedoraproject.org/builds/?bugs=12345"),
+        Err(UrlError::UnknownParameter { .. })
+    ));
+}
+
+#[test]
+fn other_queries_from_url() {
+    assert!(BuildQuery::from_url("/builds/?packages=rust&releases=F31").is_ok());
+    assert!(CommentQuery::from_url("/comments/?user=decathorpe&anonymous=false").is_ok());
+    assert!(OverrideQuery::from_url("/overrides/?packages=rust&expired=false").is_ok());
+    assert!(UserQuery::from_url("/users/?groups=packager").is_ok());
+}
+
+#[test]
+fn resource_parse() {
+    assert!(matches!(
+        BodhiResource::parse("https://bodhi.fedoraproject.org/updates/FEDORA-2019-cf87377f5f"),
+        Ok(BodhiResource::Update(_))
+    ));
+    assert!(matches!(
+        BodhiResource::parse("https://bodhi.stg.fedoraproject.org/updates/?packages=rust"),
+        Ok(BodhiResource::Updates(_))
+    ));
+    assert!(matches!(
+        BodhiResource::parse("/builds/rust-1.40.0-1.fc31"),
+        Ok(BodhiResource::Build(_))
+    ));
+    assert!(matches!(
+        BodhiResource::parse("/comments/1234"),
+        Ok(BodhiResource::Comment(_))
+    ));
+    assert!(matches!(
+        BodhiResource::parse("/overrides/rust-1.40.0-1.fc31"),
+        Ok(BodhiResource::Override(_))
+    ));
+    assert!(matches!(
+        BodhiResource::parse("/users/decathorpe"),
+        Ok(BodhiResource::User(_))
+    ));
+    assert!(matches!(BodhiResource::parse("/users/"), Ok(BodhiResource::Users(_))));
+}
+
+#[test]
+fn resource_parse_invalid() {
+    assert!(matches!(
+        BodhiResource::parse("/updates/new"),
+        Err(UrlError::InvalidValue { .. })
+    ));
+    assert!(matches!(
+        BodhiResource::parse("/comments/abc"),
+        Err(UrlError::InvalidValue { .. })
+    ));
+    assert!(matches!(
+        BodhiResource::parse("/updates/FEDORA-2019-cf87377f5f?foo=bar"),
+        Err(UrlError::UnknownParameter { .. })
+    ));
+    assert!(matches!(
+        BodhiResource::parse("/releases/F31/extra"),
+        Err(UrlError::UnsupportedPath { .. })
+    ));
+    assert!(matches!(
+        BodhiResource::parse("https://"),
+        Err(UrlError::ParseError { .. })
+    ));
+}
+
+#[test]
+fn query_web_urls() {
+    let bodhi = BodhiServiceBuilder::staging().build().unwrap();
+
+    let url = UpdateQuery::new()
+        .packages(vec!["rust"])
+        .status(UpdateStatus::Testing)
+        .web_url(&bodhi)
+        .unwrap();
+    assert_eq!(url.host_str(), Some("bodhi.stg.fedoraproject.org"));
+    assert_eq!(url.path(), "/updates/");
+
+    // links to searches can be parsed back into the original query
+    let parsed = UpdateQuery::from_url(url.as_str()).unwrap();
+    let expected = UpdateQuery::new().packages(vec!["rust"]).status(UpdateStatus::Testing);
+    assert_eq!(format!("{:?}", parsed), format!("{:?}", expected));
+
+    assert_eq!(
+        UpdateIDQuery::new(alias("FEDORA-2019-cf87377f5f"))
+            .web_url(&bodhi)
+            .as_str(),
+        "https://bodhi.stg.fedoraproject.org/updates/FEDORA-2019-cf87377f5f"
+    );
+    assert_eq!(
+        ComposeReleaseRequestQuery::new(FedoraRelease::F31, ComposeRequest::Stable)
+            .web_url(&bodhi)
+            .as_str(),
+        "https://bodhi.stg.fedoraproject.org/composes/F31/stable"
+    );
+    assert_eq!(
+        UserNameQuery::new("decathorpe").web_url(&bodhi).as_str(),
+        "https://bodhi.stg.fedoraproject.org/users/decathorpe"
+    );
+}
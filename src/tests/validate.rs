@@ -0,0 +1,258 @@
+use std::convert::TryFrom;
+
+use super::{mock_bodhi, test_override, test_update, TEST_UPDATE};
+use crate::error::{QueryError, ValidationProblem};
+use crate::{
+    BodhiDate,
+    CommentBuilder,
+    Karma,
+    OverrideBuilder,
+    UpdateBuilder,
+    UpdateEditor,
+    UpdateRequest,
+    UpdateSeverity,
+    UpdateStatusRequester,
+    UpdateType,
+    Validate,
+    ValidationContext,
+};
+
+fn future() -> BodhiDate {
+    BodhiDate::try_from("2999-01-01").unwrap()
+}
+
+fn past() -> BodhiDate {
+    BodhiDate::try_from("2000-01-01").unwrap()
+}
+
+#[test]
+fn update_valid() {
+    let builds = ["rust-1.40.0-1.fc31", "cargo-c-0.5.0-1.fc31"];
+    let builder = UpdateBuilder::from_builds(&builds, "Update to rust 1.40.0.")
+        .stable_karma(3)
+        .unstable_karma(-3)
+        .stable_days(7);
+
+    assert!(builder.validate().is_ok());
+    assert!(builder
+        .validate_with(&ValidationContext::new().minimum_stable_days(7))
+        .is_ok());
+}
+
+#[test]
+fn update_all_problems() {
+    let builds = [
+        "rust-1.40.0-1.fc31",
+        "rust-1.40.0-1.fc31",
+        "cargo-c-0.5.0-1.fc32",
+        "rust-1.40.0",
+        "broken",
+    ];
+    let builder = UpdateBuilder::from_builds(&builds, " ")
+        .stable_karma(0)
+        .unstable_karma(1)
+        .update_type(UpdateType::Security)
+        .stable_days(1);
+
+    let error = builder
+        .validate_with(&ValidationContext::new().minimum_stable_days(7))
+        .unwrap_err();
+
+    assert_eq!(
+        error.problems,
+        vec![
+            ValidationProblem::EmptyNotes,
+            ValidationProblem::DuplicateBuild {
+                nvr: String::from("rust-1.40.0-1.fc31")
+            },
+            ValidationProblem::InvalidBuild {
+                nvr: String::from("rust-1.40.0"),
+                error: String::from("Invalid value for Nvr: rust-1.40.0"),
+            },
+            ValidationProblem::InvalidBuild {
+                nvr: String::from("broken"),
+                error: String::from("Invalid value for Nvr: broken"),
+            },
+            ValidationProblem::MixedReleases {
+                dist_tags: vec![String::from("fc31"), String::from("fc32")],
+            },
+            ValidationProblem::InvalidStableKarma { karma: 0 },
+            ValidationProblem::InvalidUnstableKarma { karma: 1 },
+            ValidationProblem::MissingSeverity,
+            ValidationProblem::StableDaysBelowMinimum {
+                stable_days: 1,
+                minimum: 7
+            },
+        ]
+    );
+
+    // the release minimum is only checked if it is known
+    assert_eq!(builder.validate().unwrap_err().problems.len(), 8);
+}
+
+#[test]
+fn update_dist_tags() {
+    let builds = ["foo-1.0-1.fc32.1", "bar-1.0-0.1.rc1.fc32", "baz-1-1.fc32_1"];
+    assert!(UpdateBuilder::from_builds(&builds, "notes").validate().is_ok());
+
+    let builds = ["foo-1.0-1.el8", "bar-1.0-1.el8_2"];
+    assert!(UpdateBuilder::from_builds(&builds, "notes").validate().is_ok());
+}
+
+#[test]
+fn update_severity() {
+    let builder = UpdateBuilder::from_tag("f32-build-side-1234", "notes")
+        .update_type(UpdateType::Security)
+        .severity(UpdateSeverity::Unspecified);
+    assert_eq!(
+        builder.validate().unwrap_err().problems,
+        vec![ValidationProblem::MissingSeverity]
+    );
+
+    let builder = builder.severity(UpdateSeverity::High);
+    assert!(builder.validate().is_ok());
+}
+
+#[test]
+fn update_unknown_values() {
+    let builder = UpdateBuilder::from_tag("f32-build-side-1234", "notes")
+        .update_type(UpdateType::Unknown(String::from("hotfix")))
+        .severity(UpdateSeverity::Low);
+    assert_eq!(
+        builder.validate().unwrap_err().problems,
+        vec![ValidationProblem::UnknownValue {
+            field: "type",
+            value: String::from("hotfix")
+        }]
+    );
+
+    let update = test_update(&["rust-1.40.0-1.fc31"], None);
+    let requester = UpdateStatusRequester::from_update(&update, UpdateRequest::Unknown(String::from("stabel")));
+    assert!(requester.validate().is_err());
+
+    let requester = UpdateStatusRequester::from_update(&update, UpdateRequest::Stable);
+    assert!(requester.validate().is_ok());
+
+    // unchanged values of existing updates are not checked
+    assert!(update.edit().validate().is_ok());
+    assert!(update
+        .edit()
+        .severity(UpdateSeverity::Unknown(String::from("extreme")))
+        .validate()
+        .is_err());
+}
+
+#[test]
+fn override_expiration() {
+    let date = future();
+    assert!(OverrideBuilder::new("rust-1.40.0-1.fc31", "notes", &date)
+        .validate()
+        .is_ok());
+
+    let date = past();
+    let error = OverrideBuilder::new("rust-1.40.0-1.fc31", "", &date)
+        .validate()
+        .unwrap_err();
+    assert_eq!(
+        error.problems,
+        vec![
+            ValidationProblem::EmptyNotes,
+            ValidationProblem::ExpirationInPast {
+                expiration_date: past()
+            },
+        ]
+    );
+
+    let message = QueryError::from(error).to_string();
+    assert_eq!(
+        message,
+        "Invalid data: Notes must not be empty. Expiration date is in the past: 2000-01-01 00:00:00"
+    );
+}
+
+#[test]
+fn override_editor_expiration() {
+    let over_ride = test_override("2000-01-01 00:00:00");
+
+    // expiring an override does not require a new expiration date
+    assert!(over_ride.edit().expired(true).validate().is_ok());
+    assert!(over_ride.edit().validate().is_err());
+
+    let date = future();
+    assert!(over_ride.edit().expiration_date(&date).validate().is_ok());
+}
+
+#[test]
+fn comment_empty() {
+    assert_eq!(
+        CommentBuilder::new("FEDORA-2019-cf87377f5f")
+            .text("  ")
+            .validate()
+            .unwrap_err()
+            .problems,
+        vec![ValidationProblem::EmptyComment]
+    );
+
+    assert!(CommentBuilder::new("FEDORA-2019-cf87377f5f")
+        .text("works")
+        .validate()
+        .is_ok());
+    assert!(CommentBuilder::new("FEDORA-2019-cf87377f5f")
+        .karma(Karma::Positive)
+        .validate()
+        .is_ok());
+    assert!(CommentBuilder::new("FEDORA-2019-cf87377f5f")
+        .bug_feedback(1234567, Karma::Negative)
+        .validate()
+        .is_ok());
+}
+
+#[test]
+fn update_create_stable_days() {
+    let update: serde_json::Value = serde_json::from_str(TEST_UPDATE).unwrap();
+    let build =
+        serde_json::json!({ "epoch": 0, "nvr": "rust-1.40.0-1.fc31", "release_id": 28, "signed": true, "type": "rpm" });
+    let releases =
+        serde_json::json!({ "releases": [update["release"]], "page": 1, "pages": 1, "rows_per_page": 50, "total": 1 });
+
+    let bodhi = mock_bodhi(vec![
+        ("/builds/", build.to_string()),
+        ("/releases/", releases.to_string()),
+    ]);
+
+    let builds = ["rust-1.40.0-1.fc31"];
+    let builder = UpdateBuilder::from_builds(&builds, "Update to rust 1.40.0.").stable_days(1);
+
+    // the data is valid without knowing the minimum of the release
+    assert!(builder.validate().is_ok());
+
+    match bodhi.create(&builder) {
+        Err(QueryError::ValidationError { error }) => assert_eq!(
+            error.problems,
+            vec![ValidationProblem::StableDaysBelowMinimum {
+                stable_days: 1,
+                minimum: 7
+            }]
+        ),
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+#[test]
+fn update_edit_stable_days() {
+    let update = test_update(&["rust-1.40.0-1.fc31"], None);
+
+    assert!(UpdateEditor::from_update(&update).stable_days(7).validate().is_ok());
+
+    let error = UpdateEditor::from_update(&update)
+        .stable_days(1)
+        .validate()
+        .unwrap_err();
+    assert_eq!(
+        error.problems,
+        vec![ValidationProblem::StableDaysBelowMinimum {
+            stable_days: 1,
+            minimum: 7
+        }]
+    );
+}
@@ -0,0 +1,107 @@
+use serde_json::json;
+
+use super::mock_bodhi;
+use crate::error::QueryError;
+use crate::service::check_version;
+use crate::{ServerVersion, UpdateBuilder};
+
+#[test]
+fn parse_full() {
+    assert_eq!("5.7.1".parse::<ServerVersion>().unwrap(), ServerVersion::new(5, 7, 1));
+}
+
+#[test]
+fn parse_short() {
+    assert_eq!("5.7".parse::<ServerVersion>().unwrap(), ServerVersion::new(5, 7, 0));
+}
+
+#[test]
+fn parse_suffix() {
+    assert_eq!(
+        "5.7.1.dev0".parse::<ServerVersion>().unwrap(),
+        ServerVersion::new(5, 7, 1)
+    );
+}
+
+#[test]
+#[should_panic]
+fn parse_err() {
+    "five".parse::<ServerVersion>().unwrap();
+}
+
+#[test]
+fn ordering() {
+    assert!(ServerVersion::new(5, 10, 0) > ServerVersion::new(5, 7, 1));
+    assert!(ServerVersion::new(6, 0, 0) > ServerVersion::new(5, 99, 99));
+}
+
+#[test]
+fn idem() {
+    let string = String::from("5.7.1");
+    assert_eq!(string.parse::<ServerVersion>().unwrap().to_string(), string);
+}
+
+#[test]
+fn capabilities() {
+    assert!(ServerVersion::new(5, 7, 1).supports_side_tag_updates());
+    assert!(ServerVersion::new(5, 0, 0).supports_side_tag_updates());
+    assert!(!ServerVersion::new(4, 1, 1).supports_side_tag_updates());
+}
+
+#[test]
+fn required_version() {
+    assert!(check_version(ServerVersion::new(5, 7, 1), ServerVersion::SIDE_TAG_UPDATES).is_ok());
+
+    let error = check_version(ServerVersion::new(4, 1, 1), ServerVersion::SIDE_TAG_UPDATES).unwrap_err();
+    assert!(matches!(
+        error,
+        QueryError::UnsupportedServerVersion { version, required }
+            if version == ServerVersion::new(4, 1, 1) && required == ServerVersion::new(5, 0, 0)
+    ));
+    assert_eq!(
+        error.to_string(),
+        "Unsupported bodhi server version: 4.1.1 (required: 5.0.0 or later)"
+    );
+}
+
+#[test]
+fn server_info() {
+    let bodhi = mock_bodhi(vec![
+        ("/api_version", json!({ "version": "5.7.1" }).to_string()),
+        ("/", json!({ "frontpage_notice": "Fedora 31 is EOL." }).to_string()),
+    ]);
+
+    let info = bodhi.server_info().unwrap();
+    assert_eq!(info.api_root, *bodhi.url());
+    assert_eq!(info.version, ServerVersion::new(5, 7, 1));
+    assert_eq!(info.notice.as_deref(), Some("Fedora 31 is EOL."));
+    assert!(info.supports_side_tag_updates());
+
+    assert!(bodhi.require_version(ServerVersion::SIDE_TAG_UPDATES).is_ok());
+}
+
+#[test]
+fn server_info_html() {
+    let bodhi = mock_bodhi(vec![
+        ("/api_version", json!({ "version": "3.13.3" }).to_string()),
+        ("/", String::from("<html></html>")),
+    ]);
+
+    let info = bodhi.server_info().unwrap();
+    assert_eq!(info.notice, None);
+    assert!(!info.supports_side_tag_updates());
+}
+
+#[test]
+fn unsupported_server() {
+    let bodhi = mock_bodhi(vec![("/api_version", json!({ "version": "4.1.1" }).to_string())]);
+    let builder = UpdateBuilder::from_tag("f32-build-side-1234", "Update to rust 1.40.0.");
+
+    match bodhi.create(&builder) {
+        Err(QueryError::UnsupportedServerVersion { version, required }) => {
+            assert_eq!(version, ServerVersion::new(4, 1, 1));
+            assert_eq!(required, ServerVersion::SIDE_TAG_UPDATES);
+        },
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
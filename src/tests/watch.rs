@@ -0,0 +1,92 @@
+use std::time::{Duration, Instant};
+
+use crate::error::{QueryError, ServiceError};
+use crate::{BodhiServiceBuilder, Compose, ComposeEvent, ComposeQuery, ComposeStatus, ComposeWatcher};
+
+fn compose(state: &str, state_date: &str) -> Compose {
+    let json = format!(
+        r#"{{
+            "checkpoints": "{{}}",
+            "content_type": "rpm",
+            "date_created": "2020-01-01 00:00:00",
+            "error_message": null,
+            "release_id": 42,
+            "request": "stable",
+            "security": false,
+            "state": "{}",
+            "state_date": "{}",
+            "update_summary": []
+        }}"#,
+        state, state_date
+    );
+
+    serde_json::from_str(&json).unwrap()
+}
+
+#[test]
+fn transitions() {
+    let bodhi = BodhiServiceBuilder::default().build().unwrap();
+    let mut watcher = ComposeWatcher::new(&bodhi, ComposeQuery::new()).stuck_threshold(Duration::from_secs(3600));
+
+    let now = chrono::Utc::now();
+    let recent = crate::BodhiDate::from(now).to_string();
+
+    let events = watcher.update(vec![compose("punging", &recent)], now);
+    assert!(matches!(events.as_slice(), [ComposeEvent::Appeared { .. }]));
+
+    let events = watcher.update(vec![compose("punging", &recent)], now);
+    assert!(events.is_empty());
+
+    let events = watcher.update(vec![compose("failed", &recent)], now);
+    assert!(matches!(
+        events.as_slice(),
+        [ComposeEvent::StateChanged {
+            from: ComposeStatus::Punging,
+            to: ComposeStatus::Failed,
+            ..
+        }]
+    ));
+
+    // stuck composes are only reported once per state
+    let later = now + chrono::Duration::hours(2);
+    let events = watcher.update(vec![compose("failed", &recent)], later);
+    assert!(matches!(events.as_slice(), [ComposeEvent::Stuck { .. }]));
+    let events = watcher.update(vec![compose("failed", &recent)], later);
+    assert!(events.is_empty());
+
+    let events = watcher.update(vec![], later);
+    assert!(matches!(
+        events.as_slice(),
+        [ComposeEvent::Removed {
+            last_state: ComposeStatus::Failed,
+            ..
+        }]
+    ));
+}
+
+#[test]
+fn wait_after_error() {
+    let bodhi = BodhiServiceBuilder::default().build().unwrap();
+
+    let interval = Duration::from_millis(500);
+    let mut watcher = ComposeWatcher::new(&bodhi, ComposeQuery::new()).interval(interval);
+
+    // every poll fails immediately, without sending any requests
+    let mut polls = 0;
+    let mut failing_poll = |_: &mut ComposeWatcher| {
+        polls += 1;
+        Err(QueryError::ServiceError {
+            error: ServiceError::EmptyResponseError,
+        })
+    };
+
+    let start = Instant::now();
+    assert!(matches!(watcher.next_with(&mut failing_poll), Some(Err(_))));
+    assert!(start.elapsed() < interval);
+
+    let start = Instant::now();
+    assert!(matches!(watcher.next_with(&mut failing_poll), Some(Err(_))));
+    assert!(start.elapsed() >= interval);
+
+    assert_eq!(polls, 2);
+}
@@ -0,0 +1,187 @@
+//! This module contains the [`Validate`](trait.Validate.html) trait, which is implemented by all
+//! builders for creating and editing things on bodhi. Validation runs entirely on the client side,
+//! so it can be used to check data before sending any requests. The `create` and `edit` methods of
+//! [`BodhiService`](../service/struct.BodhiService.html) always validate data before sending it.
+//!
+//! ```
+//! use bodhi::{UpdateBuilder, Validate};
+//!
+//! let builds = ["rust-1.0-1.fc32", "rust-1.0-1.fc32"];
+//! let builder = UpdateBuilder::from_builds(&builds, "").stable_karma(0);
+//!
+//! let error = builder.validate().unwrap_err();
+//! assert_eq!(error.problems.len(), 3);
+//! ```
+
+use std::collections::BTreeSet;
+use std::convert::TryFrom;
+use std::fmt::Display;
+
+use chrono::Utc;
+
+use crate::data::StringEnum;
+use crate::error::{ValidationError, ValidationProblem};
+use crate::{BodhiDate, Nvr, Release, UpdateSeverity, UpdateType};
+
+/// This struct contains additional information for validation, which can not be determined from
+/// the data itself (for example, settings of the release an update is created for).
+#[derive(Clone, Debug, Default)]
+pub struct ValidationContext {
+    minimum_stable_days: Option<u32>,
+}
+
+impl ValidationContext {
+    /// This method creates a new, empty validation context.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// This method sets the minimum number of days updates have to stay in testing for the release
+    /// they are created for (usually 7 days for stable releases, and 3 days for pre-releases).
+    pub fn minimum_stable_days(mut self, days: u32) -> Self {
+        self.minimum_stable_days = Some(days);
+        self
+    }
+
+    /// This method creates a new validation context with the settings of the given release. The
+    /// `create` and `edit` methods of [`BodhiService`](../service/struct.BodhiService.html) use the
+    /// release of the update that is created or edited.
+    pub fn from_release(release: &Release) -> Self {
+        ValidationContext {
+            minimum_stable_days: release.mandatory_days_in_testing,
+        }
+    }
+}
+
+/// This trait is implemented by all builders for creating and editing things on bodhi.
+pub trait Validate {
+    /// This method checks the data for all problems that can be detected on the client side, and
+    /// returns all of them at once.
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.validate_with(&ValidationContext::default())
+    }
+
+    /// This method checks the data for all problems that can be detected on the client side, using
+    /// the additional information from the given context.
+    fn validate_with(&self, context: &ValidationContext) -> Result<(), ValidationError>;
+}
+
+pub(crate) fn check_notes(notes: &str, problems: &mut Vec<ValidationProblem>) {
+    if notes.trim().is_empty() {
+        problems.push(ValidationProblem::EmptyNotes);
+    }
+}
+
+/// This function checks that all builds are valid NVRs, that no build is included more than once,
+/// and that all builds are from the same release.
+pub(crate) fn check_builds(builds: &[&str], problems: &mut Vec<ValidationProblem>) {
+    let mut seen = BTreeSet::new();
+    let mut dist_tags = BTreeSet::new();
+
+    for build in builds {
+        if !seen.insert(*build) {
+            problems.push(ValidationProblem::DuplicateBuild { nvr: build.to_string() });
+            continue;
+        }
+
+        match Nvr::try_from(*build) {
+            Ok(nvr) => {
+                if let Some(dist_tag) = dist_tag(nvr.release()) {
+                    dist_tags.insert(dist_tag);
+                }
+            },
+            Err(error) => problems.push(ValidationProblem::InvalidBuild {
+                nvr: build.to_string(),
+                error: error.to_string(),
+            }),
+        }
+    }
+
+    if dist_tags.len() > 1 {
+        problems.push(ValidationProblem::MixedReleases {
+            dist_tags: dist_tags.into_iter().collect(),
+        });
+    }
+}
+
+/// This function returns the dist tag (like `fc32` or `el8`) of the release part of an NVR. Since
+/// the dist tag can be followed by additional release components (like `1.fc32.1`, or `1.el8_2`),
+/// the last component that starts with letters followed by digits is used, up to the end of its
+/// digits.
+fn dist_tag(release: &str) -> Option<String> {
+    release.rsplit('.').find_map(|component| {
+        let letters = component.chars().take_while(char::is_ascii_alphabetic).count();
+        let digits = component[letters..].chars().take_while(char::is_ascii_digit).count();
+
+        if letters > 0 && digits > 0 {
+            Some(component[..letters + digits].to_owned())
+        } else {
+            None
+        }
+    })
+}
+
+pub(crate) fn check_karma(stable: Option<i32>, unstable: Option<i32>, problems: &mut Vec<ValidationProblem>) {
+    if let Some(karma) = stable {
+        if karma < 1 {
+            problems.push(ValidationProblem::InvalidStableKarma { karma });
+        }
+    }
+
+    if let Some(karma) = unstable {
+        if karma > -1 {
+            problems.push(ValidationProblem::InvalidUnstableKarma { karma });
+        }
+    }
+}
+
+pub(crate) fn check_severity(
+    update_type: Option<&UpdateType>,
+    severity: Option<&UpdateSeverity>,
+    problems: &mut Vec<ValidationProblem>,
+) {
+    if update_type == Some(&UpdateType::Security) {
+        match severity {
+            None | Some(UpdateSeverity::Unspecified) => problems.push(ValidationProblem::MissingSeverity),
+            _ => {},
+        }
+    }
+}
+
+/// This function checks that the value of the given field is one of the known values. Unknown
+/// values are preserved when deserializing server responses, but they must not be sent to the
+/// server.
+pub(crate) fn check_known<T: StringEnum + Display>(
+    field: &'static str,
+    value: Option<&T>,
+    problems: &mut Vec<ValidationProblem>,
+) {
+    if let Some(value) = value {
+        if value.is_unknown() {
+            problems.push(ValidationProblem::UnknownValue {
+                field,
+                value: value.to_string(),
+            });
+        }
+    }
+}
+
+pub(crate) fn check_stable_days(
+    stable_days: Option<u32>,
+    context: &ValidationContext,
+    problems: &mut Vec<ValidationProblem>,
+) {
+    if let (Some(stable_days), Some(minimum)) = (stable_days, context.minimum_stable_days) {
+        if stable_days < minimum {
+            problems.push(ValidationProblem::StableDaysBelowMinimum { stable_days, minimum });
+        }
+    }
+}
+
+pub(crate) fn check_expiration(expiration_date: &BodhiDate, problems: &mut Vec<ValidationProblem>) {
+    if *expiration_date <= BodhiDate::from(Utc::now()) {
+        problems.push(ValidationProblem::ExpirationInPast {
+            expiration_date: expiration_date.clone(),
+        });
+    }
+}
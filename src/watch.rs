@@ -0,0 +1,244 @@
+//! This module contains a simple watcher for monitoring running composes on a bodhi instance.
+//!
+//! The [`ComposeWatcher`](struct.ComposeWatcher.html) repeatedly runs a
+//! [`ComposeQuery`](../query/composes/struct.ComposeQuery.html), compares the results with the
+//! previous state, and reports the differences as [`ComposeEvent`](enum.ComposeEvent.html)s. It
+//! can be used either by calling [`poll`](struct.ComposeWatcher.html#method.poll) manually, or as a
+//! (blocking, never-ending) iterator over events:
+//!
+//! ```
+//! # use std::time::Duration;
+//! # use bodhi::{BodhiServiceBuilder, ComposeEvent, ComposeQuery, ComposeWatcher};
+//! let bodhi = BodhiServiceBuilder::default().build().unwrap();
+//!
+//! let watcher = ComposeWatcher::new(&bodhi, ComposeQuery::new())
+//!     .interval(Duration::from_secs(60))
+//!     .stuck_threshold(Duration::from_secs(6 * 60 * 60));
+//!
+//! # #[cfg(feature = "online-tests")]
+//! for event in watcher {
+//!     match event.unwrap() {
+//!         ComposeEvent::StateChanged { compose, from, to } => {
+//!             println!("{}: {} -> {}", compose.release_id, from, to);
+//!         },
+//!         other => println!("{:?}", other),
+//!     }
+//! #   break;
+//! }
+//! ```
+
+use std::collections::{HashMap, VecDeque};
+use std::thread::sleep;
+use std::time::Duration;
+
+use crate::error::QueryError;
+use crate::{BodhiService, Compose, ComposeQuery, ComposeRequest, ComposeStatus};
+
+/// Specify a default polling interval (60 s) for watching composes.
+const DEFAULT_INTERVAL: Duration = Duration::from_secs(60);
+
+/// This enum represents the events that are reported by a
+/// [`ComposeWatcher`](struct.ComposeWatcher.html).
+#[derive(Debug)]
+pub enum ComposeEvent {
+    /// A compose was seen for the first time (either because it was just requested, or because it
+    /// was already running when the watcher was started).
+    Appeared {
+        /// current data of the compose
+        compose: Compose,
+    },
+    /// The state of a compose has changed since the last poll (for example, from
+    /// `ComposeStatus::Punging` to `ComposeStatus::Failed`).
+    StateChanged {
+        /// current data of the compose, including an error message in case of failure
+        compose: Compose,
+        /// previous state of the compose
+        from: ComposeStatus,
+        /// new state of the compose
+        to: ComposeStatus,
+    },
+    /// A compose has been in the same state for longer than the configured threshold. This is
+    /// reported only once for every state the compose is stuck in.
+    Stuck {
+        /// current data of the compose
+        compose: Compose,
+        /// duration since the last state change of the compose
+        duration: Duration,
+    },
+    /// A compose is no longer listed by the server (which usually means it finished successfully).
+    Removed {
+        /// ID of the release the compose was running for
+        release_id: u32,
+        /// request of the compose (stable or testing)
+        request: ComposeRequest,
+        /// last known state of the compose
+        last_state: ComposeStatus,
+    },
+}
+
+impl ComposeEvent {
+    /// This method returns the error message of the compose this event is associated with, if
+    /// there is one.
+    pub fn error_message(&self) -> Option<&str> {
+        let compose = match self {
+            ComposeEvent::Appeared { compose } => compose,
+            ComposeEvent::StateChanged { compose, .. } => compose,
+            ComposeEvent::Stuck { compose, .. } => compose,
+            ComposeEvent::Removed { .. } => return None,
+        };
+
+        match &compose.error_message {
+            Some(message) if !message.is_empty() => Some(message.as_str()),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct WatchedCompose {
+    state: ComposeStatus,
+    stuck_reported: bool,
+}
+
+/// This struct contains the state that is necessary for watching running composes. Composes are
+/// identified by their release ID and request, which is unique for running composes.
+#[derive(Debug)]
+pub struct ComposeWatcher<'a> {
+    bodhi: &'a BodhiService,
+    query: ComposeQuery,
+    interval: Duration,
+    stuck_threshold: Option<Duration>,
+
+    known: HashMap<(u32, ComposeRequest), WatchedCompose>,
+    pending: VecDeque<ComposeEvent>,
+    polled: bool,
+}
+
+impl<'a> ComposeWatcher<'a> {
+    /// This method creates a new `ComposeWatcher` for the composes that match the given query. The
+    /// default polling interval is 60 seconds, and no composes are reported as stuck.
+    pub fn new(bodhi: &'a BodhiService, query: ComposeQuery) -> Self {
+        ComposeWatcher {
+            bodhi,
+            query,
+            interval: DEFAULT_INTERVAL,
+            stuck_threshold: None,
+            known: HashMap::new(),
+            pending: VecDeque::new(),
+            polled: false,
+        }
+    }
+
+    /// This method can be used to override the default polling interval.
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// This method can be used to report composes that stay in the same state for longer than the
+    /// given duration.
+    pub fn stuck_threshold(mut self, threshold: Duration) -> Self {
+        self.stuck_threshold = Some(threshold);
+        self
+    }
+
+    /// This method queries the current composes once, and returns all events since the last poll.
+    /// It does not wait for the polling interval.
+    pub fn poll(&mut self) -> Result<Vec<ComposeEvent>, QueryError> {
+        // failed polls count as well, so the iterator also waits for the interval after errors
+        self.polled = true;
+        let composes = self.bodhi.query(self.query.clone())?;
+
+        Ok(self.update(composes, chrono::Utc::now()))
+    }
+
+    pub(crate) fn update(&mut self, composes: Vec<Compose>, now: chrono::DateTime<chrono::Utc>) -> Vec<ComposeEvent> {
+        let mut events = Vec::new();
+        let mut seen = HashMap::new();
+
+        for compose in composes {
+            let key = (compose.release_id, compose.request.clone());
+
+            let mut watched = match self.known.remove(&key) {
+                Some(watched) => watched,
+                None => {
+                    events.push(ComposeEvent::Appeared {
+                        compose: compose.clone(),
+                    });
+
+                    WatchedCompose {
+                        state: compose.state.clone(),
+                        stuck_reported: false,
+                    }
+                },
+            };
+
+            if watched.state != compose.state {
+                events.push(ComposeEvent::StateChanged {
+                    compose: compose.clone(),
+                    from: watched.state.clone(),
+                    to: compose.state.clone(),
+                });
+
+                watched.state = compose.state.clone();
+                watched.stuck_reported = false;
+            }
+
+            if let Some(threshold) = self.stuck_threshold {
+                // dates in the future (due to clock skew) are not considered stuck
+                if let Ok(duration) = (now - compose.state_date.date).to_std() {
+                    if duration > threshold && !watched.stuck_reported {
+                        watched.stuck_reported = true;
+                        events.push(ComposeEvent::Stuck { compose, duration });
+                    }
+                }
+            }
+
+            seen.insert(key, watched);
+        }
+
+        for ((release_id, request), watched) in self.known.drain() {
+            events.push(ComposeEvent::Removed {
+                release_id,
+                request,
+                last_state: watched.state,
+            });
+        }
+
+        self.known = seen;
+        events
+    }
+}
+
+impl<'a> Iterator for ComposeWatcher<'a> {
+    type Item = Result<ComposeEvent, QueryError>;
+
+    /// This method blocks until the next event is available, polling the server at the configured
+    /// interval. Query errors are returned as items, so the caller can decide whether to continue.
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_with(Self::poll)
+    }
+}
+
+impl<'a> ComposeWatcher<'a> {
+    // iterator implementation with the given poll function, which can be replaced in tests
+    pub(crate) fn next_with<F>(&mut self, mut poll: F) -> Option<Result<ComposeEvent, QueryError>>
+    where
+        F: FnMut(&mut Self) -> Result<Vec<ComposeEvent>, QueryError>,
+    {
+        while self.pending.is_empty() {
+            if self.polled {
+                sleep(self.interval);
+            }
+
+            self.polled = true;
+
+            match poll(self) {
+                Ok(events) => self.pending.extend(events),
+                Err(error) => return Some(Err(error)),
+            }
+        }
+
+        self.pending.pop_front().map(Ok)
+    }
+}
@@ -0,0 +1,215 @@
+#![allow(unused_imports)]
+#![allow(dead_code)]
+
+use std::fs::{read_dir, read_to_string};
+use std::path::Path;
+
+use proptest::collection::{btree_map, vec};
+use proptest::prelude::*;
+use proptest::test_runner::TestRunner;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use bodhi::{Build, Comment, Compose, Override, Package, Release, Update, User};
+
+const DATA_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data");
+
+// Missing fields and fields with null values are considered equivalent, since optional fields are
+// always serialized, and they are also missing from the responses of some (older) bodhi versions.
+fn assert_json_eq(path: &str, original: &Value, roundtrip: &Value) {
+    match (original, roundtrip) {
+        (Value::Object(one), Value::Object(two)) => {
+            for key in one.keys().chain(two.keys()) {
+                let one = one.get(key).unwrap_or(&Value::Null);
+                let two = two.get(key).unwrap_or(&Value::Null);
+                assert_json_eq(&format!("{}.{}", path, key), one, two);
+            }
+        },
+        (Value::Array(one), Value::Array(two)) => {
+            assert_eq!(one.len(), two.len(), "array length differs at {}", path);
+
+            for (index, (one, two)) in one.iter().zip(two.iter()).enumerate() {
+                assert_json_eq(&format!("{}[{}]", path, index), one, two);
+            }
+        },
+        _ => assert_eq!(original, roundtrip, "value differs at {}", path),
+    }
+}
+
+// This function loads all values from the data files with the given prefix. It fails if there are
+// no matching data files, so missing test data does not go unnoticed.
+fn load(prefix: &str) -> Vec<(String, Value)> {
+    let mut values = Vec::new();
+
+    for entry in read_dir(DATA_DIR).unwrap() {
+        let path = entry.unwrap().path();
+        let name = path.file_name().unwrap().to_str().unwrap().to_owned();
+
+        if !name.starts_with(prefix) || !name.ends_with(".json") {
+            continue;
+        }
+
+        values.push((name, serde_json::from_str(&read_to_string(&path).unwrap()).unwrap()));
+    }
+
+    assert!(!values.is_empty(), "no data files found for {}", prefix);
+    values
+}
+
+// arbitrary JSON values (without floating-point numbers, which are not compared exactly)
+fn json_value() -> impl Strategy<Value = Value> {
+    let leaf = prop_oneof![
+        Just(Value::Null),
+        any::<bool>().prop_map(Value::from),
+        any::<i64>().prop_map(Value::from),
+        any::<u64>().prop_map(Value::from),
+        ".{0,16}".prop_map(Value::from),
+    ];
+
+    leaf.prop_recursive(3, 32, 4, |inner| {
+        prop_oneof![
+            vec(inner.clone(), 0..4).prop_map(Value::Array),
+            btree_map("[a-z_]{1,8}", inner, 0..4).prop_map(|map| Value::Object(map.into_iter().collect())),
+        ]
+    })
+}
+
+// arbitrary fields that are not known to this crate (the prefix avoids collisions with known
+// fields)
+fn extra_fields() -> impl Strategy<Value = Map<String, Value>> {
+    btree_map("x_[a-z_]{1,8}", json_value(), 1..4).prop_map(|map| map.into_iter().collect())
+}
+
+fn roundtrip<T: DeserializeOwned + Serialize>(prefix: &str) {
+    for (name, original) in load(prefix) {
+        let values: Vec<T> = serde_json::from_value(original.clone()).unwrap();
+        let roundtrip = serde_json::to_value(&values).unwrap();
+
+        assert_json_eq(&name, &original, &roundtrip);
+    }
+}
+
+// This function checks that unknown fields of any shape, added to arbitrary values from the data
+// files, survive a round-trip through the data types unchanged.
+fn roundtrip_extra<T: DeserializeOwned + Serialize>(prefix: &str) {
+    let values: Vec<Value> = load(prefix)
+        .into_iter()
+        .flat_map(|(name, values)| match values {
+            Value::Array(values) => values,
+            _ => panic!("{} does not contain a list of values", name),
+        })
+        .collect();
+
+    assert!(!values.is_empty(), "data files for {} are empty", prefix);
+
+    let mut runner = TestRunner::default();
+    runner
+        .run(&(0..values.len(), extra_fields()), |(index, extra)| {
+            let mut original = values[index].clone();
+            original.as_object_mut().unwrap().extend(extra);
+
+            let value: T = serde_json::from_value(original.clone()).unwrap();
+            let roundtrip = serde_json::to_value(&value).unwrap();
+
+            assert_json_eq(&format!("{}[{}]", prefix, index), &original, &roundtrip);
+            Ok(())
+        })
+        .unwrap();
+}
+
+#[cfg(feature = "data-tests")]
+#[test]
+fn builds_roundtrip() {
+    roundtrip::<Build>("builds");
+}
+
+#[cfg(feature = "data-tests")]
+#[test]
+fn comments_roundtrip() {
+    roundtrip::<Comment>("comments");
+}
+
+#[cfg(feature = "data-tests")]
+#[test]
+fn composes_roundtrip() {
+    roundtrip::<Compose>("composes");
+}
+
+#[cfg(feature = "data-tests")]
+#[test]
+fn overrides_roundtrip() {
+    roundtrip::<Override>("overrides");
+}
+
+#[cfg(feature = "data-tests")]
+#[test]
+fn packages_roundtrip() {
+    roundtrip::<Package>("packages");
+}
+
+#[cfg(feature = "data-tests")]
+#[test]
+fn releases_roundtrip() {
+    roundtrip::<Release>("releases");
+}
+
+#[cfg(feature = "data-tests")]
+#[test]
+fn updates_roundtrip() {
+    roundtrip::<Update>("updates");
+}
+
+#[cfg(feature = "data-tests")]
+#[test]
+fn users_roundtrip() {
+    roundtrip::<User>("users");
+}
+
+#[cfg(feature = "data-tests")]
+#[test]
+fn builds_roundtrip_extra() {
+    roundtrip_extra::<Build>("builds");
+}
+
+#[cfg(feature = "data-tests")]
+#[test]
+fn comments_roundtrip_extra() {
+    roundtrip_extra::<Comment>("comments");
+}
+
+#[cfg(feature = "data-tests")]
+#[test]
+fn composes_roundtrip_extra() {
+    roundtrip_extra::<Compose>("composes");
+}
+
+#[cfg(feature = "data-tests")]
+#[test]
+fn overrides_roundtrip_extra() {
+    roundtrip_extra::<Override>("overrides");
+}
+
+#[cfg(feature = "data-tests")]
+#[test]
+fn packages_roundtrip_extra() {
+    roundtrip_extra::<Package>("packages");
+}
+
+#[cfg(feature = "data-tests")]
+#[test]
+fn releases_roundtrip_extra() {
+    roundtrip_extra::<Release>("releases");
+}
+
+#[cfg(feature = "data-tests")]
+#[test]
+fn updates_roundtrip_extra() {
+    roundtrip_extra::<Update>("updates");
+}
+
+#[cfg(feature = "data-tests")]
+#[test]
+fn users_roundtrip_extra() {
+    roundtrip_extra::<User>("users");
+}
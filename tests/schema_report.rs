@@ -0,0 +1,54 @@
+#![allow(unused_imports)]
+#![allow(dead_code)]
+
+use std::fs::{read_dir, read_to_string};
+
+use serde::de::DeserializeOwned;
+
+use bodhi::{Build, Comment, Compose, ExtraFields, Override, Package, Release, SchemaReport, Update, User};
+
+const DATA_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data");
+
+// This function adds all values from the data files with the given prefix to the report. It fails
+// if there are no matching data files, so missing test data does not go unnoticed.
+fn report<T: DeserializeOwned + ExtraFields>(prefix: &str, report: &mut SchemaReport) {
+    let mut files = 0;
+
+    for entry in read_dir(DATA_DIR).unwrap() {
+        let path = entry.unwrap().path();
+        let name = path.file_name().unwrap().to_str().unwrap().to_owned();
+
+        if !name.starts_with(prefix) || !name.ends_with(".json") {
+            continue;
+        }
+
+        let values: Vec<T> = serde_json::from_str(&read_to_string(&path).unwrap()).unwrap();
+        report.add(&values);
+        files += 1;
+    }
+
+    assert!(files > 0, "no data files found for {}", prefix);
+}
+
+// This test fails if any of the data files contain fields that are not known to this crate, and
+// prints a summary of all unknown fields (including those in nested values).
+#[cfg(feature = "data-tests")]
+#[test]
+fn schema_report() {
+    let mut schema = SchemaReport::new();
+
+    report::<Build>("builds", &mut schema);
+    report::<Comment>("comments", &mut schema);
+    report::<Compose>("composes", &mut schema);
+    report::<Override>("overrides", &mut schema);
+    report::<Package>("packages", &mut schema);
+    report::<Release>("releases", &mut schema);
+    report::<Update>("updates", &mut schema);
+    report::<User>("users", &mut schema);
+
+    if !schema.is_empty() {
+        println!("{}", schema);
+    }
+
+    assert!(schema.is_empty());
+}